@@ -0,0 +1,103 @@
+//! Simulated data fetch that runs on a worker thread and hands its result
+//! back to the UI thread.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use slint::{ComponentHandle, Model, SharedString, VecModel};
+
+use crate::{LoadState, MainWindow};
+
+const STEPS: u32 = 20;
+const STEP_DELAY: Duration = Duration::from_millis(75);
+
+/// Tracks the load in flight, if any. Lives on the UI thread.
+#[derive(Default)]
+pub struct Loader {
+    in_flight: RefCell<Option<Arc<AtomicBool>>>,
+}
+
+impl Loader {
+    /// Starts a fetch on a worker thread, cancelling any earlier one. The
+    /// result lands in the window's `items` model, or in `load-error` when
+    /// the fetch fails.
+    pub fn start(&self, window: &MainWindow) {
+        self.abort_in_flight();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        *self.in_flight.borrow_mut() = Some(cancelled.clone());
+
+        window.set_load_error(SharedString::new());
+        window.set_load_state(LoadState::Loading);
+
+        let window_weak = window.as_weak();
+        thread::spawn(move || {
+            for _ in 0..STEPS {
+                thread::sleep(STEP_DELAY);
+                if cancelled.load(Ordering::Relaxed) {
+                    return;
+                }
+            }
+
+            // Only plain data crosses the thread boundary; the model and the
+            // window are touched on the UI thread alone.
+            let result = fetch();
+            let _ = slint::invoke_from_event_loop(move || {
+                // Cancelled after the worker finished but before this ran
+                if cancelled.load(Ordering::Relaxed) {
+                    return;
+                }
+                let Some(window) = window_weak.upgrade() else {
+                    return;
+                };
+
+                match result {
+                    Ok(rows) => {
+                        let items = window.get_items();
+                        if let Some(items) = items.as_any().downcast_ref::<VecModel<SharedString>>() {
+                            items.set_vec(rows.into_iter().map(SharedString::from).collect::<Vec<_>>());
+                        }
+                        window.set_load_state(LoadState::Loaded);
+                    }
+                    Err(error) => {
+                        window.set_load_error(error.into());
+                        window.set_load_state(LoadState::Failed);
+                    }
+                }
+            });
+        });
+    }
+
+    /// Aborts the load in flight and returns the window to idle.
+    pub fn cancel(&self, window: &MainWindow) {
+        if self.abort_in_flight() && window.get_load_state() == LoadState::Loading {
+            window.set_load_state(LoadState::Idle);
+        }
+    }
+
+    fn abort_in_flight(&self) -> bool {
+        match self.in_flight.borrow_mut().take() {
+            Some(cancelled) => {
+                cancelled.store(true, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Stands in for a network request. Fails about one time in four so the
+/// retry path gets exercised.
+fn fetch() -> Result<Vec<String>, String> {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_nanos())
+        .unwrap_or_default();
+    if nanos % 4 == 0 {
+        return Err("Server unavailable (simulated)".to_string());
+    }
+
+    Ok((1..=8).map(|row| format!("Record {}", row)).collect())
+}
@@ -1,19 +1,57 @@
 // Basic Slint Application Template
 // Based on official examples: @source/examples/memory/, @source/examples/todo/
 
+use std::rc::Rc;
+
+use slint::{ModelRc, SharedString, VecModel};
+
+use loader::Loader;
+
+mod loader;
+#[cfg(feature = "timer-demo")]
+mod ticker;
+mod todo;
+mod validators;
+
 slint::include_modules!();
 
 fn main() -> Result<(), slint::PlatformError> {
     // Create the main window
     let main_window = MainWindow::new()?;
-
-    // Set up any additional event handlers if needed
-    // The UI logic is mostly handled in the .slint file for this template
+    setup(&main_window);
 
     // Run the application
     main_window.run()
 }
 
+// Fills in the window and wires every callback; split from `main` so tests
+// can drive a window without running the event loop.
+fn setup(main_window: &MainWindow) {
+    // Greet with the OS we're running on, e.g. "Hello from Linux (Ubuntu 22.04)!"
+    let platform = slint_skills_core::collect();
+    main_window.set_message(format!("Hello from {}!", platform.os_label()).into());
+
+    // Asynchronous data loading: the fetch runs on a worker thread and the
+    // result is posted back with `slint::invoke_from_event_loop`
+    main_window.set_items(ModelRc::new(VecModel::<SharedString>::default()));
+
+    let loader = Rc::new(Loader::default());
+    let window_weak = main_window.as_weak();
+    let load = loader.clone();
+    main_window.on_load_requested(move || load.start(&window_weak.unwrap()));
+
+    let window_weak = main_window.as_weak();
+    main_window.on_cancel_requested(move || loader.cancel(&window_weak.unwrap()));
+
+    // Todo list: add, edit and remove rows of a `VecModel` from callbacks
+    todo::install(main_window);
+
+    // Periodic updates with `slint::Timer`; build with
+    // `--features timer-demo` to show the section
+    #[cfg(feature = "timer-demo")]
+    ticker::install(main_window);
+}
+
 // WebAssembly support - uncomment for web deployment
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
@@ -28,34 +66,115 @@ pub fn main_web() {
     main().expect("Failed to run application");
 }
 
-// Example of how to add more complex logic
-// Uncomment and modify as needed:
+#[cfg(test)]
+mod tests {
+    use slint::Model;
 
-/*
-use slint::{SharedString, ModelRc, VecModel};
-use std::rc::Rc;
+    use super::*;
 
-fn main() -> Result<(), slint::PlatformError> {
-    let main_window = MainWindow::new()?;
+    fn window() -> MainWindow {
+        i_slint_backend_testing::init_no_event_loop();
+        let window = MainWindow::new().unwrap();
+        setup(&window);
+        window
+    }
+
+    fn todos(window: &MainWindow) -> Vec<String> {
+        window.get_todos().iter().map(|todo| todo.to_string()).collect()
+    }
 
-    // Example: Set up a data model
-    let items = Rc::new(VecModel::from(vec![
-        SharedString::from("Item 1"),
-        SharedString::from("Item 2"),
-        SharedString::from("Item 3"),
-    ]));
-
-    // main_window.set_items(ModelRc::from(items));
-
-    // Example: Handle custom callbacks
-    let main_window_weak = main_window.as_weak();
-    main_window.on_custom_action(move || {
-        if let Some(window) = main_window_weak.upgrade() {
-            // Handle the action
-            window.set_message(SharedString::from("Action performed!"));
+    #[test]
+    fn the_greeting_names_the_platform() {
+        let window = window();
+        assert!(window.get_message().starts_with("Hello from "));
+    }
+
+    #[test]
+    fn add_appends_valid_text_and_explains_invalid_text() {
+        let window = window();
+        assert_eq!(todos(&window).len(), 2);
+
+        assert!(!window.invoke_add("   ".into()));
+        assert_eq!(window.get_new_todo_error(), "Can't be empty");
+        assert_eq!(todos(&window).len(), 2);
+
+        assert!(window.invoke_add("  Buy milk ".into()));
+        assert_eq!(window.get_new_todo_error(), "");
+        assert_eq!(todos(&window).last().unwrap(), "Buy milk");
+    }
+
+    #[test]
+    fn editing_the_new_todo_rechecks_only_after_a_rejection() {
+        let window = window();
+        window.invoke_new_todo_edited("".into());
+        assert_eq!(window.get_new_todo_error(), "");
+
+        window.invoke_add("x".repeat(61).into());
+        assert_eq!(window.get_new_todo_error(), "At most 60 characters");
+        window.invoke_new_todo_edited("Short now".into());
+        assert_eq!(window.get_new_todo_error(), "");
+    }
+
+    #[test]
+    fn edit_replaces_a_row_or_sets_todo_error() {
+        let window = window();
+        assert!(window.invoke_edit(0, "Read the book".into()));
+        assert_eq!(todos(&window)[0], "Read the book");
+
+        assert!(!window.invoke_edit(0, "".into()));
+        assert_eq!(window.get_todo_error(), "Can't be empty");
+        assert_eq!(todos(&window)[0], "Read the book");
+
+        assert!(!window.invoke_edit(9, "Nowhere".into()));
+    }
+
+    #[test]
+    fn remove_drops_the_row_and_ignores_stale_indices() {
+        let window = window();
+        window.invoke_remove(0);
+        assert_eq!(todos(&window), ["Build something"]);
+
+        window.invoke_remove(5);
+        assert_eq!(todos(&window).len(), 1);
+    }
+
+    // The fetch finishes on a worker thread through the event loop, so
+    // only the synchronous states are checked
+    #[test]
+    fn loading_starts_and_cancels() {
+        let window = window();
+        window.invoke_load_requested();
+        assert_eq!(window.get_load_state(), LoadState::Loading);
+        assert_eq!(window.get_load_error(), "");
+
+        window.invoke_cancel_requested();
+        assert_eq!(window.get_load_state(), LoadState::Idle);
+    }
+
+    #[cfg(feature = "timer-demo")]
+    #[test]
+    fn the_timer_starts_stops_and_resets() {
+        use i_slint_backend_testing::mock_elapsed_time;
+
+        let window = window();
+        assert!(window.get_timer_demo_enabled());
+        assert_eq!(window.get_timer_clock(), "00:00.0");
+
+        window.invoke_timer_start();
+        assert!(window.get_timer_running());
+        // A repeated timer fires at most once per update, so step tick by tick
+        for _ in 0..15 {
+            mock_elapsed_time(100);
         }
-    });
+        assert_eq!(window.get_timer_clock(), "00:01.5");
 
-    main_window.run()
+        window.invoke_timer_stop();
+        assert!(!window.get_timer_running());
+        mock_elapsed_time(1000);
+        assert_eq!(window.get_timer_clock(), "00:01.5");
+
+        window.invoke_timer_reset();
+        assert_eq!(window.get_timer_clock(), "00:00.0");
+        assert_eq!(window.get_timer_progress(), 0.0);
+    }
 }
-*/
\ No newline at end of file
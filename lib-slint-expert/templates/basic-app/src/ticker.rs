@@ -0,0 +1,99 @@
+//! Periodic updates with `slint::Timer`: a progress bar and an elapsed-time
+//! clock that advance every 100 ms. Built only with the `timer-demo` feature.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use slint::{ComponentHandle, Timer, TimerMode};
+
+use crate::MainWindow;
+
+const TICK: Duration = Duration::from_millis(100);
+/// Ticks for the progress bar to go from empty to full.
+const TICKS_TO_FULL: u32 = 100;
+
+/// Owns the timer. A `slint::Timer` stops when dropped, so keep the `Ticker`
+/// alive (here: in an `Rc` captured by the window callbacks) for as long as
+/// it should run.
+pub struct Ticker {
+    timer: Timer,
+    ticks: Rc<Cell<u32>>,
+}
+
+impl Ticker {
+    pub fn new() -> Rc<Self> {
+        Rc::new(Self { timer: Timer::default(), ticks: Rc::new(Cell::new(0)) })
+    }
+
+    /// Starts ticking from the current value; does nothing while running.
+    pub fn start(&self, window: &MainWindow) {
+        if self.timer.running() {
+            return;
+        }
+
+        // The callback holds a weak handle, so the timer never keeps the
+        // window alive
+        let window_weak = window.as_weak();
+        let ticks = self.ticks.clone();
+        self.timer.start(TimerMode::Repeated, TICK, move || {
+            let Some(window) = window_weak.upgrade() else {
+                return;
+            };
+            ticks.set(ticks.get() + 1);
+            show(&window, ticks.get());
+        });
+        window.set_timer_running(true);
+    }
+
+    pub fn stop(&self, window: &MainWindow) {
+        self.timer.stop();
+        window.set_timer_running(false);
+    }
+
+    /// Stops the timer and rewinds to zero.
+    pub fn reset(&self, window: &MainWindow) {
+        self.stop(window);
+        self.ticks.set(0);
+        show(window, 0);
+    }
+}
+
+fn show(window: &MainWindow, ticks: u32) {
+    window.set_timer_progress((ticks % (TICKS_TO_FULL + 1)) as f32 / TICKS_TO_FULL as f32);
+    window.set_timer_clock(format_elapsed(TICK * ticks).into());
+}
+
+/// Formats as `mm:ss.t`.
+fn format_elapsed(elapsed: Duration) -> String {
+    let tenths = elapsed.as_millis() / 100;
+    format!("{:02}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+}
+
+/// Wires the start/stop/reset buttons and stops the timer when the window
+/// closes, so no tick fires against a closing window.
+pub fn install(window: &MainWindow) {
+    let ticker = Ticker::new();
+    window.set_timer_demo_enabled(true);
+    ticker.reset(window);
+
+    let window_weak = window.as_weak();
+    let timer = ticker.clone();
+    window.on_timer_start(move || timer.start(&window_weak.unwrap()));
+
+    let window_weak = window.as_weak();
+    let timer = ticker.clone();
+    window.on_timer_stop(move || timer.stop(&window_weak.unwrap()));
+
+    let window_weak = window.as_weak();
+    let timer = ticker.clone();
+    window.on_timer_reset(move || timer.reset(&window_weak.unwrap()));
+
+    let window_weak = window.as_weak();
+    window.window().on_close_requested(move || {
+        if let Some(window) = window_weak.upgrade() {
+            ticker.stop(&window);
+        }
+        slint::CloseRequestResponse::HideWindow
+    });
+}
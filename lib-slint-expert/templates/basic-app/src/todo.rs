@@ -0,0 +1,89 @@
+//! Editable todo list: mutating a `VecModel` from UI callbacks.
+
+use std::rc::Rc;
+
+use slint::{ComponentHandle, Model, ModelRc, SharedString, VecModel};
+
+use crate::validators::{max_len, non_empty, validate};
+use crate::MainWindow;
+
+const MAX_ITEM_LEN: usize = 60;
+
+/// Rejects items that are empty, whitespace only or too long, and returns
+/// the trimmed text otherwise.
+fn validate_item(text: &str) -> Result<SharedString, String> {
+    let text = text.trim();
+    validate(text, &[&non_empty, &max_len(MAX_ITEM_LEN)])?;
+    Ok(text.into())
+}
+
+/// Wires the add/edit/remove callbacks to a model owned here. The window
+/// only ever sees the model through `todos`; every change goes through
+/// `VecModel`, which notifies the `for` loop in the UI.
+pub fn install(window: &MainWindow) {
+    let todos = Rc::new(VecModel::from(vec![
+        SharedString::from("Read the Slint tutorial"),
+        SharedString::from("Build something"),
+    ]));
+    window.set_todos(ModelRc::from(todos.clone()));
+
+    // The callbacks capture the `Rc<VecModel>` rather than reading the
+    // model back from the window, so no downcast is needed
+    let window_weak = window.as_weak();
+    let items = todos.clone();
+    window.on_add(move |text| {
+        let window = window_weak.unwrap();
+        match validate_item(&text) {
+            Ok(item) => {
+                items.push(item);
+                window.set_new_todo_error(SharedString::new());
+                true
+            }
+            Err(error) => {
+                window.set_new_todo_error(error.into());
+                false
+            }
+        }
+    });
+
+    // Once an add has been rejected, re-check on every keystroke so the
+    // error clears as soon as the text is fixed
+    let window_weak = window.as_weak();
+    window.on_new_todo_edited(move |text| {
+        let window = window_weak.unwrap();
+        if !window.get_new_todo_error().is_empty() {
+            let error = validate_item(&text).err().unwrap_or_default();
+            window.set_new_todo_error(error.into());
+        }
+    });
+
+    let window_weak = window.as_weak();
+    let items = todos.clone();
+    window.on_edit(move |row, text| {
+        let window = window_weak.unwrap();
+        let row = row as usize;
+        if row >= items.row_count() {
+            return false;
+        }
+        match validate_item(&text) {
+            Ok(item) => {
+                items.set_row_data(row, item);
+                window.set_todo_error(SharedString::new());
+                true
+            }
+            Err(error) => {
+                window.set_todo_error(error.into());
+                false
+            }
+        }
+    });
+
+    let items = todos;
+    window.on_remove(move |row| {
+        let row = row as usize;
+        // A stale index from a double click would panic in `remove`
+        if row < items.row_count() {
+            items.remove(row);
+        }
+    });
+}
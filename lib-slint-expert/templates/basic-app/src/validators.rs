@@ -0,0 +1,115 @@
+//! Composable text-field validators. Each check takes the raw text and
+//! returns the message to show when it fails; nothing here touches the UI.
+//!
+//! ```ignore
+//! let checks: [&Check; 2] = [&non_empty, &max_len(60)];
+//! validate(text, &checks)?;
+//! ```
+
+use std::ops::RangeInclusive;
+
+use regex::Regex;
+
+/// One validator: the text in, the failure message out.
+pub type Check = dyn Fn(&str) -> Result<(), String>;
+
+/// Runs `checks` in order and returns the first failure.
+pub fn validate(text: &str, checks: &[&Check]) -> Result<(), String> {
+    checks.iter().try_for_each(|check| check(text))
+}
+
+/// Fails on empty or whitespace-only text.
+pub fn non_empty(text: &str) -> Result<(), String> {
+    if text.trim().is_empty() {
+        return Err("Can't be empty".to_string());
+    }
+    Ok(())
+}
+
+/// Fails when `text` is longer than `max` characters (not bytes).
+pub fn max_len(max: usize) -> impl Fn(&str) -> Result<(), String> {
+    move |text| {
+        if text.chars().count() > max {
+            return Err(format!("At most {} characters", max));
+        }
+        Ok(())
+    }
+}
+
+/// Fails with `message` unless `regex` matches somewhere in `text`; anchor
+/// the pattern with `^…$` to require a full match.
+pub fn matches_regex(regex: Regex, message: impl Into<String>) -> impl Fn(&str) -> Result<(), String> {
+    let message = message.into();
+    move |text| {
+        if !regex.is_match(text) {
+            return Err(message.clone());
+        }
+        Ok(())
+    }
+}
+
+/// Fails unless `text`, ignoring surrounding whitespace, parses as a number
+/// inside `range`.
+pub fn is_number_in_range(range: RangeInclusive<f64>) -> impl Fn(&str) -> Result<(), String> {
+    move |text| match text.trim().parse::<f64>() {
+        Ok(value) if range.contains(&value) => Ok(()),
+        Ok(_) => Err(format!("Must be between {} and {}", range.start(), range.end())),
+        Err(_) => Err("Must be a number".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_empty_rejects_blank_text() {
+        assert_eq!(non_empty("milk"), Ok(()));
+        assert_eq!(non_empty("  a  "), Ok(()));
+        assert_eq!(non_empty(""), Err("Can't be empty".to_string()));
+        assert_eq!(non_empty(" \t\n"), Err("Can't be empty".to_string()));
+    }
+
+    #[test]
+    fn max_len_counts_characters() {
+        let check = max_len(3);
+        assert_eq!(check(""), Ok(()));
+        assert_eq!(check("abc"), Ok(()));
+        assert_eq!(check("abcd"), Err("At most 3 characters".to_string()));
+        // Three characters, nine bytes
+        assert_eq!(check("日本語"), Ok(()));
+    }
+
+    #[test]
+    fn matches_regex_searches_unless_anchored() {
+        let digit = matches_regex(Regex::new(r"\d").unwrap(), "Needs a digit");
+        assert_eq!(digit("item 2"), Ok(()));
+        assert_eq!(digit("item"), Err("Needs a digit".to_string()));
+
+        let code = matches_regex(Regex::new(r"^[A-Z]{3}$").unwrap(), "Three capitals");
+        assert_eq!(code("ABC"), Ok(()));
+        assert_eq!(code("ABCD"), Err("Three capitals".to_string()));
+    }
+
+    #[test]
+    fn is_number_in_range_includes_the_bounds() {
+        let check = is_number_in_range(1.0..=10.0);
+        assert_eq!(check("1"), Ok(()));
+        assert_eq!(check(" 10.0 "), Ok(()));
+        assert_eq!(check("5.5"), Ok(()));
+        assert_eq!(check("0.99"), Err("Must be between 1 and 10".to_string()));
+        assert_eq!(check("11"), Err("Must be between 1 and 10".to_string()));
+        assert_eq!(check("ten"), Err("Must be a number".to_string()));
+        assert_eq!(check(""), Err("Must be a number".to_string()));
+    }
+
+    #[test]
+    fn validate_returns_the_first_failure() {
+        let short = max_len(5);
+
+        assert_eq!(validate("milk", &[&non_empty, &short]), Ok(()));
+        assert_eq!(validate("  ", &[&non_empty, &short]), Err("Can't be empty".to_string()));
+        assert_eq!(validate("oat milk", &[&non_empty, &short]), Err("At most 5 characters".to_string()));
+        assert_eq!(validate("", &[]), Ok(()));
+    }
+}
@@ -0,0 +1,76 @@
+//! `LazyModel` cost against the number of rows it reports.
+//!
+//! Reading one screenful of rows should take the same time, and building
+//! the model the same memory, whether the list has a thousand rows or ten
+//! million. Run with `cargo bench --bench lazy_model`; the allocated bytes
+//! are printed before the timings.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use slint::{Model, SharedString};
+
+#[path = "../src/lazy_model.rs"]
+#[allow(dead_code)]
+mod lazy_model;
+
+use lazy_model::LazyModel;
+
+// Counts the bytes allocated through it, to show what building a model costs
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        // SAFETY: forwarded unchanged to the system allocator
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: `ptr` came from `alloc` above, i.e. from the system allocator
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ROW_COUNTS: [usize; 4] = [1_000, 50_000, 1_000_000, 10_000_000];
+
+// Rows a typical list view shows at once
+const VISIBLE_ROWS: usize = 30;
+
+fn items(row_count: usize) -> LazyModel<SharedString> {
+    LazyModel::new(row_count, |index| SharedString::from(format!("Item #{}", index + 1)))
+}
+
+fn construction_memory() {
+    for row_count in ROW_COUNTS {
+        let before = ALLOCATED.load(Ordering::Relaxed);
+        let model = items(row_count);
+        let bytes = ALLOCATED.load(Ordering::Relaxed) - before;
+        println!("LazyModel with {row_count:>10} rows: {bytes} bytes allocated");
+        drop(model);
+    }
+}
+
+fn visible_rows(c: &mut Criterion) {
+    construction_memory();
+
+    let mut group = c.benchmark_group("lazy_model/visible_rows");
+    for row_count in ROW_COUNTS {
+        let model = items(row_count);
+        // The middle of the list, as if scrolled there
+        let first = row_count / 2;
+        group.bench_with_input(BenchmarkId::from_parameter(row_count), &model, |b, model| {
+            b.iter(|| (first..first + VISIBLE_ROWS).filter_map(|row| model.row_data(row)).count())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, visible_rows);
+criterion_main!(benches);
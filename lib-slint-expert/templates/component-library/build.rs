@@ -1,18 +1,60 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
 fn main() {
     // Configure the compiler to include our component library
     let mut config = slint_build::CompilerConfiguration::new();
+    let manifest_dir = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap());
 
     // Add library path for our components
-    let library_paths = std::collections::HashMap::from([
-        ("components".to_string(),
-         std::path::Path::new(&std::env::var_os("CARGO_MANIFEST_DIR").unwrap())
-             .join("ui")
-             .join("components")
-             .join("lib.slint")),
-    ]);
+    let mut library_paths = HashMap::from([(
+        "components".to_string(),
+        manifest_dir.join("src").join("ui").join("components").join("lib.slint"),
+    )]);
+
+    // SLINT_COMPONENT_LIB points `@components` (or other `@name` imports) at a
+    // shared component package, e.g. elsewhere in a monorepo
+    println!("cargo:rerun-if-env-changed=SLINT_COMPONENT_LIB");
+    if let Ok(value) = std::env::var("SLINT_COMPONENT_LIB") {
+        library_paths.extend(parse_component_libs(&value, &manifest_dir));
+    }
+
+    for path in library_paths.values() {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
 
     config = config.with_library_paths(library_paths);
 
     // Compile with our configuration
-    slint_build::compile_with_config("ui/main.slint", config).unwrap();
-}
\ No newline at end of file
+    slint_build::compile_with_config(manifest_dir.join("src").join("ui").join("main.slint"), config).unwrap();
+}
+
+// Parses SLINT_COMPONENT_LIB. A bare path replaces `@components`; a
+// `;`-separated list of `name=path` entries adds (or replaces) one library
+// each. Relative paths are resolved against the crate root. Panics with the
+// offending entry if a path doesn't exist.
+fn parse_component_libs(value: &str, manifest_dir: &Path) -> Vec<(String, PathBuf)> {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (name, path) = match entry.split_once('=') {
+                Some((name, path)) => (name.trim(), path.trim()),
+                None => ("components", entry),
+            };
+            if name.is_empty() {
+                panic!("SLINT_COMPONENT_LIB entry {entry:?} has no library name before `=`");
+            }
+
+            let path = manifest_dir.join(path);
+            if !path.exists() {
+                panic!(
+                    "SLINT_COMPONENT_LIB maps `@{name}` to {}, which does not exist",
+                    path.display()
+                );
+            }
+            (name.to_string(), path)
+        })
+        .collect()
+}
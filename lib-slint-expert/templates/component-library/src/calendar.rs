@@ -0,0 +1,254 @@
+//! Month grids for the `DatePicker`: leap years, days per month and the
+//! weekday each month starts on. Proleptic Gregorian calendar throughout.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use slint::{ModelRc, VecModel};
+
+use crate::{ComponentLibraryDemo, Date};
+
+/// First column of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeekStart {
+    Sunday,
+    Monday,
+}
+
+impl WeekStart {
+    /// Sunday for regions that start the week on Sunday (US, Canada, Japan
+    /// and a few others), Monday otherwise. Takes a locale such as "en-US".
+    pub fn for_locale(locale: &str) -> Self {
+        const SUNDAY_REGIONS: [&str; 12] = ["US", "CA", "MX", "BR", "JP", "KR", "TW", "HK", "PH", "IL", "IN", "ZA"];
+        let region = locale.rsplit(['-', '_']).next().unwrap_or("");
+        if locale.contains(['-', '_']) && SUNDAY_REGIONS.contains(&region) {
+            WeekStart::Sunday
+        } else {
+            WeekStart::Monday
+        }
+    }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Days in `month` (1–12) of `year`; 0 for a month out of range.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Day of the week, 0 = Sunday through 6 = Saturday (Sakamoto's method).
+pub fn weekday(year: i32, month: u32, day: u32) -> u32 {
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = if month < 3 { year - 1 } else { year };
+    let days = year + year.div_euclid(4) - year.div_euclid(100)
+        + year.div_euclid(400)
+        + OFFSETS[(month.clamp(1, 12) - 1) as usize]
+        + day as i32;
+    days.rem_euclid(7) as u32
+}
+
+/// One month laid out in weeks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarGrid {
+    pub year: i32,
+    pub month: u32,
+    /// Empty cells before the 1st.
+    pub leading_blanks: u32,
+    pub days: u32,
+}
+
+impl CalendarGrid {
+    /// `month` is clamped to 1–12.
+    pub fn new(year: i32, month: u32, week_start: WeekStart) -> Self {
+        let month = month.clamp(1, 12);
+        let first = weekday(year, month, 1);
+        let leading_blanks = match week_start {
+            WeekStart::Sunday => first,
+            WeekStart::Monday => (first + 6) % 7,
+        };
+        Self { year, month, leading_blanks, days: days_in_month(year, month) }
+    }
+
+    /// Weeks the month touches, 4 to 6.
+    pub fn weeks(&self) -> u32 {
+        (self.leading_blanks + self.days).div_ceil(7)
+    }
+
+    /// One cell per day of every week the month touches, in reading order:
+    /// the day of the month, or `None` for cells before the 1st and after
+    /// the last day.
+    pub fn cells(&self) -> Vec<Option<u32>> {
+        (0..self.weeks() * 7)
+            .map(|cell| {
+                let day = cell.checked_sub(self.leading_blanks)? + 1;
+                (day <= self.days).then_some(day)
+            })
+            .collect()
+    }
+}
+
+/// Today's date in UTC.
+pub fn today() -> (i32, u32, u32) {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / 86_400)
+        .unwrap_or(0) as i64;
+    civil_from_days(days)
+}
+
+// Days since 1970-01-01 to (year, month, day), after Howard Hinnant's
+// `civil_from_days`.
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = (if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 }) as u32;
+    let year = (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+    (year, month, day)
+}
+
+/// Implements the demo's `month-grid` callback, which every `DatePicker`
+/// in it forwards to, and starts the pickers on today.
+pub fn install(app: &ComponentLibraryDemo) {
+    app.on_month_grid(|year, month, monday_first| {
+        let week_start = if monday_first { WeekStart::Monday } else { WeekStart::Sunday };
+        let cells: Vec<i32> = CalendarGrid::new(year, month.max(1) as u32, week_start)
+            .cells()
+            .into_iter()
+            .map(|day| day.map_or(0, |day| day as i32))
+            .collect();
+        ModelRc::new(VecModel::from(cells))
+    });
+
+    let locale = slint_skills_core::detect_locale();
+    app.set_week_starts_monday(WeekStart::for_locale(&locale) == WeekStart::Monday);
+
+    let (year, month, day) = today();
+    app.set_picked_date(Date { year, month: month as i32, day: day as i32 });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leap_years_follow_the_century_rules() {
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2023));
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(2100));
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+    }
+
+    #[test]
+    fn days_per_month() {
+        let common: Vec<u32> = (1..=12).map(|month| days_in_month(2023, month)).collect();
+        assert_eq!(common, [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]);
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(2024, 0), 0);
+        assert_eq!(days_in_month(2024, 13), 0);
+    }
+
+    #[test]
+    fn weekdays_of_known_dates() {
+        assert_eq!(weekday(1970, 1, 1), 4); // Thursday
+        assert_eq!(weekday(2000, 1, 1), 6); // Saturday
+        assert_eq!(weekday(2000, 2, 29), 2); // Tuesday
+        assert_eq!(weekday(2024, 3, 1), 5); // Friday
+        assert_eq!(weekday(1900, 3, 1), 4); // Thursday
+    }
+
+    // Walks 400 years, a full Gregorian cycle, day by day and checks that
+    // the calendar and the weekday agree with counting days from 1970
+    #[test]
+    fn every_day_of_a_cycle_agrees_with_the_day_count() {
+        let start = -(365 * 30 + 8); // 1940-01-01, eight leap days before 1970
+        let mut expected = (1940, 1, 1);
+        for days in start..start + 146_097 {
+            assert_eq!(civil_from_days(days), expected);
+            let (year, month, day) = expected;
+            assert_eq!(weekday(year, month, day) as i64, (days + 4).rem_euclid(7), "{year}-{month}-{day}");
+
+            expected = if day < days_in_month(year, month) {
+                (year, month, day + 1)
+            } else if month < 12 {
+                (year, month + 1, 1)
+            } else {
+                (year + 1, 1, 1)
+            };
+        }
+    }
+
+    #[test]
+    fn week_start_moves_the_first_day() {
+        // September 2024 starts on a Sunday
+        assert_eq!(CalendarGrid::new(2024, 9, WeekStart::Sunday).leading_blanks, 0);
+        assert_eq!(CalendarGrid::new(2024, 9, WeekStart::Monday).leading_blanks, 6);
+        // July 2024 starts on a Monday
+        assert_eq!(CalendarGrid::new(2024, 7, WeekStart::Sunday).leading_blanks, 1);
+        assert_eq!(CalendarGrid::new(2024, 7, WeekStart::Monday).leading_blanks, 0);
+    }
+
+    #[test]
+    fn grids_cover_whole_weeks() {
+        // February 2015 starts on a Sunday and fits in exactly four weeks
+        let february = CalendarGrid::new(2015, 2, WeekStart::Sunday);
+        assert_eq!(february.weeks(), 4);
+        assert_eq!(february.cells(), (1..=28).map(Some).collect::<Vec<_>>());
+
+        // A Monday-first September 2024 needs six
+        let september = CalendarGrid::new(2024, 9, WeekStart::Monday);
+        assert_eq!(september.weeks(), 6);
+        let cells = september.cells();
+        assert_eq!(cells.len(), 42);
+        assert_eq!(cells[..7], [None, None, None, None, None, None, Some(1)]);
+        assert_eq!(cells[35..], [Some(30), None, None, None, None, None, None]);
+    }
+
+    #[test]
+    fn every_month_of_a_cycle_lays_out_its_days_once() {
+        for year in 2000..2400 {
+            for month in 1..=12 {
+                for week_start in [WeekStart::Sunday, WeekStart::Monday] {
+                    let grid = CalendarGrid::new(year, month, week_start);
+                    let days: Vec<u32> = grid.cells().into_iter().flatten().collect();
+                    assert_eq!(days, (1..=days_in_month(year, month)).collect::<Vec<_>>());
+                    assert!((4..=6).contains(&grid.weeks()));
+                    assert!(grid.leading_blanks < 7);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range_months_are_clamped() {
+        assert_eq!(CalendarGrid::new(2024, 0, WeekStart::Monday).month, 1);
+        assert_eq!(CalendarGrid::new(2024, 13, WeekStart::Monday).month, 12);
+    }
+
+    #[test]
+    fn week_start_follows_the_region() {
+        assert_eq!(WeekStart::for_locale("en-US"), WeekStart::Sunday);
+        assert_eq!(WeekStart::for_locale("ja_JP"), WeekStart::Sunday);
+        assert_eq!(WeekStart::for_locale("en-GB"), WeekStart::Monday);
+        assert_eq!(WeekStart::for_locale("de-DE"), WeekStart::Monday);
+        // No region to go by
+        assert_eq!(WeekStart::for_locale("US"), WeekStart::Monday);
+        assert_eq!(WeekStart::for_locale(""), WeekStart::Monday);
+    }
+}
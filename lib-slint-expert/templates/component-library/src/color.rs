@@ -0,0 +1,186 @@
+//! Color math behind the `ColorPicker`: HSV <-> RGB conversion and hex
+//! parsing. The picker calls these through pure callbacks wired in
+//! [`install`].
+
+use slint::{Color, SharedString};
+
+use crate::{ComponentLibraryDemo, Hsv, ParsedColor};
+
+/// Converts HSV to 8-bit RGB. `hue` is in degrees and wraps around;
+/// `saturation` and `value` are clamped to `[0, 1]`.
+pub fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> [u8; 3] {
+    let hue = if hue.is_finite() { hue.rem_euclid(360.0) } else { 0.0 };
+    let saturation = saturation.clamp(0.0, 1.0);
+    let value = value.clamp(0.0, 1.0);
+
+    let chroma = value * saturation;
+    let sector = hue / 60.0;
+    let second = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (red, green, blue) = match sector as u32 {
+        0 => (chroma, second, 0.0),
+        1 => (second, chroma, 0.0),
+        2 => (0.0, chroma, second),
+        3 => (0.0, second, chroma),
+        4 => (second, 0.0, chroma),
+        _ => (chroma, 0.0, second),
+    };
+
+    let lightest = value - chroma;
+    [red, green, blue].map(|channel| ((channel + lightest) * 255.0).round() as u8)
+}
+
+/// Converts 8-bit RGB to `(hue, saturation, value)`, with `hue` in
+/// `[0, 360)` and the others in `[0, 1]`. Grays get hue 0.
+pub fn rgb_to_hsv(red: u8, green: u8, blue: u8) -> (f32, f32, f32) {
+    let [red, green, blue] = [red, green, blue].map(|channel| channel as f32 / 255.0);
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == red {
+        60.0 * ((green - blue) / delta).rem_euclid(6.0)
+    } else if max == green {
+        60.0 * ((blue - red) / delta + 2.0)
+    } else {
+        60.0 * ((red - green) / delta + 4.0)
+    };
+    let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+    (hue, saturation, max)
+}
+
+/// Parses `#RGB`, `#RRGGBB` or `#RRGGBBAA` (the `#` is optional, case is
+/// ignored). The error says what is wrong with the input.
+pub fn parse_hex_color(text: &str) -> Result<Color, String> {
+    let digits = text.trim();
+    let digits = digits.strip_prefix('#').unwrap_or(digits);
+
+    if let Some(bad) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(format!("'{}' is not a hex digit", bad));
+    }
+
+    let channel = |index: usize| u8::from_str_radix(&digits[index * 2..index * 2 + 2], 16).unwrap_or(0);
+    match digits.len() {
+        3 => {
+            let [red, green, blue] = [0, 1, 2].map(|index| {
+                let nibble = u8::from_str_radix(&digits[index..index + 1], 16).unwrap_or(0);
+                nibble * 0x11
+            });
+            Ok(Color::from_rgb_u8(red, green, blue))
+        }
+        6 => Ok(Color::from_rgb_u8(channel(0), channel(1), channel(2))),
+        8 => Ok(Color::from_argb_u8(channel(3), channel(0), channel(1), channel(2))),
+        _ => Err("Use #RGB, #RRGGBB or #RRGGBBAA".to_string()),
+    }
+}
+
+/// Formats as `#RRGGBB`, or `#RRGGBBAA` when not fully opaque.
+pub fn to_hex(color: Color) -> String {
+    let rgb = format!("#{:02X}{:02X}{:02X}", color.red(), color.green(), color.blue());
+    if color.alpha() == 255 {
+        rgb
+    } else {
+        format!("{}{:02X}", rgb, color.alpha())
+    }
+}
+
+/// Implements the demo's color callbacks, which every `ColorPicker` in it
+/// forwards to.
+pub fn install(app: &ComponentLibraryDemo) {
+    app.on_hsv_to_color(|hsv| {
+        let [red, green, blue] = hsv_to_rgb(hsv.hue, hsv.saturation, hsv.value);
+        let alpha = (hsv.alpha.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Color::from_argb_u8(alpha, red, green, blue)
+    });
+
+    app.on_color_to_hsv(|color| {
+        let (hue, saturation, value) = rgb_to_hsv(color.red(), color.green(), color.blue());
+        Hsv { hue, saturation, value, alpha: color.alpha() as f32 / 255.0 }
+    });
+
+    app.on_color_to_hex(|color| to_hex(color).into());
+
+    app.on_parse_hex_color(|text| match parse_hex_color(&text) {
+        Ok(color) => ParsedColor { valid: true, color, error: SharedString::new() },
+        Err(error) => ParsedColor { valid: false, color: Color::default(), error: error.into() },
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_hex_length() {
+        assert_eq!(parse_hex_color("#F80"), Ok(Color::from_rgb_u8(0xFF, 0x88, 0x00)));
+        assert_eq!(parse_hex_color("#1a2B3c"), Ok(Color::from_rgb_u8(0x1A, 0x2B, 0x3C)));
+        assert_eq!(parse_hex_color("#1A2B3C80"), Ok(Color::from_argb_u8(0x80, 0x1A, 0x2B, 0x3C)));
+        assert_eq!(parse_hex_color("  00ff00 "), Ok(Color::from_rgb_u8(0, 255, 0)));
+    }
+
+    #[test]
+    fn rejects_malformed_hex() {
+        assert_eq!(parse_hex_color("#12G"), Err("'G' is not a hex digit".to_string()));
+        assert_eq!(parse_hex_color("#ééé"), Err("'é' is not a hex digit".to_string()));
+        for text in ["", "#", "#12", "#1234", "#12345", "#1234567", "#123456789"] {
+            assert_eq!(parse_hex_color(text), Err("Use #RGB, #RRGGBB or #RRGGBBAA".to_string()), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn hex_round_trips() {
+        assert_eq!(to_hex(Color::from_rgb_u8(0x1A, 0x2B, 0x3C)), "#1A2B3C");
+        assert_eq!(to_hex(Color::from_argb_u8(0x80, 0x1A, 0x2B, 0x3C)), "#1A2B3C80");
+        for text in ["#1A2B3C", "#1A2B3C80", "#00000000"] {
+            assert_eq!(to_hex(parse_hex_color(text).unwrap()), text);
+        }
+    }
+
+    #[test]
+    fn hsv_primaries_and_grays() {
+        assert_eq!(hsv_to_rgb(0.0, 1.0, 1.0), [255, 0, 0]);
+        assert_eq!(hsv_to_rgb(120.0, 1.0, 1.0), [0, 255, 0]);
+        assert_eq!(hsv_to_rgb(240.0, 1.0, 1.0), [0, 0, 255]);
+        assert_eq!(hsv_to_rgb(60.0, 1.0, 1.0), [255, 255, 0]);
+        assert_eq!(hsv_to_rgb(300.0, 1.0, 1.0), [255, 0, 255]);
+        assert_eq!(hsv_to_rgb(200.0, 0.0, 0.5), [128, 128, 128]);
+        assert_eq!(hsv_to_rgb(200.0, 1.0, 0.0), [0, 0, 0]);
+    }
+
+    #[test]
+    fn hsv_input_wraps_and_clamps() {
+        assert_eq!(hsv_to_rgb(360.0, 1.0, 1.0), [255, 0, 0]);
+        assert_eq!(hsv_to_rgb(-120.0, 1.0, 1.0), [0, 0, 255]);
+        assert_eq!(hsv_to_rgb(480.0, 1.0, 1.0), [0, 255, 0]);
+        assert_eq!(hsv_to_rgb(359.999, 1.0, 1.0), [255, 0, 0]);
+        assert_eq!(hsv_to_rgb(f32::NAN, 1.0, 1.0), [255, 0, 0]);
+        assert_eq!(hsv_to_rgb(0.0, 2.0, 7.0), [255, 0, 0]);
+        assert_eq!(hsv_to_rgb(0.0, -1.0, -1.0), [0, 0, 0]);
+    }
+
+    #[test]
+    fn rgb_to_hsv_edges() {
+        assert_eq!(rgb_to_hsv(0, 0, 0), (0.0, 0.0, 0.0));
+        assert_eq!(rgb_to_hsv(255, 255, 255), (0.0, 0.0, 1.0));
+        assert_eq!(rgb_to_hsv(255, 0, 0), (0.0, 1.0, 1.0));
+        assert_eq!(rgb_to_hsv(0, 255, 0), (120.0, 1.0, 1.0));
+        assert_eq!(rgb_to_hsv(0, 0, 255), (240.0, 1.0, 1.0));
+        // Just short of red again, not negative
+        let (hue, _, _) = rgb_to_hsv(255, 0, 1);
+        assert!((359.0..360.0).contains(&hue), "{hue}");
+    }
+
+    #[test]
+    fn every_rgb_color_survives_a_round_trip() {
+        for red in (0..=255).step_by(15) {
+            for green in (0..=255).step_by(15) {
+                for blue in (0..=255).step_by(15) {
+                    let (hue, saturation, value) = rgb_to_hsv(red, green, blue);
+                    assert_eq!(hsv_to_rgb(hue, saturation, value), [red, green, blue]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Placement of popups opened at the pointer, such as the card menu.
+
+use slint::{LogicalPosition, LogicalSize};
+
+/// Top-left corner for a popup of `size` opened at `anchor`, moved left or up
+/// just enough to keep it inside `bounds`. A popup larger than the bounds is
+/// pinned to the top or left edge.
+pub fn clamp_popup_position(anchor: LogicalPosition, size: LogicalSize, bounds: LogicalSize) -> LogicalPosition {
+    let clamp = |start: f32, extent: f32, limit: f32| start.min(limit - extent).max(0.0);
+    LogicalPosition::new(
+        clamp(anchor.x, size.width, bounds.width),
+        clamp(anchor.y, size.height, bounds.height),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The card menu's size, in an 800×600 window
+    const MENU: (f32, f32) = (160.0, 120.0);
+
+    fn place(x: f32, y: f32, (width, height): (f32, f32)) -> (f32, f32) {
+        let position = clamp_popup_position(
+            LogicalPosition::new(x, y),
+            LogicalSize::new(width, height),
+            LogicalSize::new(800.0, 600.0),
+        );
+        (position.x, position.y)
+    }
+
+    #[test]
+    fn fitting_popups_open_at_the_pointer() {
+        assert_eq!(place(100.0, 50.0, MENU), (100.0, 50.0));
+        assert_eq!(place(0.0, 0.0, MENU), (0.0, 0.0));
+        // Exactly touching the right and bottom edges still fits
+        assert_eq!(place(640.0, 480.0, MENU), (640.0, 480.0));
+    }
+
+    #[test]
+    fn popups_near_an_edge_move_back_inside() {
+        assert_eq!(place(750.0, 50.0, MENU), (640.0, 50.0));
+        assert_eq!(place(100.0, 590.0, MENU), (100.0, 480.0));
+        assert_eq!(place(799.0, 599.0, MENU), (640.0, 480.0));
+    }
+
+    #[test]
+    fn pointers_outside_the_window_are_pulled_in() {
+        assert_eq!(place(-20.0, -5.0, MENU), (0.0, 0.0));
+        assert_eq!(place(900.0, 700.0, MENU), (640.0, 480.0));
+    }
+
+    #[test]
+    fn oversized_popups_pin_to_the_top_left() {
+        assert_eq!(place(300.0, 200.0, (1000.0, 700.0)), (0.0, 0.0));
+        assert_eq!(place(300.0, 200.0, (1000.0, 100.0)), (0.0, 200.0));
+    }
+}
@@ -0,0 +1,129 @@
+//! Coalesces bursts of calls into one once the input settles.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// How long the demo waits for the switch to settle.
+pub const DEFAULT_DELAY: Duration = Duration::from_millis(300);
+
+/// Runs `action` with the most recent value passed to [`Debouncer::call`],
+/// once no call has come in for `delay`. Earlier values of the burst are
+/// dropped. Runs on the Slint event loop; a zero `delay` calls through
+/// immediately.
+pub struct Debouncer<T: 'static> {
+    delay: Duration,
+    timer: slint::Timer,
+    latest: Rc<Cell<Option<T>>>,
+    action: Rc<dyn Fn(T)>,
+}
+
+impl<T: 'static> Debouncer<T> {
+    pub fn new(delay: Duration, action: impl Fn(T) + 'static) -> Self {
+        Self {
+            delay,
+            timer: slint::Timer::default(),
+            latest: Rc::new(Cell::new(None)),
+            action: Rc::new(action),
+        }
+    }
+
+    /// Records `value` and restarts the wait.
+    pub fn call(&self, value: T) {
+        if self.delay.is_zero() {
+            (self.action)(value);
+            return;
+        }
+
+        self.latest.set(Some(value));
+        let latest = self.latest.clone();
+        let action = self.action.clone();
+        self.timer.start(slint::TimerMode::SingleShot, self.delay, move || {
+            if let Some(value) = latest.take() {
+                action(value);
+            }
+        });
+    }
+
+    /// Runs a pending call now instead of waiting.
+    pub fn flush(&self) {
+        self.timer.stop();
+        if let Some(value) = self.latest.take() {
+            (self.action)(value);
+        }
+    }
+
+    /// Drops a pending call.
+    pub fn cancel(&self) {
+        self.timer.stop();
+        self.latest.set(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use i_slint_backend_testing::mock_elapsed_time;
+
+    use super::*;
+
+    // A debouncer on the testing backend's mock clock, and what it ran
+    fn debouncer(delay_ms: u64) -> (Debouncer<i32>, Rc<RefCell<Vec<i32>>>) {
+        i_slint_backend_testing::init_no_event_loop();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = seen.clone();
+        (Debouncer::new(Duration::from_millis(delay_ms), move |value| sink.borrow_mut().push(value)), seen)
+    }
+
+    #[test]
+    fn bursts_coalesce_into_the_last_value() {
+        let (debouncer, seen) = debouncer(300);
+        debouncer.call(1);
+        mock_elapsed_time(100);
+        debouncer.call(2);
+        mock_elapsed_time(100);
+        debouncer.call(3);
+
+        // Each call restarted the wait
+        mock_elapsed_time(299);
+        assert!(seen.borrow().is_empty());
+        mock_elapsed_time(1);
+        assert_eq!(*seen.borrow(), [3]);
+
+        // Nothing is left over
+        mock_elapsed_time(1000);
+        assert_eq!(*seen.borrow(), [3]);
+    }
+
+    #[test]
+    fn separate_bursts_run_separately() {
+        let (debouncer, seen) = debouncer(300);
+        debouncer.call(1);
+        mock_elapsed_time(300);
+        debouncer.call(2);
+        mock_elapsed_time(300);
+        assert_eq!(*seen.borrow(), [1, 2]);
+    }
+
+    #[test]
+    fn zero_delay_calls_through() {
+        let (debouncer, seen) = debouncer(0);
+        debouncer.call(1);
+        debouncer.call(2);
+        assert_eq!(*seen.borrow(), [1, 2]);
+    }
+
+    #[test]
+    fn flush_runs_now_and_cancel_drops() {
+        let (debouncer, seen) = debouncer(300);
+        debouncer.call(1);
+        debouncer.flush();
+        assert_eq!(*seen.borrow(), [1]);
+
+        debouncer.call(2);
+        debouncer.cancel();
+        mock_elapsed_time(1000);
+        assert_eq!(*seen.borrow(), [1]);
+    }
+}
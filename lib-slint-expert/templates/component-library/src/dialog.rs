@@ -0,0 +1,58 @@
+//! Awaitable confirm dialog on top of the demo's `ConfirmDialog`.
+
+use std::cell::RefCell;
+use std::future::Future;
+
+use futures::channel::oneshot;
+
+use crate::ComponentLibraryDemo;
+
+thread_local! {
+    // Answer channel of the dialog currently on screen.
+    static PENDING: RefCell<Option<oneshot::Sender<bool>>> = const { RefCell::new(None) };
+}
+
+/// Routes the dialog's buttons to whoever is awaiting [`show_confirm`].
+pub fn install(app: &ComponentLibraryDemo) {
+    let app_weak = app.as_weak();
+    app.on_dialog_confirmed(move || answer(&app_weak, true));
+
+    let app_weak = app.as_weak();
+    app.on_dialog_cancelled(move || answer(&app_weak, false));
+}
+
+/// Opens the dialog and resolves to `true` if the user confirms. Awaiting
+/// this from `slint::spawn_local` keeps the event loop running meanwhile.
+///
+/// Opening a new dialog while one is showing answers the old one with
+/// `false`.
+pub fn show_confirm(
+    app: &ComponentLibraryDemo,
+    title: &str,
+    message: &str,
+    confirm_text: &str,
+    cancel_text: &str,
+) -> impl Future<Output = bool> {
+    let (sender, receiver) = oneshot::channel();
+    if let Some(previous) = PENDING.with(|pending| pending.borrow_mut().replace(sender)) {
+        let _ = previous.send(false);
+    }
+
+    app.set_dialog_title(title.into());
+    app.set_dialog_message(message.into());
+    app.set_dialog_confirm_text(confirm_text.into());
+    app.set_dialog_cancel_text(cancel_text.into());
+    app.set_dialog_open(true);
+
+    // A dropped sender (the window went away) counts as cancel.
+    async move { receiver.await.unwrap_or(false) }
+}
+
+fn answer(app_weak: &slint::Weak<ComponentLibraryDemo>, confirmed: bool) {
+    if let Some(app) = app_weak.upgrade() {
+        app.set_dialog_open(false);
+    }
+    if let Some(sender) = PENDING.with(|pending| pending.borrow_mut().take()) {
+        let _ = sender.send(confirmed);
+    }
+}
@@ -0,0 +1,137 @@
+//! Bounded undo/redo history.
+
+use std::collections::VecDeque;
+
+/// How many values the demo keeps for undo.
+pub const DEFAULT_DEPTH: usize = 50;
+
+/// A list of values with a cursor on the current one. [`History::push`]
+/// after an undo drops the values that could have been redone; past
+/// `depth` values the oldest is forgotten.
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    entries: VecDeque<T>,
+    cursor: usize,
+    depth: usize,
+}
+
+impl<T> History<T> {
+    /// `depth` counts the current value too, so it is at least 1.
+    pub fn new(depth: usize) -> Self {
+        Self { entries: VecDeque::new(), cursor: 0, depth: depth.max(1) }
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        self.entries.get(self.cursor)
+    }
+
+    /// Makes `value` the current value.
+    pub fn push(&mut self, value: T) {
+        if !self.entries.is_empty() {
+            self.entries.truncate(self.cursor + 1);
+        }
+        self.entries.push_back(value);
+        if self.entries.len() > self.depth {
+            self.entries.pop_front();
+        }
+        self.cursor = self.entries.len() - 1;
+    }
+
+    /// Steps back and returns the value that is now current.
+    pub fn undo(&mut self) -> Option<&T> {
+        if !self.can_undo() {
+            return None;
+        }
+        self.cursor -= 1;
+        self.current()
+    }
+
+    /// Steps forward again after an undo.
+    pub fn redo(&mut self) -> Option<&T> {
+        if !self.can_redo() {
+            return None;
+        }
+        self.cursor += 1;
+        self.current()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.cursor > 0
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.cursor + 1 < self.entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_empty() {
+        let mut history = History::<i32>::new(DEFAULT_DEPTH);
+        assert_eq!(history.current(), None);
+        assert!(!history.can_undo());
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), None);
+    }
+
+    #[test]
+    fn undo_and_redo_walk_the_pushed_values() {
+        let mut history = History::new(DEFAULT_DEPTH);
+        history.push("a");
+        assert!(!history.can_undo());
+
+        history.push("b");
+        history.push("c");
+        assert_eq!(history.current(), Some(&"c"));
+
+        assert_eq!(history.undo(), Some(&"b"));
+        assert_eq!(history.undo(), Some(&"a"));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.current(), Some(&"a"));
+
+        assert_eq!(history.redo(), Some(&"b"));
+        assert_eq!(history.redo(), Some(&"c"));
+        assert_eq!(history.redo(), None);
+    }
+
+    #[test]
+    fn pushing_after_an_undo_drops_the_redo_branch() {
+        let mut history = History::new(DEFAULT_DEPTH);
+        history.push("a");
+        history.push("b");
+        history.push("c");
+        history.undo();
+        history.undo();
+
+        history.push("d");
+        assert!(!history.can_redo());
+        assert_eq!(history.current(), Some(&"d"));
+        assert_eq!(history.undo(), Some(&"a"));
+        assert_eq!(history.undo(), None);
+    }
+
+    #[test]
+    fn the_oldest_value_goes_past_the_depth() {
+        let mut history = History::new(3);
+        (1..=5).for_each(|value| history.push(value));
+
+        assert_eq!(history.undo(), Some(&4));
+        assert_eq!(history.undo(), Some(&3));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some(&4));
+        assert_eq!(history.redo(), Some(&5));
+    }
+
+    #[test]
+    fn depth_keeps_at_least_the_current_value() {
+        let mut history = History::new(0);
+        history.push(1);
+        history.push(2);
+        assert_eq!(history.current(), Some(&2));
+        assert!(!history.can_undo());
+    }
+}
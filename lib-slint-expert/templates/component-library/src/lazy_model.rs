@@ -0,0 +1,191 @@
+//! A `slint::Model` whose rows are produced on demand.
+
+use std::cell::{Cell, RefCell};
+
+use slint::{Model, ModelNotify, ModelTracker};
+
+/// Model backed by a fetch closure instead of stored rows. Only the row
+/// count is kept; every `row_data` call asks the closure for that index, so
+/// memory use doesn't grow with the number of rows. Pair it with a view
+/// that instantiates visible rows only, such as `VirtualList`.
+///
+/// [`LazyModel::set_filter`] narrows the rows without wrapping the model in
+/// a `FilterModel`, which would fetch every row on each reset.
+pub struct LazyModel<T> {
+    row_count: Cell<usize>,
+    // Source rows the filter kept, in order; `None` shows all of them
+    kept: RefCell<Option<Vec<usize>>>,
+    fetch: Box<dyn Fn(usize) -> T>,
+    notify: ModelNotify,
+}
+
+impl<T> LazyModel<T> {
+    pub fn new(row_count: usize, fetch: impl Fn(usize) -> T + 'static) -> Self {
+        Self { row_count: Cell::new(row_count), kept: RefCell::new(None), fetch: Box::new(fetch), notify: ModelNotify::default() }
+    }
+
+    /// Changes the number of rows and drops the filter, which was computed
+    /// for the old rows; views re-fetch whatever they show.
+    pub fn set_row_count(&self, row_count: usize) {
+        self.row_count.set(row_count);
+        self.kept.replace(None);
+        self.notify.reset();
+    }
+
+    /// Shows only the source rows `keep` accepts. `keep` gets the source
+    /// index, so it can test the underlying data without the fetch closure,
+    /// and runs once per row here rather than on every read. Only the kept
+    /// indices are stored, and nothing when every row is kept.
+    pub fn set_filter(&self, keep: impl Fn(usize) -> bool) {
+        let row_count = self.row_count.get();
+        let kept: Vec<usize> = (0..row_count).filter(|&index| keep(index)).collect();
+        self.kept.replace((kept.len() < row_count).then_some(kept));
+        self.notify.reset();
+    }
+
+    /// Shows every row again.
+    pub fn clear_filter(&self) {
+        if self.kept.replace(None).is_some() {
+            self.notify.reset();
+        }
+    }
+
+    /// The index in the unfiltered rows of `row`.
+    pub fn source_row(&self, row: usize) -> Option<usize> {
+        match &*self.kept.borrow() {
+            Some(kept) => kept.get(row).copied(),
+            None => (row < self.row_count.get()).then_some(row),
+        }
+    }
+
+    /// Tells views the underlying data changed without a count change.
+    pub fn refresh(&self) {
+        self.notify.reset();
+    }
+}
+
+impl<T: Clone + 'static> Model for LazyModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.kept.borrow().as_ref().map_or(self.row_count.get(), Vec::len)
+    }
+
+    fn row_data(&self, row: usize) -> Option<T> {
+        self.source_row(row).map(|index| (self.fetch)(index))
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.notify
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn rows_map_to_their_indices() {
+        let model = LazyModel::new(50_000, |index| index * 2);
+        assert_eq!(model.row_count(), 50_000);
+        assert_eq!(model.row_data(0), Some(0));
+        assert_eq!(model.row_data(1234), Some(2468));
+        assert_eq!(model.row_data(49_999), Some(99_998));
+    }
+
+    #[test]
+    fn out_of_range_rows_are_none_without_fetching() {
+        let fetched = Rc::new(Cell::new(0));
+        let calls = fetched.clone();
+        let model = LazyModel::new(3, move |index| {
+            calls.set(calls.get() + 1);
+            index
+        });
+
+        assert_eq!(model.row_data(3), None);
+        assert_eq!(model.row_data(usize::MAX), None);
+        assert_eq!(fetched.get(), 0);
+    }
+
+    #[test]
+    fn only_requested_rows_are_fetched() {
+        let fetched = Rc::new(Cell::new(0));
+        let calls = fetched.clone();
+        let model = LazyModel::new(1_000_000, move |index| {
+            calls.set(calls.get() + 1);
+            index
+        });
+        assert_eq!(fetched.get(), 0);
+
+        let visible: Vec<_> = (500..520).filter_map(|row| model.row_data(row)).collect();
+        assert_eq!(visible, (500..520).collect::<Vec<_>>());
+        assert_eq!(fetched.get(), 20);
+    }
+
+    #[test]
+    fn row_count_changes_move_the_range() {
+        let model = LazyModel::new(10, |index| index);
+        model.set_row_count(5);
+        assert_eq!(model.row_count(), 5);
+        assert_eq!(model.row_data(4), Some(4));
+        assert_eq!(model.row_data(5), None);
+    }
+
+    #[test]
+    fn filters_keep_source_order_and_map_back() {
+        let model = LazyModel::new(20, |index| index * 10);
+        model.set_filter(|index| index % 5 == 0);
+
+        assert_eq!(model.row_count(), 4);
+        assert_eq!(model.iter().collect::<Vec<_>>(), [0, 50, 100, 150]);
+        assert_eq!(model.source_row(2), Some(10));
+        assert_eq!(model.source_row(4), None);
+        assert_eq!(model.row_data(4), None);
+
+        model.clear_filter();
+        assert_eq!(model.row_count(), 20);
+        assert_eq!(model.source_row(7), Some(7));
+    }
+
+    #[test]
+    fn filtering_does_not_fetch() {
+        let fetched = Rc::new(Cell::new(0));
+        let calls = fetched.clone();
+        let model = LazyModel::new(50_000, move |index| {
+            calls.set(calls.get() + 1);
+            index
+        });
+
+        model.set_filter(|index| index.to_string().contains("77"));
+        model.set_filter(|index| index < 100);
+        assert_eq!(model.row_count(), 100);
+        assert_eq!(fetched.get(), 0);
+
+        assert_eq!(model.row_data(99), Some(99));
+        assert_eq!(fetched.get(), 1);
+    }
+
+    // A filter that keeps every row stores no index list
+    #[test]
+    fn keeping_every_row_stores_nothing() {
+        let model = LazyModel::new(1_000, |index| index);
+        model.set_filter(|_| true);
+        assert!(model.kept.borrow().is_none());
+        assert_eq!(model.row_count(), 1_000);
+
+        model.set_filter(|_| false);
+        assert_eq!(model.row_count(), 0);
+        assert_eq!(model.row_data(0), None);
+    }
+
+    #[test]
+    fn a_new_row_count_drops_the_filter() {
+        let model = LazyModel::new(10, |index| index);
+        model.set_filter(|index| index > 7);
+        model.set_row_count(5);
+        assert_eq!(model.row_count(), 5);
+        assert_eq!(model.row_data(0), Some(0));
+    }
+}
@@ -1,35 +1,844 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use slint::{Model, ModelRc, SharedString, VecModel};
+
+use debounce::Debouncer;
+use history::History;
+use lazy_model::LazyModel;
+use notification_log::NotificationLog;
+use selection::SelectionModel;
+use snackbar::SnackbarHost;
+use state::{Event, Store};
+use toast::ToastQueue;
+use tree::{Tree, TreeModel};
+
+mod calendar;
+mod color;
+mod context_menu;
+mod debounce;
+mod dialog;
+mod history;
+mod lazy_model;
+mod motion;
+mod notification_log;
+mod pagination;
+mod rating;
+mod reorder;
+mod search;
+mod selection;
+mod slider;
+mod snackbar;
+mod split;
+mod state;
+mod table;
+mod task;
+mod theme;
+mod toast;
+mod tree;
+
 slint::include_modules!();
 
+/// Quiet period before the feature switch handler runs; `Duration::ZERO`
+/// reacts to every flip.
+const SWITCH_DEBOUNCE: Duration = debounce::DEFAULT_DELAY;
+
+/// Typing pause before the list search runs.
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Rows in the virtual list.
+const LIST_ROWS: usize = 50_000;
+
+/// Text of virtual list row `index`, the data the list and its search share.
+fn list_item(index: usize) -> String {
+    format!("Item #{}", index + 1)
+}
+
+/// Shows a toast for `duration_ms` milliseconds.
+fn push_toast(queue: &Rc<ToastQueue>, message: &str, duration_ms: u64, severity: ToastSeverity) {
+    queue.push(message, Duration::from_millis(duration_ms), severity);
+}
+
+/// Shows `message` in the snackbar, replacing any visible one. With an
+/// action, `("Undo", Box::new(...))`, it stays up longer.
+fn show_snackbar(host: &Rc<SnackbarHost>, message: &str, action: Option<(&str, snackbar::Action)>) {
+    host.show(message, action);
+}
+
 fn main() -> Result<(), slint::PlatformError> {
     let app = ComponentLibraryDemo::new()?;
+    setup(&app);
+    app.run()
+}
+
+// Fills in the demo's models and wires every callback; split from `main` so
+// tests can drive a window without running the event loop.
+fn setup(app: &ComponentLibraryDemo) {
+    // Follow the OS "reduce motion" setting; the Toggles tab can override it
+    app.global::<Motion>().set_reduced_motion(slint_skills_core::detect_reduced_motion());
+
+    // Notification history on the Cards tab, newest first: every new
+    // notification line and every toast
+    let log = NotificationLog::new(notification_log::DEFAULT_CAPACITY);
+    app.set_notification_log(log.model());
+
+    let history = log.clone();
+    app.on_clear_notification_log(move || history.clear());
+
+    // Toast notifications
+    let toasts = ToastQueue::new();
+    app.set_toasts(toasts.model());
+
+    let history = log.clone();
+    toasts.on_push(move |message| history.push(message));
+
+    let queue = toasts.clone();
+    app.on_toast_dismissed(move |id| queue.dismiss(id));
+
+    let queue = toasts.clone();
+    app.on_toast_hover_changed(move |id, hovered| queue.set_hovered(id, hovered));
+
+    // Snackbar: one at a time, optionally with an action button
+    let snackbars = SnackbarHost::new();
+    app.set_snackbars(snackbars.model());
+
+    let host = snackbars.clone();
+    app.on_snackbar_action(move |id| host.invoke_action(id));
+
+    let host = snackbars.clone();
+    app.on_snackbar_dismissed(move |id| host.dismiss(id));
+
+    // Callbacks record events in the store; the notification line is
+    // derived from its state. Each new line is kept for undo/redo, which
+    // only change what is shown; the next event starts a new branch.
+    let notifications = Rc::new(RefCell::new(History::new(history::DEFAULT_DEPTH)));
+    let store = Store::new();
+    let window_weak = app.as_weak();
+    let lines = notifications.clone();
+    store.subscribe(move |state| {
+        if let Some(window) = window_weak.upgrade() {
+            let text = state.notification_text();
+            let mut lines = lines.borrow_mut();
+            if lines.current() != Some(&text) {
+                if !text.is_empty() {
+                    log.push(&text);
+                }
+                lines.push(text);
+            }
+            show_notification(&window, &lines);
+            window.set_feature_switch_label(state.switch_label().into());
+        }
+    });
+
+    let window_weak = app.as_weak();
+    let lines = notifications.clone();
+    app.on_undo(move || {
+        let mut lines = lines.borrow_mut();
+        if lines.undo().is_some() {
+            show_notification(&window_weak.unwrap(), &lines);
+        }
+    });
+
+    let window_weak = app.as_weak();
+    let lines = notifications;
+    app.on_redo(move || {
+        let mut lines = lines.borrow_mut();
+        if lines.redo().is_some() {
+            show_notification(&window_weak.unwrap(), &lines);
+        }
+    });
 
     // Demo app interaction handlers
+    dialog::install(app);
+    color::install(app);
+    theme::install(app);
+    motion::install(app);
+    calendar::install(app);
+    pagination::install(app);
+    rating::install(app);
+    split::install(app);
+
     let window_weak = app.as_weak();
+    let events = store.clone();
     app.on_primary_button_clicked(move || {
         let window = window_weak.unwrap();
-        window.set_notification_text("Primary button clicked!");
+        let confirm = dialog::show_confirm(
+            &window,
+            "Run primary action?",
+            "Starts a simulated task on a worker thread.",
+            "Run",
+            "Cancel",
+        );
+
+        let window_weak = window.as_weak();
+        let events = events.clone();
+        let _ = slint::spawn_local(async move {
+            let confirmed = confirm.await;
+            let window = window_weak.unwrap();
+            if confirmed {
+                events.dispatch(Event::PrimaryRunning);
+                task::start(&window);
+            } else {
+                events.dispatch(Event::PrimaryCancelled);
+            }
+        });
     });
 
+    let events = store.clone();
+    let queue = toasts.clone();
+    app.on_task_finished(move || {
+        events.dispatch(Event::PrimaryCompleted);
+        push_toast(&queue, "Primary action completed", 3000, ToastSeverity::Success);
+    });
+
+    let events = store.clone();
+    let host = snackbars.clone();
     let window_weak = app.as_weak();
     app.on_secondary_button_clicked(move || {
-        let window = window_weak.unwrap();
-        window.set_notification_text("Secondary button clicked!");
+        events.dispatch(Event::SecondaryClicked);
+
+        // "Undo" steps the notification line back, like Ctrl+Z
+        let window_weak = window_weak.clone();
+        let undo: snackbar::Action = Box::new(move || {
+            if let Some(window) = window_weak.upgrade() {
+                window.invoke_undo();
+            }
+        });
+        show_snackbar(&host, "Secondary action clicked", Some(("Undo", undo)));
     });
 
-    let window_weak = app.as_weak();
+    let events = store.clone();
+    let queue = toasts.clone();
     app.on_card_button_clicked(move |card_index| {
-        let window = window_weak.unwrap();
-        let message = format!("Card {} clicked!", card_index);
-        window.set_notification_text(message.into());
+        events.dispatch(Event::CardClicked(card_index));
+        push_toast(&queue, &format!("Opened card {}", card_index), 2000, ToastSeverity::Info);
     });
 
+    // Card gallery with click/Ctrl/Shift selection
+    let cards = Rc::new(VecModel::from(
+        (1..=6)
+            .map(|id| CardData {
+                id,
+                title: format!("Card {}", id).into(),
+                content: format!("This is info card number {}", id).into(),
+                button_text: if id % 2 == 1 { "Learn More" } else { "Explore" }.into(),
+                selected: false,
+            })
+            .collect::<Vec<_>>(),
+    ));
+    app.set_cards(ModelRc::from(cards.clone()));
+
+    let selection = Rc::new(RefCell::new(SelectionModel::new(cards.row_count())));
     let window_weak = app.as_weak();
-    app.on_switch_toggled(move |is_on| {
+    let rows = cards.clone();
+    let selected = selection.clone();
+    app.on_card_select_requested(move |row, modifiers| {
+        let mut selection = selected.borrow_mut();
+        selection.click(row as usize, modifiers.control, modifiers.shift);
+        sync_card_selection(&rows, &selection);
+
+        let ids: Vec<i32> = selection
+            .selected()
+            .filter_map(|row| rows.row_data(row).map(|card| card.id))
+            .collect();
+        window_weak.unwrap().invoke_selection_changed(ModelRc::new(VecModel::from(ids)));
+    });
+
+    // Drag-to-reorder; ids travel with the cards, so callbacks that report a
+    // card by id keep naming the same card
+    let window_weak = app.as_weak();
+    let rows = cards.clone();
+    app.on_card_drop_index(move |from, offset| {
+        let pitch = window_weak.unwrap().get_card_pitch();
+        reorder::drop_index(from as usize, offset, pitch, rows.row_count()) as i32
+    });
+
+    let events = store.clone();
+    app.on_cards_reordered(move |from, to| {
+        let (from, to) = (from as usize, to as usize);
+        reorder::move_row(&cards, from, to);
+        selection.borrow_mut().move_row(from, to);
+        if let Some(card) = cards.row_data(to) {
+            events.dispatch(Event::CardMoved { card: card.id, position: to as i32 + 1 });
+        }
+    });
+
+    let events = store.clone();
+    app.on_selection_changed(move |ids| {
+        events.dispatch(Event::SelectionChanged(ids.iter().collect()));
+    });
+
+    // Card context menu
+    app.set_card_actions(ModelRc::from(Rc::new(VecModel::from(vec![
+        SharedString::from("Open"),
+        "Duplicate".into(),
+        "Delete".into(),
+    ]))));
+
+    let window_weak = app.as_weak();
+    app.on_card_menu_requested(move |card_index, x, y| {
         let window = window_weak.unwrap();
-        let status = if is_on { "ON" } else { "OFF" };
-        let message = format!("Switch is now {}", status);
-        window.set_notification_text(message.into());
+        let bounds = window.window().size().to_logical(window.window().scale_factor());
+        let size = slint::LogicalSize::new(window.get_card_menu_width(), window.get_card_menu_height());
+        let position = context_menu::clamp_popup_position(slint::LogicalPosition::new(x, y), size, bounds);
+        window.invoke_show_card_menu(card_index, position.x, position.y);
     });
 
-    app.run()
-}
\ No newline at end of file
+    let events = store.clone();
+    app.on_card_action(move |card_index, action| {
+        events.dispatch(Event::CardAction { card: card_index, action: action.to_string() });
+    });
+
+    // Rapid flips are coalesced: the handler runs once the switch has been
+    // still for `SWITCH_DEBOUNCE`, with its final state
+    let events = store.clone();
+    let queue = toasts.clone();
+    let switch_changes = Debouncer::new(SWITCH_DEBOUNCE, move |is_on| {
+        events.dispatch(Event::SwitchToggled(is_on));
+
+        if is_on {
+            push_toast(&queue, "Feature enabled", 3000, ToastSeverity::Success);
+        } else {
+            push_toast(&queue, "Feature disabled", 3000, ToastSeverity::Warning);
+        }
+    });
+    app.on_switch_toggled(move |is_on| switch_changes.call(is_on));
+
+    let events = store.clone();
+    app.on_slider_changed(move |value| {
+        // Same range and step as the demo slider in main.slint
+        let value = slider::snap_value(value, 0.0, 10.0, 0.5);
+        events.dispatch(Event::SliderChanged(value));
+    });
+
+    let events = store.clone();
+    app.on_rating_changed(move |value| events.dispatch(Event::RatingChanged(value)));
+
+    let options: Rc<VecModel<SharedString>> = Rc::new(VecModel::from(vec![
+        "Small".into(),
+        "Medium".into(),
+        "Large".into(),
+        "Extra Large".into(),
+    ]));
+    app.set_dropdown_options(ModelRc::from(options.clone()));
+
+    let events = store.clone();
+    app.on_dropdown_selected(move |index| {
+        if let Some(option) = options.row_data(index as usize) {
+            events.dispatch(Event::SizeSelected(option.to_string()));
+        }
+    });
+
+    let events = store.clone();
+    app.on_date_picked(move |date| {
+        events.dispatch(Event::DateSelected { year: date.year, month: date.month, day: date.day });
+    });
+
+    let tab_titles: Rc<VecModel<SharedString>> = Rc::new(VecModel::from(vec![
+        "Buttons".into(),
+        "Cards".into(),
+        "Toggles".into(),
+        "Data".into(),
+        "List".into(),
+        "Files".into(),
+    ]));
+    app.set_tab_titles(ModelRc::from(tab_titles.clone()));
+
+    let events = store.clone();
+    app.on_tab_changed(move |index| {
+        if let Some(title) = tab_titles.row_data(index as usize) {
+            events.dispatch(Event::TabChanged(title.to_string()));
+        }
+    });
+
+    // Sample platform features for the data table
+    app.set_table_columns(ModelRc::from(Rc::new(VecModel::from(vec![
+        SharedString::from("Feature"),
+        "Platform".into(),
+        "Since".into(),
+    ]))));
+    let table_rows = Rc::new(VecModel::from(vec![
+        table::row(["GPU rendering", "All", "1.0"]),
+        table::row(["Software renderer", "All", "1.0"]),
+        table::row(["System tray", "Desktop", "1.4"]),
+        table::row(["Clipboard", "All", "1.2"]),
+        table::row(["File dialogs", "Desktop", "1.3"]),
+        table::row(["WebAssembly", "Web", "1.0"]),
+        table::row(["Accessibility", "All", "1.1"]),
+        table::row(["Touch input", "Mobile", "1.5"]),
+    ]));
+    app.set_table_rows(ModelRc::from(table_rows.clone()));
+
+    let rows = table_rows.clone();
+    app.on_table_sort_requested(move |column, ascending| {
+        table::sort_model(&rows, column as usize, ascending, table::compare_cells);
+    });
+
+    let events = store.clone();
+    app.on_table_row_selected(move |index| {
+        let feature = table_rows
+            .row_data(index as usize)
+            .and_then(|row| row.cells.row_data(0));
+        if let Some(feature) = feature {
+            events.dispatch(Event::FeatureSelected(feature.to_string()));
+        }
+    });
+
+    let events = store.clone();
+    app.on_page_changed(move |page| events.dispatch(Event::PageChanged(page)));
+
+    // 50,000 rows generated on demand; nothing is stored per row. Once
+    // typing pauses for `SEARCH_DEBOUNCE` the search narrows the model to
+    // the matching rows, keeping only their indices.
+    let list_items = Rc::new(LazyModel::new(LIST_ROWS, |index| SharedString::from(list_item(index))));
+    app.set_list_items(ModelRc::from(list_items.clone()));
+
+    let rows = list_items.clone();
+    let apply_query = Rc::new(move |text: SharedString| {
+        if text.trim().is_empty() {
+            rows.clear_filter();
+        } else {
+            rows.set_filter(|index| search::matches(&list_item(index), &text));
+        }
+    });
+    let pending_query = Rc::new(Debouncer::new(SEARCH_DEBOUNCE, {
+        let apply_query = apply_query.clone();
+        move |text| apply_query(text)
+    }));
+
+    let pending = pending_query.clone();
+    app.on_search_edited(move |text| pending.call(text));
+
+    // Clearing shows every row right away and drops a pending query
+    app.on_search_cleared(move || {
+        pending_query.cancel();
+        apply_query(SharedString::new());
+    });
+
+    let events = store.clone();
+    app.on_list_row_clicked(move |index| {
+        if let Some(row) = list_items.source_row(index as usize) {
+            events.dispatch(Event::ListItemClicked { item: list_item(row), row: row as i32 });
+        }
+    });
+
+    // File tree; expanding or collapsing splices rows below the node only
+    let tree_rows = TreeModel::new(sample_tree());
+    app.set_tree_nodes(ModelRc::from(tree_rows.clone()));
+
+    let rows = tree_rows.clone();
+    app.on_tree_expand(move |row| {
+        rows.expand(row as usize);
+    });
+
+    let rows = tree_rows.clone();
+    app.on_tree_collapse(move |row| {
+        rows.collapse(row as usize);
+    });
+
+    let events = store.clone();
+    app.on_tree_node_activated(move |id| {
+        if let Some(label) = tree_rows.label(id as usize) {
+            events.dispatch(Event::NodeActivated(label.to_string()));
+        }
+    });
+}
+
+// This template's own layout, as sample data for the tree view.
+fn sample_tree() -> Tree {
+    let mut tree = Tree::new();
+    let root = tree.add(None, "component-library");
+    tree.add(Some(root), "Cargo.toml");
+    tree.add(Some(root), "build.rs");
+    let src = tree.add(Some(root), "src");
+    for file in ["main.rs", "state.rs", "toast.rs", "tree.rs"] {
+        tree.add(Some(src), file);
+    }
+    let ui = tree.add(Some(src), "ui");
+    tree.add(Some(ui), "main.slint");
+    let components = tree.add(Some(ui), "components");
+    for file in ["lib.slint", "primary-button.slint", "toast.slint", "tree-view.slint"] {
+        tree.add(Some(components), file);
+    }
+    tree.add(Some(root), "README.md");
+    tree.set_expanded(root, true);
+    tree
+}
+
+// Shows the current line of `history` and whether undo/redo can go further.
+fn show_notification(app: &ComponentLibraryDemo, history: &History<String>) {
+    app.set_notification_text(history.current().cloned().unwrap_or_default().into());
+    app.set_can_undo(history.can_undo());
+    app.set_can_redo(history.can_redo());
+}
+
+// Copies the selection into the cards' `selected` flags, touching only the
+// rows that changed.
+fn sync_card_selection(cards: &VecModel<CardData>, selection: &SelectionModel) {
+    for row in 0..cards.row_count() {
+        if let Some(mut card) = cards.row_data(row) {
+            if card.selected != selection.is_selected(row) {
+                card.selected = selection.is_selected(row);
+                cards.set_row_data(row, card);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use i_slint_backend_testing::mock_elapsed_time;
+    use slint::platform::{Key, WindowEvent};
+
+    use super::*;
+
+    fn demo() -> ComponentLibraryDemo {
+        i_slint_backend_testing::init_no_event_loop();
+        let app = ComponentLibraryDemo::new().unwrap();
+        setup(&app);
+        app.show().unwrap();
+        app
+    }
+
+    fn key(app: &ComponentLibraryDemo, text: SharedString) {
+        app.window().dispatch_event(WindowEvent::KeyPressed { text: text.clone() });
+        app.window().dispatch_event(WindowEvent::KeyReleased { text });
+    }
+
+    fn with_control(app: &ComponentLibraryDemo, text: &str) {
+        app.window().dispatch_event(WindowEvent::KeyPressed { text: Key::Control.into() });
+        key(app, text.into());
+        app.window().dispatch_event(WindowEvent::KeyReleased { text: Key::Control.into() });
+    }
+
+    // Nothing is focused but the shortcut scope, so Ctrl+Z / Ctrl+Y reach it
+    #[test]
+    fn keyboard_shortcuts_step_through_the_notifications() {
+        let app = demo();
+        app.invoke_card_button_clicked(1);
+        app.invoke_card_button_clicked(2);
+        assert_eq!(app.get_notification_text(), "Card 2 clicked!");
+
+        with_control(&app, "z");
+        assert_eq!(app.get_notification_text(), "Card 1 clicked!");
+
+        with_control(&app, "y");
+        assert_eq!(app.get_notification_text(), "Card 2 clicked!");
+    }
+
+    #[test]
+    fn keys_without_a_shortcut_change_nothing() {
+        let app = demo();
+        app.invoke_card_button_clicked(1);
+
+        key(&app, "z".into());
+        key(&app, Key::Escape.into());
+        assert_eq!(app.get_notification_text(), "Card 1 clicked!");
+    }
+
+    fn list_rows(app: &ComponentLibraryDemo) -> usize {
+        app.get_list_items().row_count()
+    }
+
+    #[test]
+    fn the_search_applies_the_last_query_once_typing_pauses() {
+        let app = demo();
+        app.invoke_search_edited("4999".into());
+        mock_elapsed_time(100);
+        app.invoke_search_edited("49999".into());
+        assert_eq!(list_rows(&app), LIST_ROWS);
+
+        mock_elapsed_time(SEARCH_DEBOUNCE.as_millis() as u64 - 1);
+        assert_eq!(list_rows(&app), LIST_ROWS);
+        mock_elapsed_time(1);
+        assert_eq!(list_rows(&app), 1);
+
+        // Clicks report the row in the unfiltered list
+        app.invoke_list_row_clicked(0);
+        assert_eq!(app.get_notification_text(), "Clicked Item #49999 (row 49998)");
+    }
+
+    #[test]
+    fn clearing_the_search_drops_the_pending_query() {
+        let app = demo();
+        app.invoke_search_edited("123".into());
+        mock_elapsed_time(SEARCH_DEBOUNCE.as_millis() as u64);
+        assert!(list_rows(&app) < LIST_ROWS);
+
+        app.invoke_search_edited("7".into());
+        app.invoke_search_cleared();
+        assert_eq!(list_rows(&app), LIST_ROWS);
+        mock_elapsed_time(1000);
+        assert_eq!(list_rows(&app), LIST_ROWS);
+    }
+
+    fn rows<T: Clone + 'static>(model: ModelRc<T>) -> Vec<T> {
+        model.iter().collect()
+    }
+
+    fn card_ids(app: &ComponentLibraryDemo) -> Vec<i32> {
+        rows(app.get_cards()).into_iter().map(|card| card.id).collect()
+    }
+
+    #[test]
+    fn the_primary_button_asks_first() {
+        let app = demo();
+        app.invoke_primary_button_clicked();
+        assert!(app.get_dialog_open());
+        assert_eq!(app.get_dialog_title(), "Run primary action?");
+        assert_eq!(app.get_dialog_confirm_text(), "Run");
+
+        app.invoke_dialog_cancelled();
+        assert!(!app.get_dialog_open());
+
+        app.invoke_primary_button_clicked();
+        app.invoke_dialog_confirmed();
+        assert!(!app.get_dialog_open());
+    }
+
+    #[test]
+    fn a_finished_task_is_announced() {
+        let app = demo();
+        app.invoke_task_finished();
+        assert_eq!(app.get_notification_text(), "Primary action completed!");
+        assert_eq!(rows(app.get_toasts())[0].message, "Primary action completed");
+    }
+
+    #[test]
+    fn the_secondary_snackbar_undoes_the_notification() {
+        let app = demo();
+        app.invoke_card_button_clicked(1);
+        app.invoke_secondary_button_clicked();
+        assert_eq!(app.get_notification_text(), "Secondary button clicked!");
+
+        let snackbar = rows(app.get_snackbars()).pop().unwrap();
+        assert_eq!(snackbar.action_label, "Undo");
+        app.invoke_snackbar_action(snackbar.id);
+        assert_eq!(app.get_notification_text(), "Card 1 clicked!");
+        assert!(app.get_can_redo());
+        assert!(rows(app.get_snackbars()).is_empty());
+
+        app.invoke_secondary_button_clicked();
+        let snackbar = rows(app.get_snackbars()).pop().unwrap();
+        app.invoke_snackbar_dismissed(snackbar.id);
+        assert!(rows(app.get_snackbars()).is_empty());
+        assert_eq!(app.get_notification_text(), "Secondary button clicked!");
+    }
+
+    #[test]
+    fn card_clicks_notify_toast_and_log() {
+        let app = demo();
+        app.invoke_card_button_clicked(3);
+        assert_eq!(app.get_notification_text(), "Card 3 clicked!");
+
+        let toasts = rows(app.get_toasts());
+        assert_eq!(toasts.len(), 1);
+        assert_eq!(toasts[0].message, "Opened card 3");
+
+        // Newest first: the toast, then the line it came with
+        let log: Vec<_> = rows(app.get_notification_log()).into_iter().map(|entry| entry.message).collect();
+        assert_eq!(log, ["Opened card 3", "Card 3 clicked!"]);
+
+        app.invoke_clear_notification_log();
+        assert!(rows(app.get_notification_log()).is_empty());
+    }
+
+    #[test]
+    fn toasts_close_on_click_and_wait_while_hovered() {
+        let app = demo();
+        app.invoke_card_button_clicked(1);
+        app.invoke_card_button_clicked(2);
+        let toasts = rows(app.get_toasts());
+
+        app.invoke_toast_hover_changed(toasts[1].id, true);
+        mock_elapsed_time(5000);
+        let left: Vec<_> = rows(app.get_toasts()).into_iter().map(|toast| toast.id).collect();
+        assert!(left.contains(&toasts[1].id));
+
+        app.invoke_toast_dismissed(toasts[1].id);
+        assert!(rows(app.get_toasts()).iter().all(|toast| toast.id != toasts[1].id));
+    }
+
+    #[test]
+    fn clicking_a_card_selects_it() {
+        let app = demo();
+        app.invoke_card_select_requested(1, Default::default());
+        assert_eq!(rows(app.get_cards()).iter().filter(|card| card.selected).count(), 1);
+        assert!(rows(app.get_cards())[1].selected);
+        assert_eq!(app.get_notification_text(), "Selected cards: 2");
+
+        // A plain click replaces the selection
+        app.invoke_card_select_requested(3, Default::default());
+        assert!(!rows(app.get_cards())[1].selected);
+        assert_eq!(app.get_notification_text(), "Selected cards: 4");
+
+        app.invoke_selection_changed(ModelRc::new(VecModel::from(Vec::<i32>::new())));
+        assert_eq!(app.get_notification_text(), "No cards selected");
+    }
+
+    #[test]
+    fn cards_move_with_their_ids() {
+        let app = demo();
+        assert_eq!(app.invoke_card_drop_index(2, 0.0), 2);
+        assert_eq!(app.invoke_card_drop_index(0, -1000.0), 0);
+
+        app.invoke_cards_reordered(0, 2);
+        assert_eq!(card_ids(&app), [2, 3, 1, 4, 5, 6]);
+        assert_eq!(app.get_notification_text(), "Moved card 1 to position 3");
+    }
+
+    #[test]
+    fn card_menu_actions_name_the_card() {
+        let app = demo();
+        let window = app.window().size().to_logical(app.window().scale_factor());
+        let (width, height) = (app.get_card_menu_width(), app.get_card_menu_height());
+        assert!(width > 0.0 && height > 0.0);
+
+        // Near the bottom-right corner, so the menu is moved back inside
+        app.invoke_card_menu_requested(2, window.width - 10.0, window.height - 10.0);
+        assert_eq!(app.get_card_menu_x(), window.width - width);
+        assert_eq!(app.get_card_menu_y(), window.height - height);
+
+        app.invoke_card_action(2, "Delete".into());
+        assert_eq!(app.get_notification_text(), "Delete card 2");
+    }
+
+    #[test]
+    fn the_switch_reports_its_last_state_after_the_debounce() {
+        let app = demo();
+        app.invoke_switch_toggled(true);
+        app.invoke_switch_toggled(false);
+        app.invoke_switch_toggled(true);
+        assert_eq!(app.get_notification_text(), "");
+
+        mock_elapsed_time(SWITCH_DEBOUNCE.as_millis() as u64);
+        assert_eq!(app.get_notification_text(), "Switch is now ON");
+        assert_eq!(app.get_feature_switch_label(), "Feature toggle, on");
+        assert_eq!(rows(app.get_toasts())[0].message, "Feature enabled");
+    }
+
+    #[test]
+    fn value_pickers_report_their_values() {
+        let app = demo();
+        // Snapped to the slider's 0.5 step
+        app.invoke_slider_changed(3.3);
+        assert_eq!(app.get_notification_text(), "Slider value: 3.5");
+
+        app.invoke_rating_changed(4.5);
+        assert_eq!(app.get_notification_text(), "Rated 4.5 of 5 stars");
+
+        app.invoke_dropdown_selected(2);
+        assert_eq!(app.get_notification_text(), "Selected size: Large");
+
+        app.invoke_date_picked(Date { year: 2024, month: 2, day: 29 });
+        assert_eq!(app.get_notification_text(), "Selected date: 2024-02-29");
+
+        app.invoke_tab_changed(4);
+        assert_eq!(app.get_notification_text(), "Switched to the List tab");
+
+        app.invoke_page_changed(3);
+        assert_eq!(app.get_notification_text(), "Showing page 3");
+    }
+
+    #[test]
+    fn out_of_range_picks_are_ignored() {
+        let app = demo();
+        app.invoke_dropdown_selected(9);
+        app.invoke_tab_changed(-1);
+        app.invoke_table_row_selected(99);
+        app.invoke_tree_node_activated(99);
+        assert_eq!(app.get_notification_text(), "");
+    }
+
+    #[test]
+    fn animation_speed_sets_the_motion_global() {
+        let app = demo();
+        app.invoke_animation_speed_selected(0);
+        assert_eq!(app.global::<Motion>().get_speed(), motion::SPEEDS[0]);
+        assert_eq!(app.get_animation_speed_index(), 0);
+
+        app.invoke_animation_speed_selected(99);
+        assert_eq!(app.get_animation_speed_index(), 0);
+    }
+
+    #[test]
+    fn follow_theme_clears_the_accent_override() {
+        let app = demo();
+        assert_ne!(app.get_primary_color().alpha(), 0);
+        app.invoke_follow_theme_accent();
+        assert_eq!(app.get_primary_color(), theme::override_color(None));
+    }
+
+    #[test]
+    fn the_table_sorts_and_reports_the_selected_row() {
+        let app = demo();
+        app.invoke_table_sort_requested(0, true);
+        let first = rows(app.get_table_rows())[0].cells.row_data(0).unwrap();
+        assert_eq!(first, "Accessibility");
+
+        app.invoke_table_row_selected(0);
+        assert_eq!(app.get_notification_text(), "Selected feature: Accessibility");
+
+        app.invoke_table_sort_requested(0, false);
+        assert_eq!(rows(app.get_table_rows())[0].cells.row_data(0).unwrap(), "WebAssembly");
+    }
+
+    #[test]
+    fn the_tree_expands_collapses_and_opens() {
+        let app = demo();
+        let labels = |app: &ComponentLibraryDemo| -> Vec<String> {
+            rows(app.get_tree_nodes()).into_iter().map(|node| node.label.to_string()).collect()
+        };
+        assert_eq!(labels(&app), ["component-library", "Cargo.toml", "build.rs", "src", "README.md"]);
+
+        app.invoke_tree_expand(3);
+        assert_eq!(labels(&app).len(), 10);
+        assert_eq!(labels(&app)[4], "main.rs");
+
+        app.invoke_tree_collapse(3);
+        assert_eq!(labels(&app).len(), 5);
+
+        let cargo_toml = rows(app.get_tree_nodes())[1].id;
+        app.invoke_tree_node_activated(cargo_toml);
+        assert_eq!(app.get_notification_text(), "Opened Cargo.toml");
+    }
+
+    #[test]
+    fn undo_and_redo_step_through_the_lines() {
+        let app = demo();
+        assert!(!app.get_can_undo());
+        app.invoke_page_changed(1);
+        app.invoke_page_changed(2);
+
+        app.invoke_undo();
+        assert_eq!(app.get_notification_text(), "Showing page 1");
+        assert!(app.get_can_redo());
+
+        app.invoke_redo();
+        assert_eq!(app.get_notification_text(), "Showing page 2");
+        assert!(!app.get_can_redo());
+    }
+
+    // The pure callbacks forward to the Rust functions unchanged
+    #[test]
+    fn pure_callbacks_match_their_functions() {
+        let app = demo();
+        assert_eq!(rows(app.invoke_visible_pages(5, 20, 1)), [1, 0, 4, 5, 6, 0, 20]);
+        assert_eq!(app.invoke_rating_at(2, 5.0, 20.0, 5, true), 2.5);
+        assert_eq!(app.invoke_split_drag_ratio(0.5, 100.0, 400.0, 0.0, 0.0), 0.75);
+
+        let grid = rows(app.invoke_month_grid(2024, 9, true));
+        assert_eq!(grid.len(), 42);
+        assert_eq!(grid[..7], [0, 0, 0, 0, 0, 0, 1]);
+
+        let parsed = app.invoke_parse_hex_color("#F80".into());
+        assert!(parsed.valid);
+        assert_eq!(app.invoke_color_to_hex(parsed.color), "#FF8800");
+        assert!(!app.invoke_parse_hex_color("#12".into()).valid);
+
+        let green = app.invoke_hsv_to_color(Hsv { hue: 120.0, saturation: 1.0, value: 1.0, alpha: 1.0 });
+        assert_eq!(green, slint::Color::from_rgb_u8(0, 255, 0));
+        assert_eq!(app.invoke_color_to_hsv(green).hue, 120.0);
+    }
+}
@@ -0,0 +1,63 @@
+//! Animation speed for the `Motion` global.
+
+use slint::{ComponentHandle, ModelRc, SharedString, VecModel};
+use slint_skills_core::clamp_animation_speed;
+
+use crate::{ComponentLibraryDemo, Motion};
+
+/// Speeds offered in the Toggles tab; 0 turns animations off.
+pub const SPEEDS: [f32; 5] = [0.0, 0.5, 1.0, 1.5, 2.0];
+
+/// Sets how fast every library animation plays, clamped to a range the
+/// components stay usable in. Returns the speed that was applied.
+pub fn set_speed(app: &ComponentLibraryDemo, speed: f32) -> f32 {
+    let speed = clamp_animation_speed(speed);
+    app.global::<Motion>().set_speed(speed);
+    speed
+}
+
+/// "Off", "0.5×", "1×", ...
+pub fn label(speed: f32) -> String {
+    if speed <= 0.0 {
+        "Off".to_string()
+    } else {
+        format!("{}×", speed)
+    }
+}
+
+/// Fills the speed dropdown and applies the picked speed.
+pub fn install(app: &ComponentLibraryDemo) {
+    let options: Vec<SharedString> = SPEEDS.iter().map(|&speed| label(speed).into()).collect();
+    app.set_animation_speed_options(ModelRc::new(VecModel::from(options)));
+
+    let current = app.global::<Motion>().get_speed();
+    let index = SPEEDS.iter().position(|&speed| speed == current).unwrap_or(2);
+    app.set_animation_speed_index(index as i32);
+
+    let app_weak = app.as_weak();
+    app.on_animation_speed_selected(move |index| {
+        let (Some(app), Some(&speed)) = (app_weak.upgrade(), SPEEDS.get(index as usize)) else {
+            return;
+        };
+        set_speed(&app, speed);
+        app.set_animation_speed_index(index);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels() {
+        let labels: Vec<String> = SPEEDS.iter().map(|&speed| label(speed)).collect();
+        assert_eq!(labels, ["Off", "0.5×", "1×", "1.5×", "2×"]);
+    }
+
+    #[test]
+    fn offered_speeds_are_in_range() {
+        for speed in SPEEDS {
+            assert_eq!(clamp_animation_speed(speed), speed);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Timestamped history of recent notifications, newest first.
+
+use std::ops::Range;
+use std::rc::Rc;
+
+use slint::{Model, ModelRc, VecModel};
+
+use crate::toast::{Clock, SystemClock};
+use crate::HistoryEntry;
+
+/// Entries the demo keeps.
+pub const DEFAULT_CAPACITY: usize = 20;
+
+/// Rows to drop from a newest-first list of `len` entries so that at most
+/// `capacity` remain: the oldest ones, at the end.
+pub fn evicted(len: usize, capacity: usize) -> Range<usize> {
+    len.min(capacity)..len
+}
+
+/// A bounded `VecModel<HistoryEntry>`; past `capacity` entries the oldest
+/// is dropped.
+pub struct NotificationLog<C: Clock = SystemClock> {
+    model: Rc<VecModel<HistoryEntry>>,
+    capacity: usize,
+    clock: C,
+}
+
+impl NotificationLog<SystemClock> {
+    pub fn new(capacity: usize) -> Rc<Self> {
+        Self::with_clock(capacity, SystemClock)
+    }
+}
+
+impl<C: Clock> NotificationLog<C> {
+    pub fn with_clock(capacity: usize, clock: C) -> Rc<Self> {
+        Rc::new(Self { model: Rc::new(VecModel::default()), capacity, clock })
+    }
+
+    /// The model to bind to `notification-log`.
+    pub fn model(&self) -> ModelRc<HistoryEntry> {
+        ModelRc::from(self.model.clone())
+    }
+
+    /// Records `message` with the current local time.
+    pub fn push(&self, message: &str) {
+        self.model.insert(
+            0,
+            HistoryEntry {
+                time: self.clock.local_now().format("%H:%M:%S").to_string().into(),
+                message: message.into(),
+            },
+        );
+        for row in evicted(self.model.row_count(), self.capacity).rev() {
+            self.model.remove(row);
+        }
+    }
+
+    pub fn clear(&self) {
+        self.model.set_vec(Vec::new());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::time::Instant;
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    // Starts at 09:30:00 local time and only moves when told to
+    struct MockClock(Cell<chrono::DateTime<chrono::Local>>);
+
+    impl MockClock {
+        fn new() -> Self {
+            Self(Cell::new(chrono::Local.with_ymd_and_hms(2024, 1, 15, 9, 30, 0).unwrap()))
+        }
+
+        fn advance(&self, seconds: i64) {
+            self.0.set(self.0.get() + chrono::Duration::seconds(seconds));
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Instant {
+            Instant::now()
+        }
+
+        fn local_now(&self) -> chrono::DateTime<chrono::Local> {
+            self.0.get()
+        }
+    }
+
+    fn entries<C: Clock>(log: &NotificationLog<C>) -> Vec<(String, String)> {
+        log.model().iter().map(|entry| (entry.time.to_string(), entry.message.to_string())).collect()
+    }
+
+    #[test]
+    fn entries_are_timestamped_newest_first() {
+        let log = NotificationLog::with_clock(DEFAULT_CAPACITY, MockClock::new());
+        log.push("Saved");
+        log.clock.advance(75);
+        log.push("Deleted");
+
+        assert_eq!(
+            entries(&log),
+            [("09:31:15".to_string(), "Deleted".to_string()), ("09:30:00".to_string(), "Saved".to_string())]
+        );
+    }
+
+    #[test]
+    fn clear_empties_the_log() {
+        let log = NotificationLog::with_clock(DEFAULT_CAPACITY, MockClock::new());
+        log.push("Saved");
+        log.push("Deleted");
+
+        log.clear();
+        assert_eq!(log.model().row_count(), 0);
+    }
+
+    #[test]
+    fn capacity_drops_the_oldest_entries() {
+        let log = NotificationLog::with_clock(3, MockClock::new());
+        for n in 1..=5 {
+            log.push(&format!("message {n}"));
+            log.clock.advance(1);
+        }
+
+        let messages: Vec<_> = entries(&log).into_iter().map(|(_, message)| message).collect();
+        assert_eq!(messages, ["message 5", "message 4", "message 3"]);
+    }
+
+    #[test]
+    fn evicted_rows() {
+        assert_eq!(evicted(2, 3), 2..2);
+        assert_eq!(evicted(3, 3), 3..3);
+        assert_eq!(evicted(5, 3), 3..5);
+        assert_eq!(evicted(1, 0), 0..1);
+    }
+}
@@ -0,0 +1,119 @@
+//! Page-number layout for the `Pagination` component.
+
+use slint::{ModelRc, VecModel};
+
+use crate::ComponentLibraryDemo;
+
+/// One slot in the page bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageToken {
+    Page(u32),
+    Ellipsis,
+}
+
+/// The slots to show for `current` of `total` pages (both 1-based):
+/// the first and last page, `window` pages either side of `current`, and an
+/// ellipsis for each gap. A gap of a single page shows that page instead,
+/// since an ellipsis would take the same room. `current` is clamped to
+/// `1..=total`; no pages gives no slots.
+///
+/// `visible_pages(5, 20, 1)` is `1 … 4 5 6 … 20`.
+pub fn visible_pages(current: u32, total: u32, window: u32) -> Vec<PageToken> {
+    if total == 0 {
+        return Vec::new();
+    }
+    let current = current.clamp(1, total);
+    let first = current.saturating_sub(window).max(1);
+    let last = current.saturating_add(window).min(total);
+
+    let mut pages: Vec<u32> = [1, total].into_iter().chain(first..=last).collect();
+    pages.sort_unstable();
+    pages.dedup();
+
+    let mut tokens = Vec::with_capacity(pages.len() + 2);
+    let mut previous = 0;
+    for page in pages {
+        match page - previous {
+            0 | 1 => {}
+            2 => tokens.push(PageToken::Page(previous + 1)),
+            _ => tokens.push(PageToken::Ellipsis),
+        }
+        tokens.push(PageToken::Page(page));
+        previous = page;
+    }
+    tokens
+}
+
+/// Implements the demo's `visible-pages` callback, which every `Pagination`
+/// in it forwards to. Ellipses are sent as 0.
+pub fn install(app: &ComponentLibraryDemo) {
+    app.on_visible_pages(|current, total, window| {
+        let tokens: Vec<i32> = visible_pages(current.max(0) as u32, total.max(0) as u32, window.max(0) as u32)
+            .into_iter()
+            .map(|token| match token {
+                PageToken::Page(page) => page as i32,
+                PageToken::Ellipsis => 0,
+            })
+            .collect();
+        ModelRc::new(VecModel::from(tokens))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The bar as it reads, e.g. "1 … 4 5 6 … 20"
+    fn bar(current: u32, total: u32, window: u32) -> String {
+        visible_pages(current, total, window)
+            .into_iter()
+            .map(|token| match token {
+                PageToken::Page(page) => page.to_string(),
+                PageToken::Ellipsis => "…".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn gaps_on_both_sides_of_a_middle_page() {
+        assert_eq!(bar(5, 20, 1), "1 … 4 5 6 … 20");
+        assert_eq!(bar(10, 20, 2), "1 … 8 9 10 11 12 … 20");
+    }
+
+    #[test]
+    fn first_and_last_pages() {
+        assert_eq!(bar(1, 20, 1), "1 2 … 20");
+        assert_eq!(bar(20, 20, 1), "1 … 19 20");
+    }
+
+    // One missing page is shown rather than replaced by an ellipsis
+    #[test]
+    fn single_page_gaps_are_filled_in() {
+        assert_eq!(bar(3, 20, 1), "1 2 3 4 … 20");
+        assert_eq!(bar(4, 20, 1), "1 2 3 4 5 … 20");
+        assert_eq!(bar(4, 7, 1), "1 2 3 4 5 6 7");
+        assert_eq!(bar(5, 7, 1), "1 … 4 5 6 7");
+    }
+
+    #[test]
+    fn small_totals_show_every_page() {
+        assert_eq!(bar(1, 0, 1), "");
+        assert_eq!(bar(1, 1, 1), "1");
+        assert_eq!(bar(1, 2, 0), "1 2");
+        assert_eq!(bar(2, 5, 1), "1 2 3 4 5");
+        assert_eq!(bar(1, 5, 10), "1 2 3 4 5");
+    }
+
+    #[test]
+    fn out_of_range_pages_are_clamped() {
+        assert_eq!(bar(0, 20, 1), bar(1, 20, 1));
+        assert_eq!(bar(99, 20, 1), bar(20, 20, 1));
+        assert_eq!(bar(u32::MAX, u32::MAX, 1), "1 … 4294967294 4294967295");
+    }
+
+    #[test]
+    fn a_zero_window_keeps_only_the_ends_and_current() {
+        assert_eq!(bar(10, 20, 0), "1 … 10 … 20");
+    }
+}
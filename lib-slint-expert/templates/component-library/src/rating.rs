@@ -0,0 +1,66 @@
+//! Pointer-to-value mapping for the `Rating` component.
+
+use crate::ComponentLibraryDemo;
+
+/// The rating a click at `x` within star `star` (0-based, `star_width`
+/// wide) stands for. The left half of a star gives a half star when
+/// `half_stars` is set; the result stays within `0.5..=max` (or `1..=max`).
+pub fn rating_at(star: u32, x: f32, star_width: f32, max: u32, half_stars: bool) -> f32 {
+    let max = max.max(1) as f32;
+    let in_left_half = star_width > 0.0 && x < star_width / 2.0;
+    let value = if half_stars && in_left_half { star as f32 + 0.5 } else { star as f32 + 1.0 };
+    let lowest = if half_stars { 0.5 } else { 1.0 };
+    value.clamp(lowest, max)
+}
+
+/// Implements the demo's `rating-at` callback, which every `Rating` in it
+/// forwards to.
+pub fn install(app: &ComponentLibraryDemo) {
+    app.on_rating_at(|star, x, star_width, max, half_stars| {
+        rating_at(star.max(0) as u32, x, star_width, max.max(0) as u32, half_stars)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn halves_of_a_star() {
+        assert_eq!(rating_at(2, 5.0, 20.0, 5, true), 2.5);
+        assert_eq!(rating_at(2, 9.9, 20.0, 5, true), 2.5);
+        assert_eq!(rating_at(2, 10.0, 20.0, 5, true), 3.0);
+        assert_eq!(rating_at(2, 19.0, 20.0, 5, true), 3.0);
+    }
+
+    #[test]
+    fn whole_stars_ignore_the_half() {
+        assert_eq!(rating_at(2, 5.0, 20.0, 5, false), 3.0);
+        assert_eq!(rating_at(2, 15.0, 20.0, 5, false), 3.0);
+    }
+
+    #[test]
+    fn the_first_and_last_star() {
+        assert_eq!(rating_at(0, 0.0, 20.0, 5, true), 0.5);
+        assert_eq!(rating_at(0, 0.0, 20.0, 5, false), 1.0);
+        assert_eq!(rating_at(4, 5.0, 20.0, 5, true), 4.5);
+        assert_eq!(rating_at(4, 15.0, 20.0, 5, true), 5.0);
+    }
+
+    #[test]
+    fn values_stay_within_the_scale() {
+        // A star past the end, e.g. while `max` shrinks
+        assert_eq!(rating_at(7, 15.0, 20.0, 5, true), 5.0);
+        assert_eq!(rating_at(7, 5.0, 20.0, 5, true), 5.0);
+        // No stars still gives one
+        assert_eq!(rating_at(0, 15.0, 20.0, 0, false), 1.0);
+        assert_eq!(rating_at(0, 5.0, 20.0, 0, true), 0.5);
+    }
+
+    // Before layout a star has no width; clicks count as whole stars
+    #[test]
+    fn zero_width_stars_give_whole_values() {
+        assert_eq!(rating_at(1, 0.0, 0.0, 5, true), 2.0);
+        assert_eq!(rating_at(1, -3.0, -10.0, 5, true), 2.0);
+    }
+}
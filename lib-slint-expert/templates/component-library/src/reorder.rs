@@ -0,0 +1,106 @@
+//! Drag-to-reorder math for an evenly spaced row of items.
+
+use slint::VecModel;
+
+/// Row an item dragged from `from` by `offset` lands in, for items `pitch`
+/// apart (item width plus spacing) in a row of `len`. The item takes the
+/// next slot once it has been dragged halfway across it.
+pub fn drop_index(from: usize, offset: f32, pitch: f32, len: usize) -> usize {
+    if len == 0 || pitch <= 0.0 || !offset.is_finite() {
+        return from;
+    }
+    let target = from as f32 + (offset / pitch).round();
+    target.clamp(0.0, (len - 1) as f32) as usize
+}
+
+/// Where the row at `index` ends up after the row at `from` moves to `to`.
+pub fn moved_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < to && (from + 1..=to).contains(&index) {
+        index - 1
+    } else if to < from && (to..from).contains(&index) {
+        index + 1
+    } else {
+        index
+    }
+}
+
+/// Moves the row at `from` to `to`, shifting the rows in between. Out of
+/// range rows leave the model unchanged.
+pub fn move_row<T: Clone + 'static>(model: &VecModel<T>, from: usize, to: usize) {
+    use slint::Model;
+
+    if from == to || from >= model.row_count() || to >= model.row_count() {
+        return;
+    }
+    let row = model.remove(from);
+    model.insert(to, row);
+}
+
+#[cfg(test)]
+mod tests {
+    use slint::Model;
+
+    use super::*;
+
+    #[test]
+    fn drops_after_half_a_pitch() {
+        assert_eq!(drop_index(2, 0.0, 100.0, 6), 2);
+        assert_eq!(drop_index(2, 49.0, 100.0, 6), 2);
+        assert_eq!(drop_index(2, 50.0, 100.0, 6), 3);
+        assert_eq!(drop_index(2, -150.0, 100.0, 6), 0);
+        assert_eq!(drop_index(2, 210.0, 100.0, 6), 4);
+    }
+
+    #[test]
+    fn drops_stay_within_the_row() {
+        assert_eq!(drop_index(0, -500.0, 100.0, 6), 0);
+        assert_eq!(drop_index(5, 900.0, 100.0, 6), 5);
+    }
+
+    #[test]
+    fn degenerate_input_keeps_the_item_in_place() {
+        assert_eq!(drop_index(2, 300.0, 0.0, 6), 2);
+        assert_eq!(drop_index(2, f32::NAN, 100.0, 6), 2);
+        assert_eq!(drop_index(0, 300.0, 100.0, 0), 0);
+    }
+
+    #[test]
+    fn other_rows_shift_around_the_moved_one() {
+        // 0 1 2 3 4 with 1 moved to 3: 0 2 3 1 4
+        let after: Vec<usize> = (0..5).map(|index| moved_index(index, 1, 3)).collect();
+        assert_eq!(after, [0, 3, 1, 2, 4]);
+
+        // and back
+        let after: Vec<usize> = (0..5).map(|index| moved_index(index, 3, 1)).collect();
+        assert_eq!(after, [0, 2, 3, 1, 4]);
+    }
+
+    #[test]
+    fn moving_to_the_same_index_changes_nothing() {
+        assert!((0..5).all(|index| moved_index(index, 2, 2) == index));
+
+        let model = VecModel::from(vec!["a", "b", "c"]);
+        move_row(&model, 1, 1);
+        assert_eq!(model.iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn move_row_matches_moved_index() {
+        let model = VecModel::from(vec!["a", "b", "c", "d"]);
+        move_row(&model, 0, 2);
+        assert_eq!(model.iter().collect::<Vec<_>>(), ["b", "c", "a", "d"]);
+
+        move_row(&model, 3, 0);
+        assert_eq!(model.iter().collect::<Vec<_>>(), ["d", "b", "c", "a"]);
+    }
+
+    #[test]
+    fn out_of_range_moves_are_ignored() {
+        let model = VecModel::from(vec!["a", "b"]);
+        move_row(&model, 0, 2);
+        move_row(&model, 5, 0);
+        assert_eq!(model.iter().collect::<Vec<_>>(), ["a", "b"]);
+    }
+}
@@ -0,0 +1,8 @@
+//! Query matching for the demo's search bar.
+
+/// Whether `item` contains `query`, ignoring case and the whitespace around
+/// the query. An empty query matches everything.
+pub fn matches(item: &str, query: &str) -> bool {
+    let query = query.trim();
+    query.is_empty() || item.to_lowercase().contains(&query.to_lowercase())
+}
@@ -0,0 +1,160 @@
+//! Click selection over a list of rows, independent of the UI.
+//!
+//! Follows the usual desktop rules: a plain click selects one row, Ctrl (Cmd
+//! on macOS) toggles a row, and Shift selects the range from the last plain
+//! or Ctrl click, the anchor. Ctrl+Shift adds the range to the selection
+//! instead of replacing it.
+
+use std::collections::BTreeSet;
+
+use crate::reorder;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SelectionModel {
+    len: usize,
+    selected: BTreeSet<usize>,
+    anchor: Option<usize>,
+}
+
+impl SelectionModel {
+    /// An empty selection over `len` rows.
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            ..Self::default()
+        }
+    }
+
+    /// Applies a click on `row`. Rows past the end are ignored.
+    pub fn click(&mut self, row: usize, ctrl: bool, shift: bool) {
+        if row >= self.len {
+            return;
+        }
+
+        match (ctrl, shift, self.anchor) {
+            (_, true, Some(anchor)) => {
+                if !ctrl {
+                    self.selected.clear();
+                }
+                self.selected.extend(anchor.min(row)..=anchor.max(row));
+            }
+            (true, _, _) => {
+                if !self.selected.remove(&row) {
+                    self.selected.insert(row);
+                }
+                self.anchor = Some(row);
+            }
+            _ => {
+                self.selected.clear();
+                self.selected.insert(row);
+                self.anchor = Some(row);
+            }
+        }
+    }
+
+    pub fn is_selected(&self, row: usize) -> bool {
+        self.selected.contains(&row)
+    }
+
+    /// Selected rows in ascending order.
+    pub fn selected(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected.iter().copied()
+    }
+
+    /// Keeps the same rows selected after the row at `from` moves to `to`.
+    pub fn move_row(&mut self, from: usize, to: usize) {
+        self.selected = self.selected.iter().map(|&row| reorder::moved_index(row, from, to)).collect();
+        self.anchor = self.anchor.map(|row| reorder::moved_index(row, from, to));
+    }
+
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selected(model: &SelectionModel) -> Vec<usize> {
+        model.selected().collect()
+    }
+
+    #[test]
+    fn plain_clicks_select_one_row() {
+        let mut model = SelectionModel::new(6);
+        model.click(2, false, false);
+        model.click(4, false, false);
+        assert_eq!(selected(&model), [4]);
+    }
+
+    #[test]
+    fn ctrl_clicks_toggle_rows() {
+        let mut model = SelectionModel::new(6);
+        model.click(1, true, false);
+        model.click(3, true, false);
+        assert_eq!(selected(&model), [1, 3]);
+
+        model.click(1, true, false);
+        assert_eq!(selected(&model), [3]);
+    }
+
+    #[test]
+    fn shift_clicks_select_the_range_from_the_anchor() {
+        let mut model = SelectionModel::new(6);
+        model.click(1, false, false);
+        model.click(4, false, true);
+        assert_eq!(selected(&model), [1, 2, 3, 4]);
+
+        // The anchor stays put, so another Shift click replaces the range,
+        // also when it goes the other way
+        model.click(0, false, true);
+        assert_eq!(selected(&model), [0, 1]);
+    }
+
+    #[test]
+    fn ctrl_shift_adds_the_range() {
+        let mut model = SelectionModel::new(8);
+        model.click(0, false, false);
+        model.click(5, true, false);
+        model.click(7, true, true);
+        assert_eq!(selected(&model), [0, 5, 6, 7]);
+    }
+
+    #[test]
+    fn shift_without_an_anchor_selects_one_row() {
+        let mut model = SelectionModel::new(6);
+        model.click(3, false, true);
+        assert_eq!(selected(&model), [3]);
+    }
+
+    #[test]
+    fn rows_past_the_end_are_ignored() {
+        let mut model = SelectionModel::new(3);
+        model.click(1, false, false);
+        model.click(3, false, false);
+        model.click(9, true, true);
+        assert_eq!(selected(&model), [1]);
+    }
+
+    #[test]
+    fn moving_a_row_keeps_the_selection_on_it() {
+        let mut model = SelectionModel::new(5);
+        model.click(0, true, false);
+        model.click(3, true, false);
+
+        model.move_row(0, 4);
+        assert_eq!(selected(&model), [2, 4]);
+        assert!(model.is_selected(4));
+    }
+
+    #[test]
+    fn clear_drops_the_anchor_too() {
+        let mut model = SelectionModel::new(5);
+        model.click(1, false, false);
+        model.clear();
+        model.click(3, false, true);
+        assert_eq!(selected(&model), [3]);
+    }
+}
@@ -0,0 +1,74 @@
+//! Value snapping shared with the `Slider` component.
+
+/// Snaps `value` to the nearest multiple of `step` counted from `min`, then
+/// clamps it to `[min, max]`. A non-positive `step` only clamps.
+///
+/// The result is rounded to the precision of `step`, so fractional steps such
+/// as 0.1 don't come back as 0.30000004.
+pub fn snap_value(value: f32, min: f32, max: f32, step: f32) -> f32 {
+    let (min, max) = if min <= max { (min, max) } else { (max, min) };
+
+    let snapped = if step > 0.0 {
+        let steps = ((value - min) / step).round();
+        let decimals = decimal_places(step);
+        let factor = 10f32.powi(decimals as i32);
+        ((min + steps * step) * factor).round() / factor
+    } else {
+        value
+    };
+
+    snapped.clamp(min, max)
+}
+
+// Number of decimal places needed to represent `step`, capped at 6.
+fn decimal_places(step: f32) -> u32 {
+    let mut decimals = 0;
+    let mut scaled = step;
+    while decimals < 6 && (scaled - scaled.round()).abs() > 1e-4 {
+        scaled *= 10.0;
+        decimals += 1;
+    }
+    decimals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snaps_to_the_nearest_step() {
+        assert_eq!(snap_value(42.0, 0.0, 100.0, 5.0), 40.0);
+        assert_eq!(snap_value(43.0, 0.0, 100.0, 5.0), 45.0);
+        // Steps count from `min`, not from zero
+        assert_eq!(snap_value(12.0, 1.0, 100.0, 5.0), 11.0);
+    }
+
+    #[test]
+    fn fractional_steps_come_back_exact() {
+        assert_eq!(snap_value(0.31, 0.0, 1.0, 0.1), 0.3);
+        assert_eq!(snap_value(0.74, 0.0, 1.0, 0.25), 0.75);
+    }
+
+    #[test]
+    fn clamps_to_the_bounds() {
+        assert_eq!(snap_value(-10.0, 0.0, 100.0, 5.0), 0.0);
+        assert_eq!(snap_value(250.0, 0.0, 100.0, 5.0), 100.0);
+        // The bounds themselves are kept even when they are off the step grid
+        assert_eq!(snap_value(99.0, 0.0, 99.0, 5.0), 99.0);
+        assert_eq!(snap_value(0.0, 0.0, 100.0, 5.0), 0.0);
+        assert_eq!(snap_value(100.0, 0.0, 100.0, 5.0), 100.0);
+    }
+
+    #[test]
+    fn swapped_bounds_still_clamp() {
+        assert_eq!(snap_value(150.0, 100.0, 0.0, 10.0), 100.0);
+        assert_eq!(snap_value(-5.0, 100.0, 0.0, 10.0), 0.0);
+    }
+
+    #[test]
+    fn non_positive_step_only_clamps() {
+        assert_eq!(snap_value(42.7, 0.0, 100.0, 0.0), 42.7);
+        assert_eq!(snap_value(42.7, 0.0, 100.0, -1.0), 42.7);
+        assert_eq!(snap_value(142.7, 0.0, 100.0, 0.0), 100.0);
+    }
+}
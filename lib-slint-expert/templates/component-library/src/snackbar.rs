@@ -0,0 +1,183 @@
+//! Single-slot snackbar behind the demo's `Snackbar`.
+
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use std::time::Duration;
+
+use slint::{Model, ModelRc, VecModel};
+
+use crate::SnackbarData;
+
+/// How long a snackbar without an action stays up.
+pub const PLAIN_DURATION: Duration = Duration::from_secs(5);
+/// Snackbars with an action stay longer, so there is time to use it.
+pub const ACTION_DURATION: Duration = Duration::from_secs(8);
+
+/// Callback run when the snackbar's action button is pressed.
+pub type Action = Box<dyn Fn()>;
+
+/// Holds the one visible snackbar. Showing another replaces it, along with
+/// its action and its dismiss timer.
+pub struct SnackbarHost {
+    model: Rc<VecModel<SnackbarData>>,
+    action: RefCell<Option<Action>>,
+    next_id: Cell<i32>,
+    timer: slint::Timer,
+}
+
+impl SnackbarHost {
+    pub fn new() -> Rc<Self> {
+        Rc::new(Self {
+            model: Rc::new(VecModel::default()),
+            action: RefCell::new(None),
+            next_id: Cell::new(0),
+            timer: slint::Timer::default(),
+        })
+    }
+
+    /// The model to bind to the `for` that draws the `Snackbar`; it has at
+    /// most one row.
+    pub fn model(&self) -> ModelRc<SnackbarData> {
+        ModelRc::from(self.model.clone())
+    }
+
+    /// Shows `message`, with an action button when `action` is given, and
+    /// returns its id. Whatever was showing before is dropped without
+    /// running its action.
+    pub fn show(self: &Rc<Self>, message: &str, action: Option<(&str, Action)>) -> i32 {
+        let id = self.next_id.replace(self.next_id.get() + 1);
+        let (label, action) = match action {
+            Some((label, action)) => (label, Some(action)),
+            None => ("", None),
+        };
+        let duration = if action.is_some() { ACTION_DURATION } else { PLAIN_DURATION };
+
+        *self.action.borrow_mut() = action;
+        self.model.set_vec(vec![SnackbarData {
+            id,
+            message: message.into(),
+            action_label: label.into(),
+        }]);
+
+        // Restarting the timer cancels the previous snackbar's dismissal
+        let host: Weak<Self> = Rc::downgrade(self);
+        self.timer.start(slint::TimerMode::SingleShot, duration, move || {
+            if let Some(host) = host.upgrade() {
+                host.dismiss(id);
+            }
+        });
+
+        id
+    }
+
+    /// The id of the visible snackbar, if any.
+    pub fn current(&self) -> Option<i32> {
+        self.model.iter().next().map(|snackbar| snackbar.id)
+    }
+
+    /// Runs the action of snackbar `id` and hides it. Ignored if `id` has
+    /// already been replaced or dismissed.
+    pub fn invoke_action(&self, id: i32) {
+        if self.current() != Some(id) {
+            return;
+        }
+        let action = self.action.borrow_mut().take();
+        self.dismiss(id);
+        if let Some(action) = action {
+            action();
+        }
+    }
+
+    /// Hides snackbar `id` if it is still the visible one.
+    pub fn dismiss(&self, id: i32) {
+        if self.current() != Some(id) {
+            return;
+        }
+        self.timer.stop();
+        self.action.borrow_mut().take();
+        self.model.set_vec(Vec::new());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use i_slint_backend_testing::mock_elapsed_time;
+
+    use super::*;
+
+    fn host() -> Rc<SnackbarHost> {
+        i_slint_backend_testing::init_no_event_loop();
+        SnackbarHost::new()
+    }
+
+    // An action that counts how often it ran
+    fn counted() -> (Action, Rc<Cell<u32>>) {
+        let runs = Rc::new(Cell::new(0));
+        let counter = runs.clone();
+        (Box::new(move || counter.set(counter.get() + 1)), runs)
+    }
+
+    fn messages(host: &SnackbarHost) -> Vec<String> {
+        host.model().iter().map(|snackbar| snackbar.message.to_string()).collect()
+    }
+
+    #[test]
+    fn a_new_snackbar_replaces_the_visible_one() {
+        let host = host();
+        let (undo, undo_runs) = counted();
+        let first = host.show("Deleted", Some(("Undo", undo)));
+        let second = host.show("Saved", None);
+
+        assert_ne!(first, second);
+        assert_eq!(messages(&host), ["Saved"]);
+        assert_eq!(host.current(), Some(second));
+
+        // The replaced action is gone, not run
+        host.invoke_action(first);
+        assert_eq!(undo_runs.get(), 0);
+        assert_eq!(messages(&host), ["Saved"]);
+    }
+
+    #[test]
+    fn the_action_runs_once_and_hides_the_snackbar() {
+        let host = host();
+        let (undo, runs) = counted();
+        let id = host.show("Deleted", Some(("Undo", undo)));
+        assert_eq!(host.model().row_data(0).unwrap().action_label, "Undo");
+
+        host.invoke_action(id);
+        host.invoke_action(id);
+        assert_eq!(runs.get(), 1);
+        assert_eq!(host.current(), None);
+    }
+
+    #[test]
+    fn snackbars_dismiss_themselves() {
+        let host = host();
+        host.show("Saved", None);
+        mock_elapsed_time(PLAIN_DURATION.as_millis() as u64 - 1);
+        assert_eq!(messages(&host), ["Saved"]);
+        mock_elapsed_time(1);
+        assert!(messages(&host).is_empty());
+
+        let (undo, _) = counted();
+        host.show("Deleted", Some(("Undo", undo)));
+        mock_elapsed_time(PLAIN_DURATION.as_millis() as u64);
+        assert_eq!(messages(&host), ["Deleted"]);
+        mock_elapsed_time((ACTION_DURATION - PLAIN_DURATION).as_millis() as u64);
+        assert!(messages(&host).is_empty());
+    }
+
+    // The first snackbar's timer must not cut the second one short
+    #[test]
+    fn replacing_restarts_the_dismiss_timer() {
+        let host = host();
+        host.show("first", None);
+        mock_elapsed_time(4000);
+        host.show("second", None);
+        mock_elapsed_time(1000);
+        assert_eq!(messages(&host), ["second"]);
+        mock_elapsed_time(4000);
+        assert!(messages(&host).is_empty());
+    }
+}
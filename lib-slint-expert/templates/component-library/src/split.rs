@@ -0,0 +1,73 @@
+//! Divider math for the `SplitPane` component.
+
+use crate::ComponentLibraryDemo;
+
+/// The ratio after dragging the divider by `delta` along a pane whose two
+/// sides share `extent` (the size minus the divider). The result keeps
+/// each side at least `min_first` / `min_second` long. When both minimums
+/// don't fit, the space is shared in proportion to them. A zero (or
+/// non-finite) delta just clamps `ratio`.
+pub fn dragged_ratio(ratio: f32, delta: f32, extent: f32, min_first: f32, min_second: f32) -> f32 {
+    if extent <= 0.0 || !extent.is_finite() {
+        return 0.5;
+    }
+    let min_first = min_first.max(0.0);
+    let min_second = min_second.max(0.0);
+    if min_first + min_second >= extent {
+        return if min_first + min_second > 0.0 { min_first / (min_first + min_second) } else { 0.5 };
+    }
+
+    let ratio = if ratio.is_finite() { ratio } else { 0.5 };
+    let delta = if delta.is_finite() { delta } else { 0.0 };
+    (ratio + delta / extent).clamp(min_first / extent, 1.0 - min_second / extent)
+}
+
+/// Implements the demo's `split-drag-ratio` callback, which every
+/// `SplitPane` in it forwards to.
+pub fn install(app: &ComponentLibraryDemo) {
+    app.on_split_drag_ratio(dragged_ratio);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drags_move_the_ratio_by_the_share_of_the_extent() {
+        assert_eq!(dragged_ratio(0.5, 100.0, 400.0, 0.0, 0.0), 0.75);
+        assert_eq!(dragged_ratio(0.5, -100.0, 400.0, 0.0, 0.0), 0.25);
+        assert_eq!(dragged_ratio(0.25, 0.0, 400.0, 0.0, 0.0), 0.25);
+    }
+
+    #[test]
+    fn the_ratio_stops_at_the_ends() {
+        assert_eq!(dragged_ratio(0.5, 1000.0, 400.0, 0.0, 0.0), 1.0);
+        assert_eq!(dragged_ratio(0.5, -1000.0, 400.0, 0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn each_side_keeps_its_minimum() {
+        assert_eq!(dragged_ratio(0.5, -1000.0, 400.0, 100.0, 50.0), 0.25);
+        assert_eq!(dragged_ratio(0.5, 1000.0, 400.0, 100.0, 50.0), 0.875);
+        // A zero delta pulls an out-of-range ratio back in
+        assert_eq!(dragged_ratio(0.1, 0.0, 400.0, 100.0, 50.0), 0.25);
+        assert_eq!(dragged_ratio(2.0, 0.0, 400.0, 100.0, 50.0), 0.875);
+    }
+
+    #[test]
+    fn minimums_that_do_not_fit_share_the_space() {
+        assert_eq!(dragged_ratio(0.5, 50.0, 200.0, 300.0, 100.0), 0.75);
+        assert_eq!(dragged_ratio(0.5, -50.0, 100.0, 50.0, 50.0), 0.5);
+    }
+
+    #[test]
+    fn degenerate_input_falls_back_to_the_middle() {
+        assert_eq!(dragged_ratio(0.3, 10.0, 0.0, 0.0, 0.0), 0.5);
+        assert_eq!(dragged_ratio(0.3, 10.0, -5.0, 0.0, 0.0), 0.5);
+        assert_eq!(dragged_ratio(0.3, 10.0, f32::INFINITY, 0.0, 0.0), 0.5);
+        assert_eq!(dragged_ratio(f32::NAN, 0.0, 400.0, 0.0, 0.0), 0.5);
+        assert_eq!(dragged_ratio(0.25, f32::NAN, 400.0, 0.0, 0.0), 0.25);
+        // Negative minimums count as none
+        assert_eq!(dragged_ratio(0.5, -1000.0, 400.0, -10.0, -10.0), 0.0);
+    }
+}
@@ -0,0 +1,217 @@
+//! Shared demo state with change listeners.
+//!
+//! Callbacks record what happened through [`Store::update`]; subscribers
+//! push the derived values (such as the notification text) back into Slint
+//! properties. Single-threaded: worker threads post to the event loop first.
+
+use std::cell::{Ref, RefCell};
+use std::rc::Rc;
+
+/// The most recent user-visible thing that happened.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    PrimaryRunning,
+    PrimaryCancelled,
+    PrimaryCompleted,
+    SecondaryClicked,
+    CardClicked(i32),
+    CardAction { card: i32, action: String },
+    CardMoved { card: i32, position: i32 },
+    /// Ids of the selected cards, in row order.
+    SelectionChanged(Vec<i32>),
+    SwitchToggled(bool),
+    SliderChanged(f32),
+    RatingChanged(f32),
+    SizeSelected(String),
+    DateSelected { year: i32, month: i32, day: i32 },
+    TabChanged(String),
+    FeatureSelected(String),
+    PageChanged(i32),
+    ListItemClicked { item: String, row: i32 },
+    NodeActivated(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AppState {
+    pub last_event: Option<Event>,
+    pub switch_on: bool,
+    pub slider_value: f32,
+    pub selected_size: Option<String>,
+    pub current_tab: Option<String>,
+}
+
+impl AppState {
+    /// Records `event` and the state it implies.
+    pub fn apply(&mut self, event: Event) {
+        match &event {
+            Event::SwitchToggled(on) => self.switch_on = *on,
+            Event::SliderChanged(value) => self.slider_value = *value,
+            Event::SizeSelected(size) => self.selected_size = Some(size.clone()),
+            Event::TabChanged(title) => self.current_tab = Some(title.clone()),
+            _ => {}
+        }
+        self.last_event = Some(event);
+    }
+
+    /// What screen readers announce for the feature switch.
+    pub fn switch_label(&self) -> String {
+        format!("Feature toggle, {}", if self.switch_on { "on" } else { "off" })
+    }
+
+    /// The status line shown under the demo; derived, never stored.
+    pub fn notification_text(&self) -> String {
+        let Some(event) = &self.last_event else {
+            return String::new();
+        };
+
+        match event {
+            Event::PrimaryRunning => "Primary action running...".to_string(),
+            Event::PrimaryCancelled => "Primary action cancelled".to_string(),
+            Event::PrimaryCompleted => "Primary action completed!".to_string(),
+            Event::SecondaryClicked => "Secondary button clicked!".to_string(),
+            Event::CardClicked(index) => format!("Card {} clicked!", index),
+            Event::CardAction { card, action } => format!("{} card {}", action, card),
+            Event::CardMoved { card, position } => format!("Moved card {} to position {}", card, position),
+            Event::SelectionChanged(cards) if cards.is_empty() => "No cards selected".to_string(),
+            Event::SelectionChanged(cards) => {
+                let cards: Vec<String> = cards.iter().map(i32::to_string).collect();
+                format!("Selected cards: {}", cards.join(", "))
+            }
+            Event::SwitchToggled(on) => format!("Switch is now {}", if *on { "ON" } else { "OFF" }),
+            Event::SliderChanged(value) => format!("Slider value: {}", value),
+            Event::RatingChanged(value) => format!("Rated {} of 5 stars", value),
+            Event::SizeSelected(size) => format!("Selected size: {}", size),
+            Event::DateSelected { year, month, day } => format!("Selected date: {}-{:02}-{:02}", year, month, day),
+            Event::TabChanged(title) => format!("Switched to the {} tab", title),
+            Event::FeatureSelected(feature) => format!("Selected feature: {}", feature),
+            Event::PageChanged(page) => format!("Showing page {}", page),
+            Event::ListItemClicked { item, row } => format!("Clicked {} (row {})", item, row),
+            Event::NodeActivated(label) => format!("Opened {}", label),
+        }
+    }
+}
+
+type Listener = Box<dyn Fn(&AppState)>;
+
+/// Owns the [`AppState`] and notifies subscribers after every change.
+#[derive(Default)]
+pub struct Store {
+    state: RefCell<AppState>,
+    listeners: RefCell<Vec<Listener>>,
+}
+
+impl Store {
+    pub fn new() -> Rc<Self> {
+        Rc::new(Self::default())
+    }
+
+    pub fn state(&self) -> Ref<'_, AppState> {
+        self.state.borrow()
+    }
+
+    /// Calls `listener` with the current state now and after each change.
+    /// Listeners must not subscribe from inside a notification.
+    pub fn subscribe(&self, listener: impl Fn(&AppState) + 'static) {
+        listener(&self.state.borrow());
+        self.listeners.borrow_mut().push(Box::new(listener));
+    }
+
+    /// Applies `change` and notifies subscribers if the state differs.
+    /// Listeners may call `update` again; they see a snapshot, so nothing
+    /// is borrowed while they run.
+    pub fn update(&self, change: impl FnOnce(&mut AppState)) {
+        let snapshot = {
+            let mut state = self.state.borrow_mut();
+            let before = state.clone();
+            change(&mut state);
+            if *state == before {
+                return;
+            }
+            state.clone()
+        };
+
+        for listener in self.listeners.borrow().iter() {
+            listener(&snapshot);
+        }
+    }
+
+    /// Shorthand for recording one [`Event`].
+    pub fn dispatch(&self, event: Event) {
+        self.update(|state| state.apply(event));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorder(store: &Store) -> Rc<RefCell<Vec<String>>> {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = seen.clone();
+        store.subscribe(move |state| sink.borrow_mut().push(state.notification_text()));
+        seen
+    }
+
+    #[test]
+    fn subscribers_get_the_current_state_then_every_change() {
+        let store = Store::new();
+        store.dispatch(Event::SecondaryClicked);
+        let seen = recorder(&store);
+
+        store.dispatch(Event::SwitchToggled(true));
+        store.dispatch(Event::CardClicked(2));
+
+        assert_eq!(*seen.borrow(), ["Secondary button clicked!", "Switch is now ON", "Card 2 clicked!"]);
+        assert!(store.state().switch_on);
+    }
+
+    #[test]
+    fn unchanged_state_notifies_nobody() {
+        let store = Store::new();
+        let seen = recorder(&store);
+
+        store.dispatch(Event::SliderChanged(0.5));
+        store.dispatch(Event::SliderChanged(0.5));
+        store.update(|_| {});
+
+        assert_eq!(*seen.borrow(), ["", "Slider value: 0.5"]);
+    }
+
+    #[test]
+    fn every_subscriber_is_notified() {
+        let store = Store::new();
+        let first = recorder(&store);
+        let second = recorder(&store);
+
+        store.dispatch(Event::PageChanged(3));
+        assert_eq!(first.borrow().last().unwrap(), "Showing page 3");
+        assert_eq!(second.borrow().last().unwrap(), "Showing page 3");
+    }
+
+    // A listener reacting with another update sees both changes in order
+    #[test]
+    fn listeners_may_update_again() {
+        let store = Store::new();
+        let seen = recorder(&store);
+        let chained = store.clone();
+        store.subscribe(move |state| {
+            if state.last_event == Some(Event::PrimaryRunning) {
+                chained.dispatch(Event::PrimaryCompleted);
+            }
+        });
+
+        store.dispatch(Event::PrimaryRunning);
+        assert_eq!(*seen.borrow(), ["", "Primary action running...", "Primary action completed!"]);
+    }
+
+    #[test]
+    fn apply_keeps_the_derived_fields() {
+        let mut state = AppState::default();
+        state.apply(Event::SizeSelected("Large".to_string()));
+        state.apply(Event::TabChanged("Settings".to_string()));
+
+        assert_eq!(state.selected_size.as_deref(), Some("Large"));
+        assert_eq!(state.current_tab.as_deref(), Some("Settings"));
+        assert_eq!(state.switch_label(), "Feature toggle, off");
+    }
+}
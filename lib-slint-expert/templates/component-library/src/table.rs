@@ -0,0 +1,118 @@
+//! Sorting for the `DataTable` component.
+
+use std::cmp::Ordering;
+
+use slint::{Model, SharedString, VecModel};
+
+use crate::TableRow;
+
+/// Default cell comparator. Cells that both parse as numbers compare
+/// numerically; everything else compares as case-insensitive text. In a
+/// column mixing the two, numbers sort before text.
+pub fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.to_lowercase().cmp(&b.to_lowercase()).then_with(|| a.cmp(b)),
+    }
+}
+
+/// Stably sorts `rows` by the cells in `column` using `compare`. Rows
+/// missing that cell sort last in either direction; descending order keeps
+/// equal rows in their original order.
+pub fn sort_rows<F>(rows: &mut [TableRow], column: usize, ascending: bool, compare: F)
+where
+    F: Fn(&str, &str) -> Ordering,
+{
+    rows.sort_by(|a, b| match (a.cells.row_data(column), b.cells.row_data(column)) {
+        (Some(a), Some(b)) => {
+            let ordering = compare(&a, &b);
+            if ascending { ordering } else { ordering.reverse() }
+        }
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+}
+
+/// Sorts the rows held by `model` in place, see [`sort_rows`].
+pub fn sort_model<F>(model: &VecModel<TableRow>, column: usize, ascending: bool, compare: F)
+where
+    F: Fn(&str, &str) -> Ordering,
+{
+    let mut rows: Vec<TableRow> = model.iter().collect();
+    sort_rows(&mut rows, column, ascending, compare);
+    model.set_vec(rows);
+}
+
+/// Builds a row from plain string cells.
+pub fn row<const N: usize>(cells: [&str; N]) -> TableRow {
+    let cells: Vec<SharedString> = cells.iter().map(|cell| (*cell).into()).collect();
+    TableRow { cells: slint::ModelRc::new(VecModel::from(cells)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(rows: &[TableRow], column: usize) -> Vec<String> {
+        rows.iter().map(|row| row.cells.row_data(column).unwrap_or_default().to_string()).collect()
+    }
+
+    #[test]
+    fn numbers_compare_numerically() {
+        assert_eq!(compare_cells("9", "10"), Ordering::Less);
+        assert_eq!(compare_cells(" 2.5", "2.50 "), Ordering::Equal);
+        assert_eq!(compare_cells("-1", "0"), Ordering::Less);
+    }
+
+    #[test]
+    fn text_compares_case_insensitively() {
+        assert_eq!(compare_cells("apple", "Banana"), Ordering::Less);
+        // Case only breaks ties, so the order is total
+        assert_eq!(compare_cells("Apple", "apple"), Ordering::Less);
+        assert_eq!(compare_cells("apple", "apple"), Ordering::Equal);
+    }
+
+    #[test]
+    fn numbers_sort_before_text() {
+        assert_eq!(compare_cells("100", "abc"), Ordering::Less);
+        assert_eq!(compare_cells("abc", "100"), Ordering::Greater);
+        assert_eq!(compare_cells("", "0"), Ordering::Greater);
+    }
+
+    #[test]
+    fn sorts_ascending_and_descending() {
+        let mut rows = vec![row(["b", "10"]), row(["a", "9"]), row(["c", "100"])];
+
+        sort_rows(&mut rows, 1, true, compare_cells);
+        assert_eq!(column(&rows, 0), ["a", "b", "c"]);
+
+        sort_rows(&mut rows, 1, false, compare_cells);
+        assert_eq!(column(&rows, 0), ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn equal_rows_keep_their_order_in_both_directions() {
+        let mut rows = vec![row(["first", "1"]), row(["second", "0"]), row(["third", "1"])];
+
+        sort_rows(&mut rows, 1, true, compare_cells);
+        assert_eq!(column(&rows, 0), ["second", "first", "third"]);
+
+        sort_rows(&mut rows, 1, false, compare_cells);
+        assert_eq!(column(&rows, 0), ["first", "third", "second"]);
+    }
+
+    #[test]
+    fn mixed_columns_and_missing_cells() {
+        let mut rows = vec![row(["x", "beta"]), row(["short"]), row(["y", "3"]), row(["z", "Alpha"])];
+
+        sort_rows(&mut rows, 1, true, compare_cells);
+        assert_eq!(column(&rows, 0), ["y", "z", "x", "short"]);
+
+        // Rows without the cell stay last when descending too
+        sort_rows(&mut rows, 1, false, compare_cells);
+        assert_eq!(column(&rows, 0), ["x", "z", "y", "short"]);
+    }
+}
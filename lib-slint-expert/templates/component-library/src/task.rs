@@ -0,0 +1,44 @@
+//! Simulated background task driving the demo's progress bar.
+
+use std::thread;
+use std::time::Duration;
+
+use crate::ComponentLibraryDemo;
+
+const STEPS: u32 = 100;
+const STEP_DELAY: Duration = Duration::from_millis(30);
+
+/// Runs the fake task on a worker thread. Progress is posted back to the UI
+/// thread after every step; `task-finished` fires once it reaches 100%.
+/// Does nothing while a previous run is still going.
+pub fn start(app: &ComponentLibraryDemo) {
+    if app.get_task_running() {
+        return;
+    }
+    app.set_task_progress(0.0);
+    app.set_task_running(true);
+
+    let app_weak = app.as_weak();
+    thread::spawn(move || {
+        for step in 1..=STEPS {
+            thread::sleep(STEP_DELAY);
+
+            let app_weak = app_weak.clone();
+            let progress = step as f32 / STEPS as f32;
+            let posted = slint::invoke_from_event_loop(move || {
+                if let Some(app) = app_weak.upgrade() {
+                    app.set_task_progress(progress);
+                    if step == STEPS {
+                        app.set_task_running(false);
+                        app.invoke_task_finished();
+                    }
+                }
+            });
+
+            // The event loop is gone; nobody is watching any more.
+            if posted.is_err() {
+                break;
+            }
+        }
+    });
+}
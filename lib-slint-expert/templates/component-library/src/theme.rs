@@ -0,0 +1,81 @@
+//! Accent overrides for the library components.
+//!
+//! Components with an accent take an override property that is
+//! `transparent` when unset; `Theme.resolve` in theme.slint then picks
+//! `Theme.accent`. These helpers set overrides from Rust with `Option`
+//! instead of the sentinel. To check what a component will show, call the
+//! same rule: `app.global::<Theme>().invoke_resolve(override, accent)`.
+
+use slint::{Color, ComponentHandle};
+
+use crate::ComponentLibraryDemo;
+
+/// The override property value for `color`; `None` unsets it.
+pub fn override_color(color: Option<Color>) -> Color {
+    color.unwrap_or(Color::from_argb_u8(0, 0, 0, 0))
+}
+
+/// Gives the demo's primary button its own accent, or with `None` lets it
+/// follow `Theme.accent` again.
+pub fn set_primary_accent(app: &ComponentLibraryDemo, color: Option<Color>) {
+    app.set_primary_color(override_color(color));
+}
+
+/// Wires the "Follow Theme" button.
+pub fn install(app: &ComponentLibraryDemo) {
+    let app_weak = app.as_weak();
+    app.on_follow_theme_accent(move || set_primary_accent(&app_weak.unwrap(), None));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Theme;
+
+    fn red() -> Color {
+        Color::from_rgb_u8(0xe7, 0x4c, 0x3c)
+    }
+
+    fn demo() -> ComponentLibraryDemo {
+        i_slint_backend_testing::init_no_event_loop();
+        ComponentLibraryDemo::new().unwrap()
+    }
+
+    #[test]
+    fn none_is_the_transparent_sentinel() {
+        assert_eq!(override_color(None), Color::from_argb_u8(0, 0, 0, 0));
+        assert_eq!(override_color(Some(red())), red());
+    }
+
+    #[test]
+    fn an_override_wins_over_the_theme() {
+        let app = demo();
+        let theme = app.global::<Theme>();
+        assert_eq!(theme.invoke_resolve(override_color(Some(red())), theme.get_accent()), red());
+
+        set_primary_accent(&app, Some(red()));
+        assert_eq!(theme.invoke_resolve(app.get_primary_color(), theme.get_accent()), red());
+    }
+
+    // Unset overrides keep following the theme when it changes later
+    #[test]
+    fn an_unset_override_inherits() {
+        let app = demo();
+        let theme = app.global::<Theme>();
+        set_primary_accent(&app, None);
+        assert_eq!(theme.invoke_resolve(app.get_primary_color(), theme.get_accent()), theme.get_accent());
+
+        theme.set_accent(red());
+        assert_eq!(theme.invoke_resolve(app.get_primary_color(), theme.get_accent()), red());
+    }
+
+    #[test]
+    fn follow_theme_clears_the_demo_override() {
+        let app = demo();
+        install(&app);
+        assert_ne!(app.get_primary_color(), override_color(None));
+
+        app.invoke_follow_theme_accent();
+        assert_eq!(app.get_primary_color(), override_color(None));
+    }
+}
@@ -0,0 +1,267 @@
+//! Toast queue behind the demo's `ToastStack`.
+
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use std::time::{Duration, Instant};
+
+use slint::{Model, ModelRc, VecModel};
+
+use crate::{ToastData, ToastSeverity};
+
+/// Source of the current time, so expiry can be tested without waiting.
+pub trait Clock {
+    fn now(&self) -> Instant;
+
+    /// Wall-clock time, for timestamps people read.
+    fn local_now(&self) -> chrono::DateTime<chrono::Local> {
+        chrono::Local::now()
+    }
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// When a toast goes away: at an instant, or, while the pointer is over
+/// it, once the time it had left runs out after the pointer leaves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Deadline {
+    At(Instant),
+    Paused(Duration),
+}
+
+impl Deadline {
+    /// Stops the countdown, keeping the time left at `now`.
+    pub fn pause(self, now: Instant) -> Self {
+        match self {
+            Deadline::At(instant) => Deadline::Paused(instant.saturating_duration_since(now)),
+            paused => paused,
+        }
+    }
+
+    /// Restarts the countdown from `now` with the time that was left.
+    pub fn resume(self, now: Instant) -> Self {
+        match self {
+            Deadline::Paused(remaining) => Deadline::At(now + remaining),
+            running => running,
+        }
+    }
+
+    pub fn has_passed(self, now: Instant) -> bool {
+        matches!(self, Deadline::At(instant) if instant <= now)
+    }
+}
+
+/// Visible toasts plus when each one expires.
+pub struct ToastQueue<C: Clock = SystemClock> {
+    model: Rc<VecModel<ToastData>>,
+    deadlines: RefCell<Vec<(i32, Deadline)>>,
+    next_id: Cell<i32>,
+    clock: C,
+    on_push: RefCell<Option<Box<dyn Fn(&str)>>>,
+}
+
+impl ToastQueue<SystemClock> {
+    pub fn new() -> Rc<Self> {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<C: Clock + 'static> ToastQueue<C> {
+    pub fn with_clock(clock: C) -> Rc<Self> {
+        Rc::new(Self {
+            model: Rc::new(VecModel::default()),
+            deadlines: RefCell::new(Vec::new()),
+            next_id: Cell::new(0),
+            clock,
+            on_push: RefCell::new(None),
+        })
+    }
+
+    /// The model to bind to `ToastStack.toasts`.
+    pub fn model(&self) -> ModelRc<ToastData> {
+        ModelRc::from(self.model.clone())
+    }
+
+    /// Calls `observer` with the message of every toast pushed from now on,
+    /// e.g. to keep a history.
+    pub fn on_push(&self, observer: impl Fn(&str) + 'static) {
+        *self.on_push.borrow_mut() = Some(Box::new(observer));
+    }
+
+    /// Shows `message` until it has been on screen for `duration` without
+    /// the pointer over it, or until it is clicked, and returns its id.
+    pub fn push(self: &Rc<Self>, message: &str, duration: Duration, severity: ToastSeverity) -> i32 {
+        let id = self.next_id.replace(self.next_id.get() + 1);
+
+        self.model.push(ToastData {
+            id,
+            message: message.into(),
+            severity,
+        });
+        self.deadlines.borrow_mut().push((id, Deadline::At(self.clock.now() + duration)));
+        self.expire_after(duration);
+        if let Some(observer) = &*self.on_push.borrow() {
+            observer(message);
+        }
+
+        id
+    }
+
+    /// Holds a toast's countdown while the pointer is over it and restarts
+    /// it with the remaining time once the pointer leaves.
+    pub fn set_hovered(self: &Rc<Self>, id: i32, hovered: bool) {
+        let now = self.clock.now();
+        let resumed = {
+            let mut deadlines = self.deadlines.borrow_mut();
+            let Some((_, deadline)) = deadlines.iter_mut().find(|(toast_id, _)| *toast_id == id) else {
+                return;
+            };
+            let remaining = match *deadline {
+                Deadline::Paused(remaining) if !hovered => Some(remaining),
+                _ => None,
+            };
+            *deadline = if hovered { deadline.pause(now) } else { deadline.resume(now) };
+            remaining
+        };
+
+        if let Some(remaining) = resumed {
+            self.expire_after(remaining);
+        }
+    }
+
+    fn expire_after(self: &Rc<Self>, delay: Duration) {
+        let queue: Weak<Self> = Rc::downgrade(self);
+        slint::Timer::single_shot(delay, move || {
+            if let Some(queue) = queue.upgrade() {
+                queue.expire();
+            }
+        });
+    }
+
+    /// Removes a toast early, e.g. because it was clicked.
+    pub fn dismiss(&self, id: i32) {
+        self.deadlines.borrow_mut().retain(|(toast_id, _)| *toast_id != id);
+        if let Some(row) = self.model.iter().position(|toast| toast.id == id) {
+            self.model.remove(row);
+        }
+    }
+
+    /// Removes every toast whose deadline has passed. Hovered toasts stay.
+    pub fn expire(&self) {
+        let now = self.clock.now();
+        let expired: Vec<i32> = self
+            .deadlines
+            .borrow()
+            .iter()
+            .filter(|(_, deadline)| deadline.has_passed(now))
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in expired {
+            self.dismiss(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Time only moves when the test says so
+    #[derive(Clone)]
+    struct MockClock(Rc<Cell<Instant>>);
+
+    impl MockClock {
+        fn new() -> Self {
+            Self(Rc::new(Cell::new(Instant::now())))
+        }
+
+        fn advance(&self, ms: u64) {
+            self.0.set(self.0.get() + Duration::from_millis(ms));
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Instant {
+            self.0.get()
+        }
+    }
+
+    fn messages<C: Clock + 'static>(queue: &ToastQueue<C>) -> Vec<String> {
+        queue.model().iter().map(|toast| toast.message.to_string()).collect()
+    }
+
+    #[test]
+    fn toasts_dismiss_themselves_after_their_duration() {
+        let clock = MockClock::new();
+        let queue = ToastQueue::with_clock(clock.clone());
+        queue.push("short", Duration::from_millis(1000), ToastSeverity::Info);
+        queue.push("long", Duration::from_millis(3000), ToastSeverity::Success);
+
+        clock.advance(999);
+        queue.expire();
+        assert_eq!(messages(&queue), ["short", "long"]);
+
+        clock.advance(1);
+        queue.expire();
+        assert_eq!(messages(&queue), ["long"]);
+
+        clock.advance(2000);
+        queue.expire();
+        assert!(messages(&queue).is_empty());
+    }
+
+    #[test]
+    fn hovering_holds_the_countdown_and_leaving_resumes_it() {
+        let clock = MockClock::new();
+        let queue = ToastQueue::with_clock(clock.clone());
+        let id = queue.push("Saved", Duration::from_millis(3000), ToastSeverity::Success);
+
+        clock.advance(1000);
+        queue.set_hovered(id, true);
+        clock.advance(5000);
+        queue.expire();
+        assert_eq!(messages(&queue), ["Saved"]);
+
+        // The 2000 ms that were left count from when the pointer leaves
+        queue.set_hovered(id, false);
+        clock.advance(1999);
+        queue.expire();
+        assert_eq!(messages(&queue), ["Saved"]);
+
+        clock.advance(1);
+        queue.expire();
+        assert!(messages(&queue).is_empty());
+    }
+
+    #[test]
+    fn deadlines_pause_and_resume_with_the_time_left() {
+        let start = Instant::now();
+        let deadline = Deadline::At(start + Duration::from_millis(3000));
+
+        let paused = deadline.pause(start + Duration::from_millis(1000));
+        assert_eq!(paused, Deadline::Paused(Duration::from_millis(2000)));
+        assert!(!paused.has_passed(start + Duration::from_secs(60)));
+        // Pausing twice keeps the first remainder
+        assert_eq!(paused.pause(start + Duration::from_millis(2500)), paused);
+
+        let later = start + Duration::from_millis(10_000);
+        assert_eq!(paused.resume(later), Deadline::At(later + Duration::from_millis(2000)));
+        assert_eq!(deadline.resume(later), deadline);
+    }
+
+    #[test]
+    fn dismissing_early_removes_only_that_toast() {
+        let queue = ToastQueue::with_clock(MockClock::new());
+        let first = queue.push("first", Duration::from_millis(1000), ToastSeverity::Info);
+        queue.push("second", Duration::from_millis(1000), ToastSeverity::Info);
+
+        queue.dismiss(first);
+        assert_eq!(messages(&queue), ["second"]);
+    }
+}
@@ -0,0 +1,296 @@
+//! An expandable tree and the flat row model `TreeView` shows.
+//!
+//! [`Tree`] holds the hierarchy and each node's expanded flag. [`TreeModel`]
+//! holds the visible rows, depth-first, and splices rows in or out below a
+//! node when it is expanded or collapsed; the rest of the list is left
+//! alone. Walks are iterative, so very deep trees can't overflow the stack.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use slint::{Model, ModelNotify, ModelTracker, SharedString};
+
+use crate::TreeNode;
+
+struct Node {
+    label: SharedString,
+    children: Vec<usize>,
+    expanded: bool,
+}
+
+/// Nodes addressed by the id [`Tree::add`] returns.
+#[derive(Default)]
+pub struct Tree {
+    nodes: Vec<Node>,
+    roots: Vec<usize>,
+}
+
+impl Tree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a collapsed node under `parent`, or at the top level for
+    /// `None`, after its existing siblings. Returns the new node's id.
+    ///
+    /// Panics if `parent` is not an id from this tree.
+    pub fn add(&mut self, parent: Option<usize>, label: &str) -> usize {
+        let id = self.nodes.len();
+        match parent {
+            Some(parent) => self.nodes[parent].children.push(id),
+            None => self.roots.push(id),
+        }
+        self.nodes.push(Node { label: label.into(), children: Vec::new(), expanded: false });
+        id
+    }
+
+    pub fn label(&self, id: usize) -> Option<&str> {
+        self.nodes.get(id).map(|node| node.label.as_str())
+    }
+
+    /// Sets the expanded flag; returns whether it changed. Leaves keep
+    /// `false`. Descendants keep their own flags, so collapsing and
+    /// re-expanding a node restores what was open beneath it.
+    pub fn set_expanded(&mut self, id: usize, expanded: bool) -> bool {
+        match self.nodes.get_mut(id) {
+            Some(node) if !node.children.is_empty() && node.expanded != expanded => {
+                node.expanded = expanded;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Every visible row, depth-first.
+    pub fn visible_rows(&self) -> Vec<TreeNode> {
+        self.flatten(&self.roots, 0)
+    }
+
+    /// The visible rows below `id` (at `depth`), depth-first, as if `id`
+    /// were expanded.
+    pub fn visible_descendants(&self, id: usize, depth: i32) -> Vec<TreeNode> {
+        match self.nodes.get(id) {
+            Some(node) => self.flatten(&node.children, depth + 1),
+            None => Vec::new(),
+        }
+    }
+
+    fn flatten(&self, start: &[usize], depth: i32) -> Vec<TreeNode> {
+        let mut rows = Vec::new();
+        let mut stack: Vec<(usize, i32)> = start.iter().rev().map(|&id| (id, depth)).collect();
+        while let Some((id, depth)) = stack.pop() {
+            let node = &self.nodes[id];
+            rows.push(TreeNode {
+                id: id as i32,
+                label: node.label.clone(),
+                depth,
+                expanded: node.expanded,
+                has_children: !node.children.is_empty(),
+            });
+            if node.expanded {
+                stack.extend(node.children.iter().rev().map(|&child| (child, depth + 1)));
+            }
+        }
+        rows
+    }
+}
+
+/// The visible rows of a [`Tree`], as a `slint::Model` for `TreeView.nodes`.
+pub struct TreeModel {
+    tree: RefCell<Tree>,
+    rows: RefCell<Vec<TreeNode>>,
+    notify: ModelNotify,
+}
+
+impl TreeModel {
+    pub fn new(tree: Tree) -> Rc<Self> {
+        let rows = tree.visible_rows();
+        Rc::new(Self { tree: RefCell::new(tree), rows: RefCell::new(rows), notify: ModelNotify::default() })
+    }
+
+    pub fn label(&self, id: usize) -> Option<SharedString> {
+        self.tree.borrow().label(id).map(SharedString::from)
+    }
+
+    /// Expands the node in `row`, inserting its visible descendants below
+    /// it. Returns whether anything changed.
+    pub fn expand(&self, row: usize) -> bool {
+        let Some(node) = self.rows.borrow().get(row).cloned() else {
+            return false;
+        };
+        let inserted = {
+            let mut tree = self.tree.borrow_mut();
+            if !tree.set_expanded(node.id as usize, true) {
+                return false;
+            }
+            tree.visible_descendants(node.id as usize, node.depth)
+        };
+
+        let count = inserted.len();
+        {
+            let mut rows = self.rows.borrow_mut();
+            rows[row].expanded = true;
+            rows.splice(row + 1..row + 1, inserted);
+        }
+        // Notified after the borrow ends: views read rows back right away
+        self.notify.row_changed(row);
+        self.notify.row_added(row + 1, count);
+        true
+    }
+
+    /// Collapses the node in `row`, removing the rows below it that are
+    /// deeper. Returns whether anything changed.
+    pub fn collapse(&self, row: usize) -> bool {
+        let Some(node) = self.rows.borrow().get(row).cloned() else {
+            return false;
+        };
+        if !self.tree.borrow_mut().set_expanded(node.id as usize, false) {
+            return false;
+        }
+
+        let count = {
+            let mut rows = self.rows.borrow_mut();
+            rows[row].expanded = false;
+            let end = rows[row + 1..]
+                .iter()
+                .position(|below| below.depth <= node.depth)
+                .map_or(rows.len(), |offset| row + 1 + offset);
+            rows.drain(row + 1..end).count()
+        };
+        self.notify.row_changed(row);
+        self.notify.row_removed(row + 1, count);
+        true
+    }
+
+    pub fn toggle(&self, row: usize) -> bool {
+        let expanded = self.rows.borrow().get(row).map(|node| node.expanded);
+        match expanded {
+            Some(true) => self.collapse(row),
+            Some(false) => self.expand(row),
+            None => false,
+        }
+    }
+}
+
+impl Model for TreeModel {
+    type Data = TreeNode;
+
+    fn row_count(&self) -> usize {
+        self.rows.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<TreeNode> {
+        self.rows.borrow().get(row).cloned()
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.notify
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // src/            0
+    //   ui/           1
+    //     main.slint  2
+    //   main.rs       3
+    // README.md       4
+    fn sample() -> Tree {
+        let mut tree = Tree::new();
+        let src = tree.add(None, "src/");
+        let ui = tree.add(Some(src), "ui/");
+        tree.add(Some(ui), "main.slint");
+        tree.add(Some(src), "main.rs");
+        tree.add(None, "README.md");
+        tree
+    }
+
+    // Labels indented two spaces per level
+    fn outline(model: &TreeModel) -> Vec<String> {
+        model.iter().map(|node| format!("{}{}", "  ".repeat(node.depth as usize), node.label)).collect()
+    }
+
+    #[test]
+    fn collapsed_trees_show_only_the_roots() {
+        let model = TreeModel::new(sample());
+        assert_eq!(outline(&model), ["src/", "README.md"]);
+        let src = model.row_data(0).unwrap();
+        assert!(src.has_children && !src.expanded);
+        assert!(!model.row_data(1).unwrap().has_children);
+    }
+
+    #[test]
+    fn expanding_inserts_the_children_below() {
+        let model = TreeModel::new(sample());
+        assert!(model.expand(0));
+        assert_eq!(outline(&model), ["src/", "  ui/", "  main.rs", "README.md"]);
+        assert!(model.row_data(0).unwrap().expanded);
+
+        assert!(model.expand(1));
+        assert_eq!(outline(&model), ["src/", "  ui/", "    main.slint", "  main.rs", "README.md"]);
+    }
+
+    #[test]
+    fn collapsing_removes_every_deeper_row() {
+        let model = TreeModel::new(sample());
+        model.expand(0);
+        model.expand(1);
+
+        assert!(model.collapse(0));
+        assert_eq!(outline(&model), ["src/", "README.md"]);
+    }
+
+    // Collapsing a parent leaves its children's flags alone
+    #[test]
+    fn re_expanding_restores_open_descendants() {
+        let model = TreeModel::new(sample());
+        model.expand(0);
+        model.expand(1);
+        model.collapse(0);
+
+        model.expand(0);
+        assert_eq!(outline(&model), ["src/", "  ui/", "    main.slint", "  main.rs", "README.md"]);
+    }
+
+    #[test]
+    fn the_rows_match_a_fresh_flatten() {
+        let model = TreeModel::new(sample());
+        model.toggle(0);
+        model.toggle(1);
+        model.toggle(0);
+        model.toggle(0);
+        model.toggle(1);
+
+        let tree = model.tree.borrow();
+        let fresh: Vec<_> = tree.visible_rows().into_iter().map(|node| (node.id, node.depth, node.expanded)).collect();
+        let rows: Vec<_> = model.iter().map(|node| (node.id, node.depth, node.expanded)).collect();
+        assert_eq!(rows, fresh);
+    }
+
+    #[test]
+    fn leaves_and_repeats_change_nothing() {
+        let model = TreeModel::new(sample());
+        assert!(!model.expand(1));
+        assert!(!model.collapse(0));
+        assert!(model.expand(0));
+        assert!(!model.expand(0));
+        assert!(!model.toggle(9));
+        assert_eq!(outline(&model), ["src/", "  ui/", "  main.rs", "README.md"]);
+    }
+
+    #[test]
+    fn deep_trees_flatten_without_recursion() {
+        let mut tree = Tree::new();
+        let mut parent = tree.add(None, "0");
+        for depth in 1..100_000 {
+            let child = tree.add(Some(parent), &depth.to_string());
+            tree.set_expanded(parent, true);
+            parent = child;
+        }
+        let rows = tree.visible_rows();
+        assert_eq!(rows.len(), 100_000);
+        assert_eq!(rows.last().unwrap().depth, 99_999);
+    }
+}
@@ -0,0 +1,198 @@
+//! Windowing backend and renderer detection.
+
+/// Windowing backend and renderer, e.g. "Wayland (skia)" or "WebGL 2 (femtovg)".
+pub fn backend_info() -> String {
+    #[cfg(target_os = "windows")]
+    let backend: &'static str = "Win32";
+
+    #[cfg(target_os = "macos")]
+    let backend: &'static str = "Cocoa";
+
+    #[cfg(target_os = "linux")]
+    let backend: &'static str = detect_linux_session();
+
+    #[cfg(target_arch = "wasm32")]
+    let backend: &'static str = detect_web_renderer().as_str();
+
+    #[cfg(not(any(
+        target_os = "windows",
+        target_os = "macos",
+        target_os = "linux",
+        target_arch = "wasm32"
+    )))]
+    let backend: &'static str = "Default";
+
+    format!("{} ({})", backend, renderer_name())
+}
+
+/// How the browser can draw the app canvas. Defined on every target so the
+/// reporting can be checked natively; only detected on the web.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebRenderer {
+    WebGl2,
+    WebGl,
+    /// No WebGL context available (disabled, blocklisted GPU, some headless
+    /// browsers); Slint draws on the CPU instead.
+    Software,
+}
+
+impl WebRenderer {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WebRenderer::WebGl2 => "WebGL 2",
+            WebRenderer::WebGl => "WebGL",
+            WebRenderer::Software => "Software",
+        }
+    }
+
+    /// The Slint renderer drawing with this context.
+    pub fn renderer_name(self) -> &'static str {
+        match self {
+            WebRenderer::Software => "software",
+            WebRenderer::WebGl2 | WebRenderer::WebGl => "femtovg",
+        }
+    }
+}
+
+/// Probes for WebGL 2, then WebGL 1, on a throwaway canvas. The result is
+/// cached for the page's lifetime.
+#[cfg(target_arch = "wasm32")]
+pub fn detect_web_renderer() -> WebRenderer {
+    thread_local! {
+        static DETECTED: std::cell::OnceCell<WebRenderer> = const { std::cell::OnceCell::new() };
+    }
+
+    DETECTED.with(|detected| {
+        *detected.get_or_init(|| {
+            if has_context("webgl2") {
+                WebRenderer::WebGl2
+            } else if has_context("webgl") {
+                WebRenderer::WebGl
+            } else {
+                WebRenderer::Software
+            }
+        })
+    })
+}
+
+// A canvas hands out only one kind of context, so each probe gets its own.
+#[cfg(target_arch = "wasm32")]
+fn has_context(kind: &str) -> bool {
+    use wasm_bindgen::JsCast;
+
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.create_element("canvas").ok())
+        .and_then(|canvas| canvas.dyn_into::<web_sys::HtmlCanvasElement>().ok())
+        .is_some_and(|canvas| matches!(canvas.get_context(kind), Ok(Some(_))))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+static SELECTED_RENDERER: std::sync::OnceLock<&'static str> = std::sync::OnceLock::new();
+
+/// Records the renderer the app selected, e.g. after a successful
+/// `slint::BackendSelector::select()`. Slint has no public getter for it, so
+/// [`renderer_name`] reports what is recorded here. Only the first call
+/// counts, like the selection itself.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn record_renderer(name: &'static str) {
+    let _ = SELECTED_RENDERER.set(name);
+}
+
+/// The renderer recorded with [`record_renderer`]. Apps that leave the
+/// choice to Slint get the renderer requested through `SLINT_BACKEND`
+/// (e.g. "winit-skia"), or "default renderer" when that is unset too.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn renderer_name() -> &'static str {
+    if let Some(selected) = SELECTED_RENDERER.get() {
+        return selected;
+    }
+
+    let requested = std::env::var("SLINT_BACKEND").unwrap_or_default();
+    let renderer = requested.rsplit('-').next().unwrap_or_default();
+
+    match renderer {
+        "femtovg" => "femtovg",
+        "skia" => "skia",
+        "software" | "sw" => "software",
+        _ => "default renderer",
+    }
+}
+
+// The web build picks its renderer from `detect_web_renderer` at startup.
+#[cfg(target_arch = "wasm32")]
+pub fn renderer_name() -> &'static str {
+    detect_web_renderer().renderer_name()
+}
+
+#[cfg(target_os = "linux")]
+pub fn detect_linux_session() -> &'static str {
+    let wayland_display = std::env::var("WAYLAND_DISPLAY").ok();
+    let session_type = std::env::var("XDG_SESSION_TYPE").ok();
+
+    classify_linux_session(wayland_display.as_deref(), session_type.as_deref())
+}
+
+// WAYLAND_DISPLAY wins over XDG_SESSION_TYPE: XWayland sessions may still
+// report "x11" while the compositor itself is Wayland.
+#[cfg(target_os = "linux")]
+fn classify_linux_session(wayland_display: Option<&str>, session_type: Option<&str>) -> &'static str {
+    if wayland_display.is_some_and(|display| !display.is_empty()) {
+        return "Wayland";
+    }
+
+    match session_type {
+        Some("wayland") => "Wayland",
+        Some("x11") => "X11",
+        _ => "Unknown (Linux)",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn web_renderer_labels() {
+        assert_eq!(WebRenderer::WebGl2.as_str(), "WebGL 2");
+        assert_eq!(WebRenderer::WebGl.as_str(), "WebGL");
+        assert_eq!(WebRenderer::Software.as_str(), "Software");
+    }
+
+    // Both WebGL versions go through femtovg; only without WebGL does
+    // Slint fall back to its software renderer
+    #[test]
+    fn web_renderer_picks_the_slint_renderer() {
+        assert_eq!(WebRenderer::WebGl2.renderer_name(), "femtovg");
+        assert_eq!(WebRenderer::WebGl.renderer_name(), "femtovg");
+        assert_eq!(WebRenderer::Software.renderer_name(), "software");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn x11_session() {
+        assert_eq!(classify_linux_session(None, Some("x11")), "X11");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn wayland_session() {
+        assert_eq!(classify_linux_session(Some("wayland-0"), Some("wayland")), "Wayland");
+        assert_eq!(classify_linux_session(None, Some("wayland")), "Wayland");
+    }
+
+    // An X11 app started from a Wayland session: the compositor is Wayland
+    // even though the session type says otherwise
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn xwayland_session() {
+        assert_eq!(classify_linux_session(Some("wayland-0"), Some("x11")), "Wayland");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn unset_environment() {
+        assert_eq!(classify_linux_session(None, None), "Unknown (Linux)");
+        assert_eq!(classify_linux_session(Some(""), Some("tty")), "Unknown (Linux)");
+    }
+}
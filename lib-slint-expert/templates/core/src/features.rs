@@ -0,0 +1,113 @@
+//! Capabilities the templates offer on the current target.
+
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Offered on every target, and always listed first.
+const BASE_FEATURES: [&str; 3] = ["Basic UI", "Animations", "Theming"];
+const WEB_FEATURES: [&str; 2] = ["Web integration", "Browser storage"];
+
+static SYSTEM_TRAY: AtomicBool = AtomicBool::new(false);
+
+/// What a target offers beyond the base features.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Target {
+    /// A browser rather than a desktop OS.
+    pub web: bool,
+    /// Open/save dialogs are compiled in (desktop only).
+    pub file_dialogs: bool,
+    /// A tray icon could be created (desktop only).
+    pub system_tray: bool,
+}
+
+/// Records whether the app managed to create a tray icon, which is only
+/// known at runtime (e.g. there is none on GNOME without an extension).
+pub fn record_system_tray(available: bool) {
+    SYSTEM_TRAY.store(available, Ordering::Relaxed);
+}
+
+/// Capability names for the info panel; the set depends on the target.
+pub fn available_features() -> Vec<&'static str> {
+    features_for(Target {
+        web: cfg!(target_arch = "wasm32"),
+        file_dialogs: cfg!(feature = "file-dialogs"),
+        system_tray: SYSTEM_TRAY.load(Ordering::Relaxed),
+    })
+}
+
+/// The capability names for `target`. Takes the target as an argument so
+/// every branch can be checked from one build. The base features come
+/// first; no name appears twice.
+pub fn features_for(target: Target) -> Vec<&'static str> {
+    let mut features = BASE_FEATURES.to_vec();
+    if target.web {
+        features.extend(WEB_FEATURES);
+        return features;
+    }
+
+    if target.file_dialogs {
+        features.push("File dialogs");
+    }
+    if target.system_tray {
+        features.push("System tray");
+    }
+    features.push("Multiple windows");
+    features
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DESKTOP_ONLY: [&str; 3] = ["File dialogs", "System tray", "Multiple windows"];
+
+    // Every combination of the flags, including the desktop-only ones on
+    // the web where they must be ignored
+    fn all_targets() -> impl Iterator<Item = Target> {
+        (0..8).map(|bits| Target {
+            web: bits & 1 != 0,
+            file_dialogs: bits & 2 != 0,
+            system_tray: bits & 4 != 0,
+        })
+    }
+
+    #[test]
+    fn base_features_come_first() {
+        for target in all_targets() {
+            assert_eq!(features_for(target)[..3], BASE_FEATURES, "{:?}", target);
+        }
+    }
+
+    #[test]
+    fn no_duplicates() {
+        for target in all_targets() {
+            let features = features_for(target);
+            for (i, feature) in features.iter().enumerate() {
+                assert!(!features[i + 1..].contains(feature), "{} twice for {:?}", feature, target);
+            }
+        }
+    }
+
+    #[test]
+    fn web_lists_only_base_and_web_features() {
+        for target in all_targets().filter(|target| target.web) {
+            let features = features_for(target);
+            assert!(features.iter().all(|feature| BASE_FEATURES.contains(feature) || WEB_FEATURES.contains(feature)));
+            assert!(DESKTOP_ONLY.iter().all(|feature| !features.contains(feature)));
+        }
+    }
+
+    #[test]
+    fn desktop_follows_the_flags() {
+        let bare = features_for(Target::default());
+        assert_eq!(bare, ["Basic UI", "Animations", "Theming", "Multiple windows"]);
+
+        let full = features_for(Target {
+            web: false,
+            file_dialogs: true,
+            system_tray: true,
+        });
+        assert_eq!(full[3..], DESKTOP_ONLY);
+        assert!(WEB_FEATURES.iter().all(|feature| !full.contains(feature)));
+    }
+}
@@ -0,0 +1,130 @@
+//! CPU, memory and battery readings.
+
+use crate::{BatteryInfo, BatteryState};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn cpu_cores() -> Option<usize> {
+    std::thread::available_parallelism().ok().map(|cores| cores.get())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn cpu_cores() -> Option<usize> {
+    let cores = web_sys::window()?.navigator().hardware_concurrency();
+    (cores >= 1.0).then_some(cores as usize)
+}
+
+// (total, available) in bytes. sysinfo reports 0 when it can't read the
+// values (e.g. in some containers), which is treated as unknown.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn memory() -> Option<(u64, u64)> {
+    let mut system = sysinfo::System::new();
+    system.refresh_memory();
+
+    let total = system.total_memory();
+    (total > 0).then(|| (total, system.available_memory()))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn memory() -> Option<(u64, u64)> {
+    None
+}
+
+/// The combined charge of all batteries, or `None` on machines without one
+/// (and where the platform can't tell).
+#[cfg(not(target_arch = "wasm32"))]
+pub fn battery_status() -> Option<BatteryInfo> {
+    let manager = battery::Manager::new().ok()?;
+    let batteries: Vec<battery::Battery> = manager.batteries().ok()?.flatten().collect();
+
+    combine(batteries.iter().map(|battery| {
+        let state = match battery.state() {
+            battery::State::Charging => BatteryState::Charging,
+            battery::State::Discharging | battery::State::Empty => BatteryState::Discharging,
+            battery::State::Full => BatteryState::Full,
+            _ => BatteryState::Unknown,
+        };
+        (battery.energy().value, battery.energy_full().value, state)
+    }))
+}
+
+// Several batteries (e.g. a laptop with a swappable second pack) are
+// reported as one: charge is the stored energy over the total capacity, so
+// a small battery doesn't count as much as a big one. One charging battery
+// makes the whole charging; all full makes it full.
+#[cfg(not(target_arch = "wasm32"))]
+fn combine(readings: impl Iterator<Item = (f32, f32, BatteryState)>) -> Option<BatteryInfo> {
+    let mut energy = 0.0;
+    let mut capacity = 0.0;
+    let mut states = Vec::new();
+    for (stored, full, state) in readings {
+        energy += stored;
+        capacity += full;
+        states.push(state);
+    }
+    if states.is_empty() || capacity <= 0.0 {
+        return None;
+    }
+
+    let state = if states.contains(&BatteryState::Charging) {
+        BatteryState::Charging
+    } else if states.contains(&BatteryState::Discharging) {
+        BatteryState::Discharging
+    } else if states.iter().all(|&state| state == BatteryState::Full) {
+        BatteryState::Full
+    } else {
+        BatteryState::Unknown
+    };
+    let percent = (energy / capacity * 100.0).round().clamp(0.0, 100.0) as u8;
+    Some(BatteryInfo { percent, state })
+}
+
+/// The Battery Status API only answers asynchronously, so each call starts
+/// a new reading and returns the previous one: `None` until the first
+/// reading arrives, and always `None` in browsers without `getBattery()`.
+/// web-sys has no binding for it, so it is looked up on the navigator.
+#[cfg(target_arch = "wasm32")]
+pub fn battery_status() -> Option<BatteryInfo> {
+    use std::cell::Cell;
+    use wasm_bindgen::JsCast;
+
+    thread_local! {
+        static LAST: Cell<Option<BatteryInfo>> = const { Cell::new(None) };
+    }
+
+    let promise = web_sys::window().and_then(|window| {
+        let navigator = window.navigator();
+        let get_battery = js_sys::Reflect::get(&navigator, &"getBattery".into()).ok()?;
+        let get_battery = get_battery.dyn_into::<js_sys::Function>().ok()?;
+        get_battery.call0(&navigator).ok()?.dyn_into::<js_sys::Promise>().ok()
+    });
+    if let Some(promise) = promise {
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(manager) = wasm_bindgen_futures::JsFuture::from(promise).await {
+                LAST.with(|last| last.set(web_battery(&manager)));
+            }
+        });
+    }
+    LAST.with(Cell::get)
+}
+
+// Reads the BatteryManager's fields. Without a battery the spec has
+// browsers report a full, charging battery that will never discharge;
+// that's treated as no battery.
+#[cfg(target_arch = "wasm32")]
+fn web_battery(manager: &wasm_bindgen::JsValue) -> Option<BatteryInfo> {
+    let field = |name: &str| js_sys::Reflect::get(manager, &name.into()).ok();
+    let level = field("level")?.as_f64()?;
+    let charging = field("charging")?.as_bool()?;
+    let charging_time = field("chargingTime")?.as_f64()?;
+    let discharging_time = field("dischargingTime")?.as_f64()?;
+    if charging && level >= 1.0 && charging_time == 0.0 && discharging_time.is_infinite() {
+        return None;
+    }
+
+    let state = match (charging, level >= 1.0) {
+        (true, true) => BatteryState::Full,
+        (true, false) => BatteryState::Charging,
+        (false, _) => BatteryState::Discharging,
+    };
+    Some(BatteryInfo { percent: (level * 100.0).round().clamp(0.0, 100.0) as u8, state })
+}
@@ -0,0 +1,134 @@
+//! The structured values callers get back.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+/// Snapshot of the platform the app runs on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlatformInfo {
+    /// Operating system family, e.g. "Linux".
+    pub os: String,
+    /// Release details, e.g. "Ubuntu 22.04"; empty when unknown.
+    pub version: String,
+    /// Windowing backend and renderer, e.g. "Wayland (skia)".
+    pub backend: String,
+    /// Logical CPU cores; `None` when unknown.
+    pub cpu_cores: Option<usize>,
+    /// Installed RAM in bytes; `None` when unknown or unavailable (web).
+    pub total_memory: Option<u64>,
+    /// RAM available at collection time, in bytes.
+    pub available_memory: Option<u64>,
+    /// System locale as language-REGION, e.g. "en-US".
+    pub locale: String,
+    /// IANA timezone name, e.g. "Europe/Berlin".
+    pub timezone: String,
+    pub features: Vec<String>,
+    /// VM or container, e.g. "Docker" or "KVM"; `None` on bare metal.
+    #[serde(default)]
+    pub virtualization: Option<String>,
+}
+
+impl PlatformInfo {
+    /// OS name with its version, e.g. "Linux (Ubuntu 22.04)".
+    pub fn os_label(&self) -> String {
+        if self.version.is_empty() {
+            self.os.clone()
+        } else {
+            format!("{} ({})", self.os, self.version)
+        }
+    }
+}
+
+/// Facts that change while the app runs. Kept apart from [`PlatformInfo`],
+/// which is mostly fixed for the life of the process, so refreshing stays
+/// cheap.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct LiveStatus {
+    /// Combined charge of all batteries; `None` without a battery.
+    pub battery: Option<BatteryInfo>,
+}
+
+/// Charge across all batteries, as one reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatteryInfo {
+    /// Stored energy as a share of full capacity, 0-100.
+    pub percent: u8,
+    pub state: BatteryState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BatteryState {
+    Charging,
+    Discharging,
+    Full,
+    Unknown,
+}
+
+/// Gathers the platform facts for the current build target.
+#[cfg(feature = "std")]
+pub fn collect() -> PlatformInfo {
+    use crate::PlatformProvider;
+
+    crate::RealPlatform.collect()
+}
+
+/// Re-reads the dynamic platform state. Asks the OS power APIs, which may
+/// block briefly, so desktop callers should run it off the UI thread.
+#[cfg(feature = "std")]
+pub fn collect_live() -> LiveStatus {
+    LiveStatus {
+        battery: crate::battery_status(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn collect_describes_the_build_target() {
+        let info = collect();
+
+        assert_eq!(info.os, crate::os_name());
+        assert_eq!(info.version, crate::os_version().unwrap_or_default());
+        assert!(info.backend.ends_with(&format!("({})", crate::renderer_name())), "{}", info.backend);
+        assert_eq!(info.features, crate::available_features());
+        assert!(!info.locale.is_empty());
+        assert!(!info.timezone.is_empty());
+        if let (Some(total), Some(available)) = (info.total_memory, info.available_memory) {
+            assert!(available <= total);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn collect_is_stable() {
+        let (first, second) = (collect(), collect());
+        assert_eq!(first.os_label(), second.os_label());
+        assert_eq!(first.features, second.features);
+    }
+
+    #[test]
+    fn os_label_omits_an_unknown_version() {
+        let mut info = PlatformInfo {
+            os: "Linux".into(),
+            version: "Fedora Linux 40".into(),
+            backend: String::new(),
+            cpu_cores: None,
+            total_memory: None,
+            available_memory: None,
+            locale: crate::DEFAULT_LOCALE.into(),
+            timezone: crate::DEFAULT_TIMEZONE.into(),
+            features: Vec::new(),
+            virtualization: None,
+        };
+        assert_eq!(info.os_label(), "Linux (Fedora Linux 40)");
+
+        info.version.clear();
+        assert_eq!(info.os_label(), "Linux");
+    }
+}
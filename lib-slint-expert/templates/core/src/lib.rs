@@ -0,0 +1,61 @@
+//! Platform detection shared by the Slint templates: which OS, windowing
+//! backend and renderer the app runs on, plus hardware, locale and live
+//! status facts, collected into serializable values.
+//!
+//! Nothing here depends on Slint, so the same code serves desktop, web and
+//! (with `default-features = false`) `no_std` targets, where only the data
+//! types, [`os_name`] and [`normalize_locale`] are available.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod backend;
+#[cfg(feature = "std")]
+mod features;
+#[cfg(feature = "std")]
+mod hardware;
+mod info;
+mod locale;
+#[cfg(feature = "std")]
+mod motion;
+mod os;
+#[cfg(feature = "std")]
+mod provider;
+#[cfg(feature = "std")]
+mod version;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+mod virtualization;
+
+#[cfg(all(target_os = "linux", feature = "std"))]
+pub use backend::detect_linux_session;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub use backend::record_renderer;
+#[cfg(feature = "std")]
+pub use backend::{backend_info, renderer_name};
+#[cfg(all(target_arch = "wasm32", feature = "std"))]
+pub use backend::detect_web_renderer;
+#[cfg(feature = "std")]
+pub use backend::WebRenderer;
+#[cfg(feature = "std")]
+pub use features::{available_features, features_for, record_system_tray, Target};
+#[cfg(feature = "std")]
+pub use hardware::battery_status;
+#[cfg(feature = "std")]
+pub use info::{collect, collect_live};
+pub use info::{BatteryInfo, BatteryState, LiveStatus, PlatformInfo};
+#[cfg(feature = "std")]
+pub use locale::{detect_locale, detect_timezone};
+pub use locale::{normalize_locale, DEFAULT_LOCALE, DEFAULT_TIMEZONE};
+#[cfg(feature = "std")]
+pub use motion::{clamp_animation_speed, detect_reduced_motion, MAX_ANIMATION_SPEED, MIN_ANIMATION_SPEED};
+pub use os::os_name;
+#[cfg(feature = "std")]
+pub use provider::{MockPlatform, PlatformProvider, RealPlatform};
+#[cfg(feature = "std")]
+pub use version::os_version;
+#[cfg(all(feature = "std", target_os = "linux"))]
+pub use virtualization::container_from_cgroup;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub use virtualization::detect_virtualization;
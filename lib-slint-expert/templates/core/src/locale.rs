@@ -0,0 +1,91 @@
+//! Locale and timezone detection.
+
+use alloc::format;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+
+/// Used when the system locale can't be read or parsed.
+pub const DEFAULT_LOCALE: &str = "en-US";
+/// Used when the system timezone can't be read.
+pub const DEFAULT_TIMEZONE: &str = "UTC";
+
+/// The user's locale as language-REGION (or just the language), falling
+/// back to [`DEFAULT_LOCALE`].
+#[cfg(feature = "std")]
+pub fn detect_locale() -> String {
+    sys_locale::get_locale()
+        .and_then(|raw| normalize_locale(&raw))
+        .unwrap_or_else(|| DEFAULT_LOCALE.to_string())
+}
+
+/// Brings the locale spellings of the different platforms into one form:
+/// "en_US.UTF-8" (POSIX), "zh-Hans-CN" (macOS) and "de" become "en-US",
+/// "zh-CN" and "de". The POSIX "C"/"POSIX" locales name no language and
+/// give `None`.
+pub fn normalize_locale(raw: &str) -> Option<String> {
+    // Drop the encoding and modifier, e.g. ".UTF-8" and "@euro"
+    let raw = raw.split(['.', '@']).next()?.trim();
+    if raw.eq_ignore_ascii_case("C") || raw.eq_ignore_ascii_case("POSIX") {
+        return None;
+    }
+
+    let mut parts = raw.split(['-', '_']);
+    let language = parts.next()?;
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let language = language.to_ascii_lowercase();
+
+    // Script subtags ("Hans") are four letters; regions are two letters or
+    // three digits ("419" for Latin America).
+    let region = parts.find(|part| {
+        (part.len() == 2 && part.chars().all(|c| c.is_ascii_alphabetic()))
+            || (part.len() == 3 && part.chars().all(|c| c.is_ascii_digit()))
+    });
+
+    Some(match region {
+        Some(region) => format!("{}-{}", language, region.to_ascii_uppercase()),
+        None => language,
+    })
+}
+
+/// The system's IANA timezone, falling back to [`DEFAULT_TIMEZONE`].
+#[cfg(feature = "std")]
+pub fn detect_timezone() -> String {
+    iana_time_zone::get_timezone().unwrap_or_else(|_| DEFAULT_TIMEZONE.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn posix_locales() {
+        assert_eq!(normalize_locale("en_US.UTF-8").as_deref(), Some("en-US"));
+        assert_eq!(normalize_locale("de_DE@euro").as_deref(), Some("de-DE"));
+        assert_eq!(normalize_locale("pt_br").as_deref(), Some("pt-BR"));
+    }
+
+    #[test]
+    fn bcp47_locales() {
+        assert_eq!(normalize_locale("zh-Hans-CN").as_deref(), Some("zh-CN"));
+        assert_eq!(normalize_locale("es-419").as_deref(), Some("es-419"));
+        assert_eq!(normalize_locale("fil-PH").as_deref(), Some("fil-PH"));
+    }
+
+    #[test]
+    fn language_only() {
+        assert_eq!(normalize_locale("de").as_deref(), Some("de"));
+        assert_eq!(normalize_locale("zh-Hant").as_deref(), Some("zh"));
+    }
+
+    #[test]
+    fn locales_without_a_language() {
+        assert_eq!(normalize_locale("C"), None);
+        assert_eq!(normalize_locale("C.UTF-8"), None);
+        assert_eq!(normalize_locale("POSIX"), None);
+        assert_eq!(normalize_locale(""), None);
+        assert_eq!(normalize_locale("english"), None);
+    }
+}
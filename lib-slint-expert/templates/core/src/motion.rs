@@ -0,0 +1,129 @@
+//! The OS "reduce motion" accessibility setting, and the animation speed
+//! multiplier the templates offer on top of it.
+
+/// Slowest animation speed other than off: durations are doubled.
+pub const MIN_ANIMATION_SPEED: f32 = 0.5;
+
+/// Fastest animation speed: durations are halved.
+pub const MAX_ANIMATION_SPEED: f32 = 2.0;
+
+/// Keeps an animation speed within [`MIN_ANIMATION_SPEED`,
+/// [`MAX_ANIMATION_SPEED`]. Zero or less turns animations off and stays 0;
+/// anything that isn't a number (e.g. from a hand-edited prefs file) means
+/// normal speed.
+pub fn clamp_animation_speed(speed: f32) -> f32 {
+    if !speed.is_finite() {
+        1.0
+    } else if speed <= 0.0 {
+        0.0
+    } else {
+        speed.clamp(MIN_ANIMATION_SPEED, MAX_ANIMATION_SPEED)
+    }
+}
+
+/// Whether the user asked the OS (or browser) to minimize animations.
+/// `false` where the setting can't be read.
+///
+/// - Linux: GNOME's `enable-animations` setting, via `gsettings`
+/// - macOS: the Accessibility "Reduce motion" option
+/// - Windows: "Show animations in Windows" (client area animations)
+/// - Web: the `prefers-reduced-motion` media query
+pub fn detect_reduced_motion() -> bool {
+    imp::detect()
+}
+
+#[cfg(target_os = "linux")]
+mod imp {
+    pub fn detect() -> bool {
+        std::process::Command::new("gsettings")
+            .args(["get", "org.gnome.desktop.interface", "enable-animations"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .is_some_and(|output| String::from_utf8_lossy(&output.stdout).trim() == "false")
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod imp {
+    pub fn detect() -> bool {
+        std::process::Command::new("defaults")
+            .args(["read", "com.apple.universalaccess", "reduceMotion"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .is_some_and(|output| String::from_utf8_lossy(&output.stdout).trim() == "1")
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod imp {
+    use windows::Win32::Foundation::BOOL;
+    use windows::Win32::UI::WindowsAndMessaging::{
+        SystemParametersInfoW, SPI_GETCLIENTAREAANIMATION, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+    };
+
+    pub fn detect() -> bool {
+        let mut animations = BOOL(1);
+        // SAFETY: SPI_GETCLIENTAREAANIMATION writes a BOOL to the pointer,
+        // which points at a live local.
+        let result = unsafe {
+            SystemParametersInfoW(
+                SPI_GETCLIENTAREAANIMATION,
+                0,
+                Some((&mut animations as *mut BOOL).cast()),
+                SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+            )
+        };
+        result.is_ok() && !animations.as_bool()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod imp {
+    pub fn detect() -> bool {
+        web_sys::window()
+            .and_then(|window| window.match_media("(prefers-reduced-motion: reduce)").ok().flatten())
+            .is_some_and(|query| query.matches())
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows", target_arch = "wasm32")))]
+mod imp {
+    pub fn detect() -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speeds_in_range_are_kept() {
+        for speed in [MIN_ANIMATION_SPEED, 1.0, 1.5, MAX_ANIMATION_SPEED] {
+            assert_eq!(clamp_animation_speed(speed), speed);
+        }
+    }
+
+    #[test]
+    fn speeds_are_clamped_to_the_range() {
+        assert_eq!(clamp_animation_speed(0.1), MIN_ANIMATION_SPEED);
+        assert_eq!(clamp_animation_speed(10.0), MAX_ANIMATION_SPEED);
+        assert_eq!(clamp_animation_speed(f32::MAX), MAX_ANIMATION_SPEED);
+    }
+
+    #[test]
+    fn zero_or_less_turns_animations_off() {
+        assert_eq!(clamp_animation_speed(0.0), 0.0);
+        assert_eq!(clamp_animation_speed(-1.0), 0.0);
+        assert_eq!(clamp_animation_speed(f32::MIN), 0.0);
+    }
+
+    #[test]
+    fn non_numbers_mean_normal_speed() {
+        assert_eq!(clamp_animation_speed(f32::NAN), 1.0);
+        assert_eq!(clamp_animation_speed(f32::INFINITY), 1.0);
+        assert_eq!(clamp_animation_speed(f32::NEG_INFINITY), 1.0);
+    }
+}
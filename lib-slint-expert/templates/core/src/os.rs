@@ -0,0 +1,35 @@
+//! Operating system name.
+
+/// Operating system family, e.g. "Linux"; "Unknown" for targets the
+/// templates don't know about.
+pub fn os_name() -> &'static str {
+    #[cfg(target_os = "windows")]
+    let name = "Windows";
+
+    #[cfg(target_os = "macos")]
+    let name = "macOS";
+
+    #[cfg(target_os = "linux")]
+    let name = "Linux";
+
+    #[cfg(target_arch = "wasm32")]
+    let name = "WebAssembly";
+
+    #[cfg(target_os = "android")]
+    let name = "Android";
+
+    #[cfg(target_os = "ios")]
+    let name = "iOS";
+
+    #[cfg(not(any(
+        target_os = "windows",
+        target_os = "macos",
+        target_os = "linux",
+        target_arch = "wasm32",
+        target_os = "android",
+        target_os = "ios"
+    )))]
+    let name = "Unknown";
+
+    name
+}
@@ -0,0 +1,153 @@
+//! A seam over the detection functions, so code that renders platform facts
+//! can be driven by fixed values instead of the machine it runs on.
+
+use crate::PlatformInfo;
+
+/// Source of the platform facts. [`RealPlatform`] asks the system;
+/// [`MockPlatform`] returns whatever it was built with.
+pub trait PlatformProvider {
+    /// Operating system family, e.g. "Linux".
+    fn os_name(&self) -> String;
+    /// Release details, e.g. "Ubuntu 22.04"; `None` when unknown.
+    fn os_version(&self) -> Option<String>;
+    /// Windowing backend and renderer, e.g. "Wayland (skia)".
+    fn backend(&self) -> String;
+    fn features(&self) -> Vec<String>;
+    fn cpu_cores(&self) -> Option<usize>;
+    /// (total, available) RAM in bytes.
+    fn memory(&self) -> Option<(u64, u64)>;
+    /// language-REGION, e.g. "en-US".
+    fn locale(&self) -> String;
+    /// IANA timezone name.
+    fn timezone(&self) -> String;
+    /// VM or container name; `None` on bare metal.
+    fn virtualization(&self) -> Option<String>;
+
+    /// All of the above as one snapshot.
+    fn collect(&self) -> PlatformInfo {
+        let memory = self.memory();
+        PlatformInfo {
+            os: self.os_name(),
+            version: self.os_version().unwrap_or_default(),
+            backend: self.backend(),
+            cpu_cores: self.cpu_cores(),
+            total_memory: memory.map(|(total, _)| total),
+            available_memory: memory.map(|(_, available)| available),
+            locale: self.locale(),
+            timezone: self.timezone(),
+            features: self.features(),
+            virtualization: self.virtualization(),
+        }
+    }
+}
+
+/// The platform the process is running on.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealPlatform;
+
+impl PlatformProvider for RealPlatform {
+    fn os_name(&self) -> String {
+        crate::os_name().to_string()
+    }
+
+    fn os_version(&self) -> Option<String> {
+        crate::os_version()
+    }
+
+    fn backend(&self) -> String {
+        crate::backend_info()
+    }
+
+    fn features(&self) -> Vec<String> {
+        crate::available_features().into_iter().map(String::from).collect()
+    }
+
+    fn cpu_cores(&self) -> Option<usize> {
+        crate::hardware::cpu_cores()
+    }
+
+    fn memory(&self) -> Option<(u64, u64)> {
+        crate::hardware::memory()
+    }
+
+    fn locale(&self) -> String {
+        crate::detect_locale()
+    }
+
+    fn timezone(&self) -> String {
+        crate::detect_timezone()
+    }
+
+    // A browser tab can't see what the machine runs on
+    fn virtualization(&self) -> Option<String> {
+        #[cfg(not(target_arch = "wasm32"))]
+        return crate::detect_virtualization().map(String::from);
+
+        #[cfg(target_arch = "wasm32")]
+        return None;
+    }
+}
+
+/// Fixed answers, for tests and previews. [`Default`] describes a plain
+/// Linux machine; override fields on [`MockPlatform::info`] as needed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockPlatform {
+    pub info: PlatformInfo,
+}
+
+impl Default for MockPlatform {
+    fn default() -> Self {
+        Self {
+            info: PlatformInfo {
+                os: "Linux".to_string(),
+                version: "Ubuntu 22.04".to_string(),
+                backend: "X11 (femtovg)".to_string(),
+                cpu_cores: Some(8),
+                total_memory: Some(16 << 30),
+                available_memory: Some(8 << 30),
+                locale: "en-US".to_string(),
+                timezone: "UTC".to_string(),
+                features: vec!["Basic UI".to_string(), "Theming".to_string()],
+                virtualization: None,
+            },
+        }
+    }
+}
+
+impl PlatformProvider for MockPlatform {
+    fn os_name(&self) -> String {
+        self.info.os.clone()
+    }
+
+    fn os_version(&self) -> Option<String> {
+        (!self.info.version.is_empty()).then(|| self.info.version.clone())
+    }
+
+    fn backend(&self) -> String {
+        self.info.backend.clone()
+    }
+
+    fn features(&self) -> Vec<String> {
+        self.info.features.clone()
+    }
+
+    fn cpu_cores(&self) -> Option<usize> {
+        self.info.cpu_cores
+    }
+
+    fn memory(&self) -> Option<(u64, u64)> {
+        self.info.total_memory.zip(self.info.available_memory)
+    }
+
+    fn locale(&self) -> String {
+        self.info.locale.clone()
+    }
+
+    fn timezone(&self) -> String {
+        self.info.timezone.clone()
+    }
+
+    fn virtualization(&self) -> Option<String> {
+        self.info.virtualization.clone()
+    }
+}
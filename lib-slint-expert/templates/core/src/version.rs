@@ -0,0 +1,299 @@
+//! Operating system version details.
+
+use std::sync::OnceLock;
+
+/// Release details, e.g. "Ubuntu 22.04", "11 23H2" or "14.2 on Apple M1";
+/// `None` when unknown. Read from the system on the first call only; it
+/// can't change while the process runs.
+pub fn os_version() -> Option<String> {
+    static VERSION: OnceLock<Option<String>> = OnceLock::new();
+    VERSION.get_or_init(detect_os_version).clone()
+}
+
+fn detect_os_version() -> Option<String> {
+    #[cfg(target_os = "windows")]
+    let version = Some(windows_version());
+
+    #[cfg(target_os = "macos")]
+    let version = Some(macos_details());
+
+    #[cfg(target_os = "linux")]
+    let version = linux_distribution();
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+    let version = None;
+
+    version
+}
+
+#[cfg(target_os = "linux")]
+fn linux_distribution() -> Option<String> {
+    let contents = std::fs::read_to_string("/etc/os-release").ok()?;
+    parse_os_release(&contents)
+}
+
+// Builds "NAME VERSION_ID" from os-release contents, e.g. "Ubuntu 22.04".
+// Rolling releases such as Arch ship no VERSION_ID, so only NAME is required.
+#[cfg(target_os = "linux")]
+fn parse_os_release(contents: &str) -> Option<String> {
+    let mut name = None;
+    let mut version = None;
+
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"').trim_matches('\'');
+
+        match key.trim() {
+            "NAME" if !value.is_empty() => name = Some(value),
+            "VERSION_ID" if !value.is_empty() => version = Some(value),
+            _ => {}
+        }
+    }
+
+    match (name?, version) {
+        (name, Some(version)) => Some(format!("{} {}", name, version)),
+        (name, None) => Some(name.to_string()),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn macos_details() -> String {
+    format_macos_details(&LibcSysctl)
+}
+
+// Indirection over sysctlbyname so the formatting can be driven by canned
+// values on hosts that aren't macOS.
+#[cfg(any(target_os = "macos", test))]
+trait Sysctl {
+    fn string(&self, name: &str) -> Option<String>;
+    fn int(&self, name: &str) -> Option<i32>;
+}
+
+#[cfg(any(target_os = "macos", test))]
+fn format_macos_details(sysctl: &impl Sysctl) -> String {
+    let mut details = sysctl.string("kern.osproductversion").unwrap_or_else(|| "unknown version".to_string());
+
+    if let Some(cpu) = sysctl.string("machdep.cpu.brand_string") {
+        details.push_str(&format!(" on {}", cpu));
+    }
+
+    // Only present on Apple Silicon; 1 means this process is translated.
+    if sysctl.int("sysctl.proc_translated") == Some(1) {
+        details.push_str(" under Rosetta");
+    }
+
+    details
+}
+
+#[cfg(target_os = "macos")]
+struct LibcSysctl;
+
+#[cfg(target_os = "macos")]
+impl LibcSysctl {
+    fn read(name: &str, buf: *mut libc::c_void, len: &mut libc::size_t) -> Option<()> {
+        let name = std::ffi::CString::new(name).ok()?;
+        // SAFETY: `name` is NUL-terminated and `buf`/`len` describe a valid
+        // buffer (or a null buffer when only querying the size).
+        let rc = unsafe { libc::sysctlbyname(name.as_ptr(), buf, len, std::ptr::null_mut(), 0) };
+        (rc == 0).then_some(())
+    }
+}
+
+#[cfg(target_os = "macos")]
+impl Sysctl for LibcSysctl {
+    fn string(&self, name: &str) -> Option<String> {
+        let mut len = 0;
+        Self::read(name, std::ptr::null_mut(), &mut len)?;
+
+        let mut buf = vec![0u8; len];
+        Self::read(name, buf.as_mut_ptr().cast(), &mut len)?;
+        buf.truncate(len);
+
+        let value = String::from_utf8_lossy(&buf);
+        let value = value.trim_end_matches('\0').trim();
+        (!value.is_empty()).then(|| value.to_string())
+    }
+
+    fn int(&self, name: &str) -> Option<i32> {
+        let mut value: i32 = 0;
+        let mut len = std::mem::size_of::<i32>();
+        Self::read(name, (&mut value as *mut i32).cast(), &mut len)?;
+        Some(value)
+    }
+}
+
+// RtlGetVersion reports the real OS version; GetVersionEx lies to
+// unmanifested binaries and always claims Windows 8.
+#[cfg(target_os = "windows")]
+fn windows_version() -> String {
+    use windows::Wdk::System::SystemServices::RtlGetVersion;
+    use windows::Win32::System::SystemInformation::OSVERSIONINFOW;
+
+    let mut info = OSVERSIONINFOW {
+        dwOSVersionInfoSize: std::mem::size_of::<OSVERSIONINFOW>() as u32,
+        ..Default::default()
+    };
+
+    // SAFETY: `info` is a properly sized OSVERSIONINFOW owned by this frame.
+    let status = unsafe { RtlGetVersion(&mut info) };
+    if status.is_err() {
+        return "unknown version".to_string();
+    }
+
+    describe_windows_version(info.dwMajorVersion, info.dwMinorVersion, info.dwBuildNumber)
+}
+
+#[cfg(target_os = "windows")]
+fn describe_windows_version(major: u32, minor: u32, build: u32) -> String {
+    match (major, minor) {
+        // Windows 11 still reports itself as 10.0; only the build number differs.
+        (10, 0) if build >= 22000 => {
+            let release = match build {
+                26100.. => "24H2",
+                22631.. => "23H2",
+                22621.. => "22H2",
+                _ => "21H2",
+            };
+            format!("11 {}", release)
+        }
+        (10, 0) => {
+            let release = match build {
+                19045.. => "22H2",
+                19044 => "21H2",
+                19043 => "21H1",
+                19042 => "20H2",
+                19041 => "2004",
+                18363 => "1909",
+                18362 => "1903",
+                17763 => "1809",
+                17134 => "1803",
+                16299 => "1709",
+                15063 => "1703",
+                14393 => "1607",
+                10586 => "1511",
+                10240 => "1507",
+                _ => return format!("10 (build {})", build),
+            };
+            format!("10 {}", release)
+        }
+        (6, 3) => "8.1".to_string(),
+        (6, 2) => "8".to_string(),
+        (6, 1) => "7".to_string(),
+        _ => format!("{}.{} (build {})", major, minor, build),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct MockSysctl {
+        product_version: Option<&'static str>,
+        cpu: Option<&'static str>,
+        translated: Option<i32>,
+    }
+
+    impl Sysctl for MockSysctl {
+        fn string(&self, name: &str) -> Option<String> {
+            match name {
+                "kern.osproductversion" => self.product_version.map(str::to_string),
+                "machdep.cpu.brand_string" => self.cpu.map(str::to_string),
+                _ => None,
+            }
+        }
+
+        fn int(&self, name: &str) -> Option<i32> {
+            match name {
+                "sysctl.proc_translated" => self.translated,
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn macos_details_on_apple_silicon() {
+        let sysctl = MockSysctl {
+            product_version: Some("14.5"),
+            cpu: Some("Apple M1"),
+            translated: Some(0),
+        };
+        assert_eq!(format_macos_details(&sysctl), "14.5 on Apple M1");
+    }
+
+    #[test]
+    fn macos_details_under_rosetta() {
+        let sysctl = MockSysctl {
+            product_version: Some("14.5"),
+            cpu: Some("Apple M2 Pro"),
+            translated: Some(1),
+        };
+        assert_eq!(format_macos_details(&sysctl), "14.5 on Apple M2 Pro under Rosetta");
+    }
+
+    // Intel Macs don't have sysctl.proc_translated at all
+    #[test]
+    fn macos_details_on_intel() {
+        let sysctl = MockSysctl {
+            product_version: Some("13.6.7"),
+            cpu: Some("Intel(R) Core(TM) i7-9750H CPU @ 2.60GHz"),
+            translated: None,
+        };
+        assert_eq!(format_macos_details(&sysctl), "13.6.7 on Intel(R) Core(TM) i7-9750H CPU @ 2.60GHz");
+    }
+
+    #[test]
+    fn macos_details_without_sysctl_values() {
+        assert_eq!(format_macos_details(&MockSysctl::default()), "unknown version");
+    }
+
+    #[cfg(target_os = "linux")]
+    const UBUNTU: &str = r#"PRETTY_NAME="Ubuntu 22.04.4 LTS"
+NAME="Ubuntu"
+VERSION_ID="22.04"
+VERSION="22.04.4 LTS (Jammy Jellyfish)"
+VERSION_CODENAME=jammy
+ID=ubuntu
+ID_LIKE=debian
+HOME_URL="https://www.ubuntu.com/"
+"#;
+
+    #[cfg(target_os = "linux")]
+    const FEDORA: &str = r#"NAME="Fedora Linux"
+VERSION="40 (Workstation Edition)"
+ID=fedora
+VERSION_ID=40
+PLATFORM_ID="platform:f40"
+"#;
+
+    #[cfg(target_os = "linux")]
+    const ARCH: &str = r#"NAME="Arch Linux"
+PRETTY_NAME="Arch Linux"
+ID=arch
+BUILD_ID=rolling
+ANSI_COLOR="38;2;23;147;209"
+"#;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn os_release_name_and_version() {
+        assert_eq!(parse_os_release(UBUNTU).as_deref(), Some("Ubuntu 22.04"));
+        assert_eq!(parse_os_release(FEDORA).as_deref(), Some("Fedora Linux 40"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn os_release_without_version_id() {
+        assert_eq!(parse_os_release(ARCH).as_deref(), Some("Arch Linux"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn os_release_without_name() {
+        assert_eq!(parse_os_release("ID=custom\nVERSION_ID=1\n"), None);
+        assert_eq!(parse_os_release(""), None);
+        assert_eq!(parse_os_release("NAME=\"\"\n# comment\n"), None);
+    }
+}
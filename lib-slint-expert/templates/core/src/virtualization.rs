@@ -0,0 +1,223 @@
+//! Virtual machine and container detection.
+
+/// The VM or container the process runs in, e.g. "Docker" or "KVM"; `None`
+/// on bare metal or when nothing gives it away. Containers win over the VM
+/// they may run in, since they're what the user set up. Cached after the
+/// first call.
+pub fn detect_virtualization() -> Option<&'static str> {
+    static DETECTED: std::sync::OnceLock<Option<&'static str>> = std::sync::OnceLock::new();
+    *DETECTED.get_or_init(|| {
+        #[cfg(target_os = "linux")]
+        if let Some(kind) = linux_container().or_else(systemd_detect_virt) {
+            return Some(kind);
+        }
+        hypervisor()
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn linux_container() -> Option<&'static str> {
+    if std::path::Path::new("/.dockerenv").exists() {
+        return Some("Docker");
+    }
+    // Written by podman into every container it starts
+    if std::path::Path::new("/run/.containerenv").exists() {
+        return Some("Podman");
+    }
+    container_from_cgroup(&std::fs::read_to_string("/proc/1/cgroup").ok()?)
+}
+
+/// The container runtime named in a `/proc/<pid>/cgroup` file. With cgroup
+/// v2 inside a private namespace the file is just "0::/", so this finds
+/// nothing there and the marker files above have to do.
+#[cfg(target_os = "linux")]
+pub fn container_from_cgroup(contents: &str) -> Option<&'static str> {
+    contents.lines().find_map(|line| {
+        // "hierarchy-id:controllers:path"
+        let path = line.rsplit(':').next()?;
+        if path.contains("kubepods") {
+            Some("Kubernetes")
+        } else if path.contains("docker") {
+            Some("Docker")
+        } else if path.contains("libpod") {
+            Some("Podman")
+        } else if path.contains("/lxc") {
+            Some("LXC")
+        } else {
+            None
+        }
+    })
+}
+
+// systemd knows more hypervisors than the checks here, when it is installed
+#[cfg(target_os = "linux")]
+fn systemd_detect_virt() -> Option<&'static str> {
+    let output = std::process::Command::new("systemd-detect-virt").output().ok()?;
+    virt_name(String::from_utf8_lossy(&output.stdout).trim())
+}
+
+// Names printed by systemd-detect-virt; "none" (and anything unknown) is
+// bare metal.
+#[cfg(target_os = "linux")]
+fn virt_name(id: &str) -> Option<&'static str> {
+    Some(match id {
+        "kvm" => "KVM",
+        "qemu" => "QEMU",
+        "vmware" => "VMware",
+        "microsoft" => "Hyper-V",
+        "oracle" => "VirtualBox",
+        "xen" => "Xen",
+        "amazon" => "AWS Nitro",
+        "parallels" => "Parallels",
+        "wsl" => "WSL",
+        "docker" => "Docker",
+        "podman" => "Podman",
+        "lxc" | "lxc-libvirt" => "LXC",
+        "systemd-nspawn" => "systemd-nspawn",
+        _ => return None,
+    })
+}
+
+// CPUID leaf 1 sets ECX bit 31 under a hypervisor, which then names itself
+// in leaf 0x40000000 (EBX, ECX, EDX; EAX is the highest hypervisor leaf).
+// `__cpuid` is only `unsafe` on older toolchains.
+#[cfg(target_arch = "x86_64")]
+#[allow(unused_unsafe)]
+fn hypervisor() -> Option<&'static str> {
+    use std::arch::x86_64::__cpuid;
+
+    // SAFETY: CPUID is available on every x86_64 CPU
+    let features = unsafe { __cpuid(1) };
+    if features.ecx & (1 << 31) == 0 {
+        return None;
+    }
+    // SAFETY: as above; leaves 0x40000000+ are reserved for hypervisors
+    let leaf = unsafe { __cpuid(0x4000_0000) };
+    let mut vendor = [0u8; 12];
+    vendor[..4].copy_from_slice(&leaf.ebx.to_le_bytes());
+    vendor[4..8].copy_from_slice(&leaf.ecx.to_le_bytes());
+    vendor[8..].copy_from_slice(&leaf.edx.to_le_bytes());
+
+    // Windows with virtualization-based security runs on Hyper-V itself, in
+    // the root partition, which is the only one allowed to create
+    // partitions (leaf 0x40000003, EBX bit 0)
+    let root_partition = &vendor == HYPER_V && leaf.eax >= 0x4000_0003 && {
+        // SAFETY: as above; the hypervisor reported this leaf in EAX
+        let privileges = unsafe { __cpuid(0x4000_0003) };
+        privileges.ebx & 1 != 0
+    };
+
+    hypervisor_name(&vendor, root_partition)
+}
+
+#[cfg(any(target_arch = "x86_64", test))]
+const HYPER_V: &[u8; 12] = b"Microsoft Hv";
+
+/// The hypervisor named by the CPUID vendor signature; `None` for the
+/// Hyper-V root partition, which is the bare-metal host.
+#[cfg(any(target_arch = "x86_64", test))]
+fn hypervisor_name(vendor: &[u8; 12], root_partition: bool) -> Option<&'static str> {
+    Some(match vendor {
+        HYPER_V if root_partition => return None,
+        b"KVMKVMKVM\0\0\0" => "KVM",
+        b"TCGTCGTCGTCG" => "QEMU",
+        b"VMwareVMware" => "VMware",
+        HYPER_V => "Hyper-V",
+        b"VBoxVBoxVBox" => "VirtualBox",
+        b"XenVMMXenVMM" => "Xen",
+        b" lrpepyh  vr" => "Parallels",
+        _ => "Virtual machine",
+    })
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn hypervisor() -> Option<&'static str> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_os = "linux")]
+    mod cgroup {
+        use super::super::container_from_cgroup;
+
+        const DOCKER_V1: &str = "\
+12:memory:/docker/3f4e9a1b2c7d8e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d3e4f
+11:cpu,cpuacct:/docker/3f4e9a1b2c7d8e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d3e4f
+1:name=systemd:/docker/3f4e9a1b2c7d8e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d3e4f
+";
+
+        // cgroup v2 seen from the host namespace (or with --cgroupns=host)
+        const DOCKER_V2: &str = "0::/system.slice/docker-3f4e9a1b2c7d8e0f1a2b3c4d5e6f7a8b.scope\n";
+
+        const PODMAN_V1: &str = "\
+10:pids:/machine.slice/libpod-8c2d1e0f9a8b7c6d5e4f3a2b1c0d9e8f.scope
+1:name=systemd:/machine.slice/libpod-8c2d1e0f9a8b7c6d5e4f3a2b1c0d9e8f.scope
+";
+
+        const PODMAN_V2: &str = "0::/user.slice/user-1000.slice/user@1000.service/user.slice/libpod-8c2d1e0f9a8b.scope/container\n";
+
+        const LXC_V1: &str = "\
+11:devices:/lxc/web01
+1:name=systemd:/lxc/web01
+";
+
+        const LXC_V2: &str = "0::/lxc.payload.web01\n";
+
+        const KUBERNETES_V1: &str = "11:memory:/kubepods/burstable/pod1a2b3c4d/3f4e9a1b2c7d\n";
+
+        const HOST_V1: &str = "\
+12:memory:/user.slice/user-1000.slice/session-2.scope
+1:name=systemd:/user.slice/user-1000.slice/session-2.scope
+";
+
+        // Inside a private cgroup namespace the path gives nothing away
+        const PRIVATE_V2: &str = "0::/\n";
+
+        #[test]
+        fn docker() {
+            assert_eq!(container_from_cgroup(DOCKER_V1), Some("Docker"));
+            assert_eq!(container_from_cgroup(DOCKER_V2), Some("Docker"));
+        }
+
+        #[test]
+        fn podman() {
+            assert_eq!(container_from_cgroup(PODMAN_V1), Some("Podman"));
+            assert_eq!(container_from_cgroup(PODMAN_V2), Some("Podman"));
+        }
+
+        #[test]
+        fn lxc() {
+            assert_eq!(container_from_cgroup(LXC_V1), Some("LXC"));
+            assert_eq!(container_from_cgroup(LXC_V2), Some("LXC"));
+        }
+
+        #[test]
+        fn kubernetes_wins_over_its_runtime() {
+            assert_eq!(container_from_cgroup(KUBERNETES_V1), Some("Kubernetes"));
+        }
+
+        #[test]
+        fn no_container() {
+            assert_eq!(container_from_cgroup(HOST_V1), None);
+            assert_eq!(container_from_cgroup(PRIVATE_V2), None);
+            assert_eq!(container_from_cgroup(""), None);
+        }
+    }
+
+    #[test]
+    fn hypervisor_vendors() {
+        assert_eq!(hypervisor_name(b"KVMKVMKVM\0\0\0", false), Some("KVM"));
+        assert_eq!(hypervisor_name(b"VMwareVMware", false), Some("VMware"));
+        assert_eq!(hypervisor_name(b"Microsoft Hv", false), Some("Hyper-V"));
+        assert_eq!(hypervisor_name(b"NewVisorXYZ1", false), Some("Virtual machine"));
+    }
+
+    // Bare-metal Windows with VBS sets the hypervisor bit too
+    #[test]
+    fn hyper_v_root_partition_is_bare_metal() {
+        assert_eq!(hypervisor_name(b"Microsoft Hv", true), None);
+    }
+}
@@ -0,0 +1,79 @@
+//! Time from `CrossPlatformApp::new()` to the first rendered frame.
+//!
+//! The window renders into memory with Slint's software renderer, so the
+//! numbers leave out the GPU setup and the compositor but need no display.
+//! `with_platform_info` adds the platform collection that used to run
+//! before the first frame and now runs after it; the gap between the two is
+//! what deferring it saves. Run with `cargo bench --bench cold_start`; the
+//! first, uncached `collect()` is timed once and printed before the
+//! timings.
+
+use std::rc::Rc;
+use std::time::Instant;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType, Rgb8Pixel};
+use slint::platform::{Platform, WindowAdapter};
+use slint::{ComponentHandle, PhysicalSize, PlatformError, SharedPixelBuffer};
+
+slint::include_modules!();
+
+// The window's size in main.slint
+const WIDTH: u32 = 600;
+const HEIGHT: u32 = 500;
+
+// Hands every window the same in-memory one, like `screenshot.rs`
+struct HeadlessPlatform {
+    window: Rc<MinimalSoftwareWindow>,
+}
+
+impl Platform for HeadlessPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(self.window.clone())
+    }
+}
+
+// Builds the window and renders its first frame; returns the window so it
+// is dropped outside the measurement.
+fn first_frame(window: &MinimalSoftwareWindow, pixels: &mut SharedPixelBuffer<Rgb8Pixel>, collect_first: bool) -> CrossPlatformApp {
+    let app = CrossPlatformApp::new().unwrap();
+    if collect_first {
+        std::hint::black_box(slint_skills_core::collect());
+    }
+
+    app.show().unwrap();
+    app.window().set_size(PhysicalSize::new(WIDTH, HEIGHT));
+    slint::platform::update_timers_and_animations();
+    window.request_redraw();
+    let drawn = window.draw_if_needed(|renderer| {
+        renderer.render(pixels.make_mut_slice(), WIDTH as usize);
+    });
+    assert!(drawn, "the window did not render");
+    app
+}
+
+fn cold_collect() {
+    let start = Instant::now();
+    std::hint::black_box(slint_skills_core::collect());
+    println!("first slint_skills_core::collect(): {:?}", start.elapsed());
+}
+
+fn cold_start(c: &mut Criterion) {
+    cold_collect();
+
+    let window = MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+    slint::platform::set_platform(Box::new(HeadlessPlatform { window: window.clone() })).unwrap();
+    let mut pixels = SharedPixelBuffer::<Rgb8Pixel>::new(WIDTH, HEIGHT);
+
+    let mut group = c.benchmark_group("cold_start");
+    group.bench_function("new_to_first_frame", |b| {
+        b.iter_with_large_drop(|| first_frame(&window, &mut pixels, false))
+    });
+    group.bench_function("new_to_first_frame/with_platform_info", |b| {
+        b.iter_with_large_drop(|| first_frame(&window, &mut pixels, true))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, cold_start);
+criterion_main!(benches);
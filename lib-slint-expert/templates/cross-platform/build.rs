@@ -1,3 +1,9 @@
+// Font files imported by assets/fonts/fonts.slint
+const FONT_FILES: &[&str] = &["Inter-Regular.ttf", "Inter-Bold.ttf", "NotoSansSC-Regular.ttf"];
+
+// Values accepted in SLINT_STYLE
+const STYLES: &[&str] = &["fluent", "material", "native", "cupertino"];
+
 fn main() {
     let mut config = slint_build::CompilerConfiguration::new();
 
@@ -23,10 +29,225 @@ fn main() {
         config = config.with_style("material");
     }
 
-    // Compile the UI
-    slint_build::compile_with_config("ui/main.slint", config).unwrap();
+    // SLINT_STYLE overrides the per-OS default, e.g. to render fluent on Linux
+    println!("cargo:rerun-if-env-changed=SLINT_STYLE");
+    if let Some(style) = style_override() {
+        config = config.with_style(style);
+    }
+
+    // Bundle the gettext catalogs so `select_bundled_translation` can switch
+    // languages at runtime without shipping .mo files
+    let translations = std::path::Path::new(&std::env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("translations");
+    config = config.with_bundled_translations(&translations);
+    println!("cargo:rerun-if-changed={}", translations.display());
+
+    // `@fonts` resolves to the bundled fonts or to the system-font stand-in,
+    // `@icon` to the embedded application icon or to an empty one
+    config = config.with_library_paths(std::collections::HashMap::from([
+        ("fonts".to_string(), with_fonts()),
+        ("icon".to_string(), with_icon()),
+    ]));
+
+    // Version details for the About panel (src/build_info.rs)
+    with_build_info();
+
+    // Live reload interprets the UI at runtime in debug builds; tell the
+    // binary where the sources are. Release builds never load .slint files.
+    let ui_entry = std::path::Path::new(&std::env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("src/ui/main.slint");
+    if std::env::var_os("CARGO_FEATURE_LIVE_RELOAD").is_some() && std::env::var("PROFILE").as_deref() == Ok("debug") {
+        println!("cargo:rustc-env=SLINT_UI_PATH={}", ui_entry.display());
+    }
+
+    // Compile the UI: main.slint plus every screen in src/ui/screens, through
+    // one generated entry file so `include_modules!` sees all of them
+    let screens = ui_entry.with_file_name("screens");
+    slint_build::compile_with_config(with_screens(&ui_entry, &screens), config).unwrap();
 
     // Print target information for debugging
-    println!("cargo:rerun-if-changed=ui/main.slint");
+    println!("cargo:rerun-if-changed=src/ui");
+    println!("cargo:rerun-if-changed={}", screens.display());
     println!("cargo:rerun-if-changed=build.rs");
-}
\ No newline at end of file
+}
+
+// Returns the file to compile: `main` itself when there are no screens,
+// otherwise a file in OUT_DIR re-exporting `main` and each screen's exports.
+// Panics if two files export the same name, since only one of them could be
+// reached from Rust.
+fn with_screens(main: &std::path::Path, screens_dir: &std::path::Path) -> std::path::PathBuf {
+    let mut screens: Vec<_> = std::fs::read_dir(screens_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "slint"))
+        .collect();
+    if screens.is_empty() {
+        return main.to_path_buf();
+    }
+    screens.sort();
+
+    let mut owners: std::collections::HashMap<String, std::path::PathBuf> =
+        exported_names(main).into_iter().map(|name| (name, main.to_path_buf())).collect();
+    let mut source = format!("export * from \"{}\";\n", slint_path(main));
+
+    for screen in &screens {
+        let names = exported_names(screen);
+        for name in &names {
+            if let Some(owner) = owners.insert(name.clone(), screen.clone()) {
+                panic!(
+                    "`{name}` is exported by both {} and {}; rename one of them",
+                    owner.display(),
+                    screen.display()
+                );
+            }
+        }
+        if !names.is_empty() {
+            source += &format!("export {{ {} }} from \"{}\";\n", names.join(", "), slint_path(screen));
+        }
+    }
+
+    let entry = std::path::Path::new(&std::env::var_os("OUT_DIR").unwrap()).join("ui.slint");
+    std::fs::write(&entry, source).unwrap();
+    entry
+}
+
+// Names declared with `export component|global|struct|enum` in `file`.
+fn exported_names(file: &std::path::Path) -> Vec<String> {
+    let source = std::fs::read_to_string(file).unwrap_or_else(|err| panic!("cannot read {}: {err}", file.display()));
+    source
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim_start().strip_prefix("export ")?;
+            let (kind, rest) = rest.split_once(char::is_whitespace)?;
+            if !matches!(kind, "component" | "global" | "struct" | "enum") {
+                return None;
+            }
+            let name = rest.split(|c: char| c.is_whitespace() || c == '{').next()?;
+            (!name.is_empty()).then(|| name.to_string())
+        })
+        .collect()
+}
+
+// Slint import paths use forward slashes on every host.
+fn slint_path(path: &std::path::Path) -> String {
+    path.display().to_string().replace('\\', "/")
+}
+
+// Emits BUILD_GIT_HASH and BUILD_TIMESTAMP (Unix seconds, or
+// SOURCE_DATE_EPOCH for reproducible builds). Without git, e.g. in a source
+// tarball, the hash is "unknown".
+fn with_build_info() {
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let hash = match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) if git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty()) => format!("{hash}-dirty"),
+        Some(hash) => hash,
+        None => "unknown".to_string(),
+    };
+    println!("cargo:rustc-env=BUILD_GIT_HASH={hash}");
+
+    // Pick up new commits and branch switches
+    if let Some(head) = git(&["rev-parse", "--git-path", "HEAD"]) {
+        println!("cargo:rerun-if-changed={head}");
+    }
+    if let Some(branch) = git(&["symbolic-ref", "-q", "HEAD"]).and_then(|name| git(&["rev-parse", "--git-path", &name])) {
+        println!("cargo:rerun-if-changed={branch}");
+    }
+
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    let timestamp = std::env::var("SOURCE_DATE_EPOCH").ok().unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs())
+            .to_string()
+    });
+    println!("cargo:rustc-env=BUILD_TIMESTAMP={timestamp}");
+}
+
+// Reads SLINT_STYLE, failing the build on a style Slint doesn't ship.
+fn style_override() -> Option<String> {
+    let style = std::env::var("SLINT_STYLE").ok().filter(|style| !style.is_empty())?;
+    if !STYLES.contains(&style.as_str()) {
+        panic!("SLINT_STYLE={style:?} is not a known style; use one of: {}", STYLES.join(", "));
+    }
+    Some(style)
+}
+
+// Picks the file `@fonts` maps to. With `bundled-fonts` the TTFs are
+// embedded into the binary (and the wasm module), so every platform renders
+// the same glyphs.
+fn with_fonts() -> std::path::PathBuf {
+    let manifest_dir = std::path::PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap());
+
+    if std::env::var_os("CARGO_FEATURE_BUNDLED_FONTS").is_none() {
+        return manifest_dir.join("src/ui/system-fonts.slint");
+    }
+
+    let fonts = manifest_dir.join("assets/fonts");
+    for file in FONT_FILES {
+        let path = fonts.join(file);
+        if !path.exists() {
+            panic!(
+                "the `bundled-fonts` feature needs {}; \
+                 add it or build without `--features bundled-fonts`",
+                path.display()
+            );
+        }
+    }
+    println!("cargo:rerun-if-changed={}", fonts.display());
+    fonts.join("fonts.slint")
+}
+
+// Picks the file `@icon` maps to. With `app-icon` it is generated to embed
+// assets/icon.png through `@image-url`, so the PNG is decoded at build time,
+// and Windows targets also get the .ico in the executable's resources.
+fn with_icon() -> std::path::PathBuf {
+    let manifest_dir = std::path::PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap());
+
+    if std::env::var_os("CARGO_FEATURE_APP_ICON").is_none() {
+        return manifest_dir.join("src/ui/no-icon.slint");
+    }
+
+    let assets = manifest_dir.join("assets");
+
+    let png = assets.join("icon.png");
+    if !png.exists() {
+        panic!(
+            "the `app-icon` feature needs {} (a square PNG, 256x256 recommended); \
+             add it or build without `--features app-icon`",
+            png.display()
+        );
+    }
+    println!("cargo:rerun-if-changed={}", png.display());
+
+    // Windows resources need an .ico; winres can't convert PNGs.
+    if std::env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("windows") {
+        let ico = assets.join("icon.ico");
+        if !ico.exists() {
+            panic!(
+                "the `app-icon` feature needs {} when targeting Windows; \
+                 convert assets/icon.png to .ico (e.g. `magick icon.png icon.ico`)",
+                ico.display()
+            );
+        }
+        println!("cargo:rerun-if-changed={}", ico.display());
+
+        let mut resource = winres::WindowsResource::new();
+        resource.set_icon(ico.to_str().unwrap());
+        resource.compile().unwrap();
+    }
+
+    let icon = std::path::Path::new(&std::env::var_os("OUT_DIR").unwrap()).join("icon.slint");
+    let source = format!(
+        "export global AppIcon {{\n    out property <image> image: @image-url(\"{}\");\n}}\n",
+        slint_path(&png)
+    );
+    std::fs::write(&icon, source).unwrap();
+    icon
+}
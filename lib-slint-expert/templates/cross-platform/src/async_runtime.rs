@@ -0,0 +1,66 @@
+//! A tokio runtime running next to Slint's event loop (desktop only).
+//!
+//! Futures run on the runtime's worker threads and hand their output back to
+//! the UI thread through `slint::invoke_from_event_loop`, so only plain data
+//! crosses threads and component handles stay on the UI thread. The runtime
+//! lives as long as the [`RuntimeGuard`] returned by [`start`]; dropping it
+//! after the event loop returns shuts the workers down.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::runtime::Runtime;
+
+/// How long shutdown waits for tasks that are still running, e.g. a request
+/// that hasn't answered yet. Whatever is left after that is abandoned.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
+
+thread_local! {
+    // Only the UI thread spawns, so the runtime is kept in its thread-local
+    static RUNTIME: RefCell<Option<Runtime>> = const { RefCell::new(None) };
+}
+
+/// Shuts the runtime down when dropped.
+pub struct RuntimeGuard(());
+
+impl Drop for RuntimeGuard {
+    fn drop(&mut self) {
+        if let Some(runtime) = RUNTIME.with(|cell| cell.borrow_mut().take()) {
+            runtime.shutdown_timeout(SHUTDOWN_TIMEOUT);
+        }
+    }
+}
+
+/// Starts the runtime for the calling (UI) thread. Keep the guard until the
+/// event loop has returned.
+pub fn start() -> std::io::Result<RuntimeGuard> {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .thread_name("async-worker")
+        .enable_all()
+        .build()?;
+    RUNTIME.with(|cell| *cell.borrow_mut() = Some(runtime));
+    Ok(RuntimeGuard(()))
+}
+
+/// Runs `future` on the runtime and calls `on_done` with its output on the
+/// UI thread. Returns `false`, without running anything, when called off the
+/// UI thread or after shutdown. If the event loop quits first, `on_done` is
+/// dropped without being called.
+pub fn spawn_async<F>(future: F, on_done: impl FnOnce(F::Output) + Send + 'static) -> bool
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    RUNTIME.with(|cell| {
+        let Some(runtime) = cell.borrow().as_ref().map(|runtime| runtime.handle().clone()) else {
+            return false;
+        };
+        runtime.spawn(async move {
+            let output = future.await;
+            let _ = slint::invoke_from_event_loop(move || on_done(output));
+        });
+        true
+    })
+}
@@ -0,0 +1,54 @@
+//! Version, commit and build time baked in by `build.rs`, for the About
+//! panel.
+
+use serde::Serialize;
+
+/// Shown for fields the build couldn't determine, e.g. the commit when
+/// building from a source tarball without git.
+pub const UNKNOWN: &str = "unknown";
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BuildInfo {
+    /// Crate version from Cargo.toml.
+    pub version: &'static str,
+    /// Short commit hash, with a `-dirty` suffix for uncommitted changes.
+    pub git_hash: &'static str,
+    /// When the build script last ran, as "YYYY-MM-DD HH:MM UTC".
+    pub built_at: String,
+}
+
+impl BuildInfo {
+    pub fn current() -> Self {
+        let built_at = env!("BUILD_TIMESTAMP")
+            .parse()
+            .ok()
+            .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+            .map_or_else(|| UNKNOWN.to_string(), |time| time.format("%Y-%m-%d %H:%M UTC").to_string());
+
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            git_hash: env!("BUILD_GIT_HASH"),
+            built_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_has_a_version() {
+        let info = BuildInfo::current();
+        assert!(!info.version.is_empty());
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    }
+
+    // build.rs always emits both, falling back to "unknown" without git
+    #[test]
+    fn current_fills_every_field() {
+        let info = BuildInfo::current();
+        assert!(!info.git_hash.is_empty());
+        assert!(!info.built_at.is_empty());
+    }
+}
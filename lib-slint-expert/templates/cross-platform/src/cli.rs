@@ -0,0 +1,65 @@
+//! Command-line flags for the desktop build.
+
+use clap::builder::PossibleValuesParser;
+use clap::Parser;
+
+use crate::logging;
+use crate::renderer;
+use crate::theme;
+use crate::AppOptions;
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Slint cross-platform demo")]
+struct Cli {
+    /// Theme to start with, overriding the saved one
+    #[arg(long, value_parser = PossibleValuesParser::new(theme::THEME_MODES))]
+    theme: Option<String>,
+
+    /// Initial window width in logical pixels
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    width: Option<u32>,
+
+    /// Initial window height in logical pixels
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    height: Option<u32>,
+
+    /// UI language such as "zh", overriding the system language
+    #[arg(long)]
+    language: Option<String>,
+
+    /// Renderer to draw with; falls back to the default when unavailable
+    #[arg(long, value_parser = PossibleValuesParser::new(renderer::RENDERERS))]
+    renderer: Option<String>,
+
+    /// Run the platform checks once, print the results and exit without
+    /// showing a window; no display is needed
+    #[arg(long)]
+    headless: bool,
+
+    /// Render the window to this PNG file without a display and exit; the
+    /// size comes from --width/--height
+    #[arg(long, value_name = "PATH")]
+    screenshot: Option<std::path::PathBuf>,
+
+    /// Log verbosity, overriding RUST_LOG
+    #[arg(long, value_parser = PossibleValuesParser::new(logging::LEVELS))]
+    log_level: Option<String>,
+}
+
+/// Parses the process arguments. Invalid or unknown flags print usage and
+/// exit with a non-zero status. Unset options may still come from
+/// [`crate::config`].
+pub fn parse() -> AppOptions {
+    let cli = Cli::parse();
+
+    AppOptions {
+        theme: cli.theme,
+        width: cli.width,
+        height: cli.height,
+        language: cli.language,
+        renderer: cli.renderer,
+        headless: cli.headless,
+        screenshot: cli.screenshot,
+        log_level: cli.log_level,
+    }
+}
@@ -0,0 +1,44 @@
+//! Copying text to the system clipboard.
+
+/// Copies `text` and reports the outcome through `on_done`. The browser
+/// clipboard API is asynchronous, so on the web `on_done` runs later on the
+/// UI thread; on desktop it runs before this returns.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn copy_text(text: String, on_done: impl FnOnce(Result<(), String>) + 'static) {
+    thread_local! {
+        // On X11 and Wayland the copying process serves the clipboard
+        // contents, so the handle must outlive this call.
+        static CLIPBOARD: std::cell::RefCell<Option<arboard::Clipboard>> = const { std::cell::RefCell::new(None) };
+    }
+
+    let result = CLIPBOARD.with(|slot| {
+        let mut slot = slot.borrow_mut();
+        if slot.is_none() {
+            *slot = Some(arboard::Clipboard::new().map_err(|err| err.to_string())?);
+        }
+        slot.as_mut()
+            .expect("clipboard initialized above")
+            .set_text(text)
+            .map_err(|err| err.to_string())
+    });
+
+    on_done(result);
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn copy_text(text: String, on_done: impl FnOnce(Result<(), String>) + 'static) {
+    let Some(window) = web_sys::window() else {
+        on_done(Err("no browser window".to_string()));
+        return;
+    };
+    // Only available in secure contexts (https or localhost).
+    let promise = window.navigator().clipboard().write_text(&text);
+
+    wasm_bindgen_futures::spawn_local(async move {
+        let result = wasm_bindgen_futures::JsFuture::from(promise)
+            .await
+            .map(|_| ())
+            .map_err(|err| err.as_string().unwrap_or_else(|| "clipboard write was rejected".to_string()));
+        on_done(result);
+    });
+}
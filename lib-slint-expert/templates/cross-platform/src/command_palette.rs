@@ -0,0 +1,175 @@
+//! Ctrl+K command palette: named actions, filtered as you type.
+//!
+//! Commands live in a per-thread registry shared by every window and run
+//! against the window whose palette invoked them. Adding one is a single
+//! [`register`] call.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use slint::{ComponentHandle, ModelRc, SharedString, VecModel};
+
+use crate::fuzzy;
+use crate::CrossPlatformApp;
+
+type Action = Rc<dyn Fn(&CrossPlatformApp)>;
+
+thread_local! {
+    static COMMANDS: RefCell<Vec<(String, Action)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Adds a command, or replaces the one with the same name.
+pub fn register(name: &str, action: impl Fn(&CrossPlatformApp) + 'static) {
+    COMMANDS.with_borrow_mut(|commands| {
+        let action: Action = Rc::new(action);
+        match commands.iter_mut().find(|(existing, _)| existing == name) {
+            Some(entry) => entry.1 = action,
+            None => commands.push((name.to_string(), action)),
+        }
+    });
+}
+
+/// The commands the template ships with.
+pub fn register_defaults() {
+    register("Toggle Theme", |app| app.invoke_toggle_theme());
+    register("Refresh Platform Info", |app| app.invoke_refresh_platform_info());
+    register("Test Features", |app| app.invoke_test_features());
+    register("Export Diagnostics", |app| app.invoke_export_diagnostics());
+}
+
+/// Command names matching `query`, ranked by [`fuzzy::filter_and_rank`].
+/// An empty query lists every command in registration order.
+pub fn matches(query: &str) -> Vec<String> {
+    COMMANDS.with_borrow(|commands| {
+        let names: Vec<&str> = commands.iter().map(|(name, _)| name.as_str()).collect();
+        fuzzy::filter_and_rank(query, &names).into_iter().map(|name| name.to_string()).collect()
+    })
+}
+
+/// Runs the command called `name` on `app`. Returns `false` when there is
+/// none.
+pub fn run(name: &str, app: &CrossPlatformApp) -> bool {
+    // Cloned out so the action may register commands itself
+    let action = COMMANDS.with_borrow(|commands| {
+        commands.iter().find(|(existing, _)| existing == name).map(|(_, action)| action.clone())
+    });
+    match action {
+        Some(action) => {
+            tracing::debug!(command = name, "running command");
+            action(app);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Opens `app`'s palette with every command listed.
+pub fn open(app: &CrossPlatformApp) {
+    show_matches(app, "");
+    app.set_show_palette(true);
+}
+
+fn show_matches(app: &CrossPlatformApp, query: &str) {
+    let names: Vec<SharedString> = matches(query).into_iter().map(SharedString::from).collect();
+    app.set_palette_results(ModelRc::new(VecModel::from(names)));
+    app.set_palette_index(0);
+}
+
+/// Wires `app`'s palette to the registry.
+pub fn install(app: &CrossPlatformApp) {
+    let app_weak = app.as_weak();
+    app.on_palette_query_changed(move |query| {
+        if let Some(app) = app_weak.upgrade() {
+            show_matches(&app, &query);
+        }
+    });
+
+    let app_weak = app.as_weak();
+    app.on_palette_run(move |name| {
+        if let Some(app) = app_weak.upgrade() {
+            run(&name, &app);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use slint::Model;
+
+    use super::*;
+
+    // The registry is per thread, so every test starts with it empty
+    fn app() -> CrossPlatformApp {
+        i_slint_backend_testing::init_no_event_loop();
+        CrossPlatformApp::new().unwrap()
+    }
+
+    // Registers `name` with an action that counts its runs
+    fn counted(name: &str) -> Rc<Cell<u32>> {
+        let runs = Rc::new(Cell::new(0));
+        let counter = runs.clone();
+        register(name, move |_| counter.set(counter.get() + 1));
+        runs
+    }
+
+    #[test]
+    fn defaults_are_listed_in_registration_order() {
+        register_defaults();
+        assert_eq!(matches(""), ["Toggle Theme", "Refresh Platform Info", "Test Features", "Export Diagnostics"]);
+    }
+
+    #[test]
+    fn registering_a_name_again_replaces_the_action() {
+        let first = counted("Greet");
+        let second = counted("Greet");
+        assert_eq!(matches(""), ["Greet"]);
+
+        assert!(run("Greet", &app()));
+        assert_eq!((first.get(), second.get()), (0, 1));
+    }
+
+    #[test]
+    fn queries_filter_and_rank() {
+        register_defaults();
+        assert_eq!(matches("tf"), ["Test Features", "Refresh Platform Info"]);
+        assert_eq!(matches("theme"), ["Toggle Theme"]);
+        assert!(matches("xyz").is_empty());
+    }
+
+    #[test]
+    fn running_an_unknown_command_does_nothing() {
+        let runs = counted("Greet");
+        assert!(!run("Greeting", &app()));
+        assert_eq!(runs.get(), 0);
+    }
+
+    #[test]
+    fn actions_may_register_commands() {
+        register("Add Another", |_| register("Another", |_| {}));
+        assert!(run("Add Another", &app()));
+        assert_eq!(matches(""), ["Add Another", "Another"]);
+    }
+
+    #[test]
+    fn the_palette_shows_matches_and_runs_the_pick() {
+        let app = app();
+        install(&app);
+        register_defaults();
+        let runs = counted("Test Features");
+
+        open(&app);
+        assert!(app.get_show_palette());
+        assert_eq!(app.get_palette_results().row_count(), 4);
+
+        app.set_palette_index(2);
+        app.invoke_palette_query_changed("tf".into());
+        let results: Vec<SharedString> = app.get_palette_results().iter().collect();
+        assert_eq!(results, ["Test Features", "Refresh Platform Info"]);
+        assert_eq!(app.get_palette_index(), 0);
+
+        app.invoke_palette_run("Test Features".into());
+        assert_eq!(runs.get(), 1);
+    }
+}
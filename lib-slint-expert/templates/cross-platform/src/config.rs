@@ -0,0 +1,192 @@
+//! Default startup options from `config.toml` in the platform config
+//! directory (desktop only).
+//!
+//! ```toml
+//! theme = "dark"
+//! width = 1024
+//! height = 768
+//! language = "zh"
+//! preferred_renderer = "skia"
+//! log_level = "debug"
+//! ```
+//!
+//! Every key is optional. Precedence is command line > environment >
+//! config file > built-in behaviour. `RUST_LOG` sets the log level and
+//! `SLINT_BACKEND` the renderer; the built-in behaviour is the saved theme
+//! preference, the restored window geometry and the system language.
+
+use serde::Deserialize;
+
+use crate::storage;
+use crate::AppOptions;
+
+pub const FILE_NAME: &str = "config.toml";
+
+/// The contents of `config.toml`. `Default` is an empty file: nothing is
+/// overridden.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// One of `theme::THEME_MODES`.
+    pub theme: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// Catalog language such as "zh", instead of the system one.
+    pub language: Option<String>,
+    /// One of `renderer::RENDERERS`.
+    pub preferred_renderer: Option<String>,
+    /// One of `logging::LEVELS`.
+    pub log_level: Option<String>,
+}
+
+impl Config {
+    /// Reads the config file. A missing file is an empty config; a file
+    /// that doesn't parse is an error describing where.
+    pub fn load() -> Result<Self, String> {
+        let Some(path) = storage::config_dir().map(|dir| dir.join(FILE_NAME)) else {
+            return Ok(Self::default());
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("{}: {}", path.display(), err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("{}: {}", path.display(), err)),
+        }
+    }
+
+    /// Parses the file contents. Sizes must be at least 1, as with
+    /// `--width` and `--height`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let config: Self = toml::from_str(text).map_err(|err| err.to_string())?;
+        for (key, value) in [("width", config.width), ("height", config.height)] {
+            if value == Some(0) {
+                return Err(format!("{} must be at least 1", key));
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// The environment variables that set startup options.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Env {
+    /// Log filter, read by `logging::init_logging`.
+    pub rust_log: Option<String>,
+    /// Backend and renderer, read by `renderer::select_default`.
+    pub slint_backend: Option<String>,
+}
+
+impl Env {
+    /// Reads the variables; empty ones count as unset.
+    pub fn read() -> Self {
+        let var = |name| std::env::var(name).ok().filter(|value| !value.is_empty());
+        Self { rust_log: var("RUST_LOG"), slint_backend: var("SLINT_BACKEND") }
+    }
+}
+
+/// Fills in what the command line left unset from `config`, except where
+/// `env` sets the option: those are left unset, so the variable applies
+/// when logging starts and the renderer is picked. Flags that only make
+/// sense per run, like `--headless`, come from `cli` alone.
+pub fn merge(cli: AppOptions, env: &Env, config: Config) -> AppOptions {
+    AppOptions {
+        theme: cli.theme.or(config.theme),
+        width: cli.width.or(config.width),
+        height: cli.height.or(config.height),
+        language: cli.language.or(config.language),
+        renderer: cli.renderer.or(config.preferred_renderer.filter(|_| env.slint_backend.is_none())),
+        log_level: cli.log_level.or(config.log_level.filter(|_| env.rust_log.is_none())),
+        ..cli
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(text: &str) -> Config {
+        Config::parse(text).unwrap()
+    }
+
+    fn no_flags() -> AppOptions {
+        AppOptions::default()
+    }
+
+    fn env(rust_log: Option<&str>, slint_backend: Option<&str>) -> Env {
+        Env { rust_log: rust_log.map(Into::into), slint_backend: slint_backend.map(Into::into) }
+    }
+
+    #[test]
+    fn the_command_line_wins_over_the_file() {
+        let options = merge(AppOptions { theme: Some("dark".into()), ..no_flags() }, &Env::default(), config(r#"theme = "sepia""#));
+        assert_eq!(options.theme.as_deref(), Some("dark"));
+
+        let options = merge(AppOptions { renderer: Some("skia".into()), ..no_flags() }, &Env::default(), config(r#"preferred_renderer = "software""#));
+        assert_eq!(options.renderer.as_deref(), Some("skia"));
+    }
+
+    #[test]
+    fn the_file_fills_in_what_the_command_line_leaves_out() {
+        let options = merge(no_flags(), &Env::default(), config(r#"theme = "sepia""#));
+        assert_eq!(options.theme.as_deref(), Some("sepia"));
+    }
+
+    #[test]
+    fn a_partial_file_leaves_the_rest_to_the_defaults() {
+        let options = merge(no_flags(), &Env::default(), config("width = 800"));
+        assert_eq!(options.width, Some(800));
+        assert_eq!((options.theme, options.height, options.language), (None, None, None));
+        assert_eq!((options.renderer, options.log_level), (None, None));
+    }
+
+    #[test]
+    fn per_run_flags_come_from_the_command_line_only() {
+        let options = merge(AppOptions { headless: true, ..no_flags() }, &Env::default(), Config::default());
+        assert!(options.headless);
+        assert_eq!(options.theme, None);
+        assert_eq!(options.screenshot, None);
+    }
+
+    // Leaving the option unset lets the variable apply
+    #[test]
+    fn the_environment_wins_over_the_file() {
+        let file = config("preferred_renderer = \"software\"\nlog_level = \"debug\"");
+        let options = merge(no_flags(), &env(Some("warn"), Some("winit-skia")), file);
+        assert_eq!(options.renderer, None);
+        assert_eq!(options.log_level, None);
+    }
+
+    #[test]
+    fn the_command_line_wins_over_the_environment() {
+        let flags = AppOptions { renderer: Some("femtovg".into()), log_level: Some("trace".into()), ..no_flags() };
+        let options = merge(flags, &env(Some("warn"), Some("winit-skia")), Config::default());
+        assert_eq!(options.renderer.as_deref(), Some("femtovg"));
+        assert_eq!(options.log_level.as_deref(), Some("trace"));
+    }
+
+    #[test]
+    fn unset_everywhere_is_the_built_in_behaviour() {
+        let options = merge(no_flags(), &Env::default(), Config::default());
+        assert_eq!(options.log_level, None);
+        assert_eq!(options.renderer, None);
+    }
+
+    #[test]
+    fn files_that_do_not_parse_are_errors() {
+        assert_eq!(config(""), Config::default());
+        assert!(Config::parse("colour = \"dark\"").unwrap_err().contains("unknown field `colour`"));
+        assert!(Config::parse("width = \"wide\"").is_err());
+        assert_eq!(Config::parse("width = 0"), Err("width must be at least 1".to_string()));
+        assert_eq!(Config::parse("width = 800\nheight = 0"), Err("height must be at least 1".to_string()));
+    }
+
+    #[test]
+    fn a_missing_file_is_an_empty_config() {
+        // `storage::config_dir` is a fresh temporary directory under test
+        assert_eq!(Config::load(), Ok(Config::default()));
+
+        let path = storage::config_dir().unwrap().join(FILE_NAME);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "height = 600").unwrap();
+        assert_eq!(Config::load(), Ok(Config { height: Some(600), ..Config::default() }));
+    }
+}
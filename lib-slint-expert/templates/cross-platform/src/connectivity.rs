@@ -0,0 +1,102 @@
+//! Online/offline detection for the connectivity indicator.
+
+use crate::ConnectivityStatus;
+
+/// Calls `on_change` with the first result and again whenever connectivity
+/// flips. The watcher stops once `on_change` returns `false`, e.g. because
+/// the event loop has shut down.
+///
+/// Desktop builds probe a public endpoint from a background thread, at most
+/// every [`CHECK_INTERVAL`] and less often while offline.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn watch(on_change: impl Fn(ConnectivityStatus) -> bool + Send + 'static) {
+    std::thread::spawn(move || {
+        let mut last = None;
+        let mut failures = 0;
+        loop {
+            let status = probe();
+            failures = match status {
+                ConnectivityStatus::Online => 0,
+                _ => failures + 1,
+            };
+
+            if last != Some(status) {
+                last = Some(status);
+                if !on_change(status) {
+                    break;
+                }
+            }
+            std::thread::sleep(retry_delay(failures));
+        }
+    });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub const CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+// Cap for the backoff: 10s doubled four times.
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_BACKOFF_STEPS: u32 = 4;
+
+/// Delay before the next probe after `failures` failed probes in a row:
+/// [`CHECK_INTERVAL`] while online or after one failure, then doubling with
+/// each further failure up to 160s.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn retry_delay(failures: u32) -> std::time::Duration {
+    CHECK_INTERVAL * 2u32.pow(failures.saturating_sub(1).min(MAX_BACKOFF_STEPS))
+}
+
+// A TCP handshake with well-known anycast DNS resolvers; no data is sent.
+// Online as soon as one of them answers.
+#[cfg(not(target_arch = "wasm32"))]
+fn probe() -> ConnectivityStatus {
+    use std::net::{SocketAddr, TcpStream};
+    use std::time::Duration;
+
+    const ENDPOINTS: [([u8; 4], u16); 2] = [([1, 1, 1, 1], 443), ([8, 8, 8, 8], 443)];
+    const TIMEOUT: Duration = Duration::from_secs(3);
+
+    let reachable = ENDPOINTS
+        .iter()
+        .any(|&(ip, port)| TcpStream::connect_timeout(&SocketAddr::from((ip, port)), TIMEOUT).is_ok());
+
+    if reachable {
+        ConnectivityStatus::Online
+    } else {
+        ConnectivityStatus::Offline
+    }
+}
+
+/// Browsers track connectivity themselves: read `navigator.onLine` once and
+/// then follow the window's `online`/`offline` events.
+#[cfg(target_arch = "wasm32")]
+pub fn watch(on_change: impl Fn(ConnectivityStatus) -> bool + 'static) {
+    use std::rc::Rc;
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+
+    let Some(window) = web_sys::window() else {
+        on_change(ConnectivityStatus::Unknown);
+        return;
+    };
+
+    let status = if window.navigator().on_line() {
+        ConnectivityStatus::Online
+    } else {
+        ConnectivityStatus::Offline
+    };
+    if !on_change(status) {
+        return;
+    }
+
+    let on_change = Rc::new(on_change);
+    for (event, status) in [("online", ConnectivityStatus::Online), ("offline", ConnectivityStatus::Offline)] {
+        let on_change = on_change.clone();
+        let listener = Closure::<dyn Fn()>::new(move || {
+            on_change(status);
+        });
+        let _ = window.add_event_listener_with_callback(event, listener.as_ref().unchecked_ref());
+        // The listeners live as long as the page.
+        listener.forget();
+    }
+}
@@ -0,0 +1,167 @@
+//! WCAG 2.x contrast checks for the palettes.
+//!
+//! Built-in themes are checked once at startup and failures are logged;
+//! custom token files are checked when they are loaded.
+
+use std::fmt;
+
+use slint::{Color, ComponentHandle};
+
+use crate::theme::THEMES;
+use crate::theme_tokens::ThemeTokens;
+use crate::{CrossPlatformApp, Palette};
+
+/// AA minimum for body text.
+pub const AA_NORMAL: f32 = 4.5;
+
+/// AA minimum for large text (18pt, or 14pt bold) and UI components.
+pub const AA_LARGE: f32 = 3.0;
+
+/// Relative luminance of `color` from 0 (black) to 1 (white). Alpha is
+/// ignored; palette colors are opaque.
+pub fn relative_luminance(color: Color) -> f32 {
+    let linear = |channel: u8| {
+        let value = channel as f32 / 255.0;
+        if value <= 0.04045 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(color.red()) + 0.7152 * linear(color.green()) + 0.0722 * linear(color.blue())
+}
+
+/// The contrast ratio between two colors, from 1 (same luminance) to 21
+/// (black on white). The order of `fg` and `bg` doesn't matter.
+pub fn contrast_ratio(fg: Color, bg: Color) -> f32 {
+    let (a, b) = (relative_luminance(fg), relative_luminance(bg));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Whether `fg` on `bg` meets WCAG AA for normal or large text.
+pub fn meets_aa(fg: Color, bg: Color, large_text: bool) -> bool {
+    contrast_ratio(fg, bg) >= if large_text { AA_LARGE } else { AA_NORMAL }
+}
+
+/// A token pairing below AA.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    pub foreground: &'static str,
+    pub background: &'static str,
+    pub ratio: f32,
+    pub required: f32,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} on {} is {:.2}:1, needs {}:1", self.foreground, self.background, self.ratio, self.required)
+    }
+}
+
+/// Checks the pairings the UI draws: text on the background and on panels,
+/// muted text on the background, and buttons against the background
+/// (held to the large-text minimum, like other UI components).
+pub fn check(tokens: &ThemeTokens) -> Vec<Failure> {
+    let pairs = [
+        ("foreground", tokens.foreground, "background", tokens.background, false),
+        ("foreground", tokens.foreground, "surface", tokens.surface, false),
+        ("accent", tokens.accent, "background", tokens.background, false),
+        ("primary", tokens.primary, "background", tokens.background, true),
+    ];
+
+    pairs
+        .into_iter()
+        .filter(|&(_, fg, _, bg, large_text)| !meets_aa(fg, bg, large_text))
+        .map(|(foreground, fg, background, bg, large_text)| Failure {
+            foreground,
+            background,
+            ratio: contrast_ratio(fg, bg),
+            required: if large_text { AA_LARGE } else { AA_NORMAL },
+        })
+        .collect()
+}
+
+/// Logs a warning for every built-in theme pairing below AA. The palette
+/// is read by switching `Palette.theme` through each theme, so call this
+/// before the window is shown.
+pub fn validate_builtin_themes(app: &CrossPlatformApp) {
+    let palette = app.global::<Palette>();
+    let shown = palette.get_theme();
+
+    for &theme in THEMES {
+        palette.set_theme(theme.into());
+        for failure in check(&ThemeTokens::current(app)) {
+            tracing::warn!(theme, %failure, "theme fails WCAG AA contrast");
+        }
+    }
+
+    palette.set_theme(shown);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(rgb: u32) -> Color {
+        Color::from_rgb_u8((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+    }
+
+    fn assert_ratio(fg: u32, bg: u32, expected: f32) {
+        let ratio = contrast_ratio(hex(fg), hex(bg));
+        assert!((ratio - expected).abs() < 0.01, "#{fg:06x} on #{bg:06x} is {ratio}, expected {expected}");
+    }
+
+    #[test]
+    fn black_on_white_is_21_to_1() {
+        assert_ratio(0x000000, 0xffffff, 21.0);
+        assert_ratio(0xffffff, 0x000000, 21.0);
+    }
+
+    #[test]
+    fn identical_colors_are_1_to_1() {
+        for rgb in [0x000000, 0x777777, 0x3498db, 0xffffff] {
+            assert_ratio(rgb, rgb, 1.0);
+        }
+    }
+
+    // #767676 is the lightest gray that passes AA for body text on white,
+    // #949494 for large text
+    #[test]
+    fn aa_thresholds() {
+        let white = hex(0xffffff);
+        assert!(meets_aa(hex(0x767676), white, false));
+        assert!(!meets_aa(hex(0x777777), white, false));
+        assert!(meets_aa(hex(0x949494), white, true));
+        assert!(!meets_aa(hex(0x959595), white, true));
+    }
+
+    #[test]
+    fn a_readable_palette_passes() {
+        // The token file from the README
+        let tokens = ThemeTokens {
+            background: hex(0x1e1e2e),
+            surface: hex(0x313244),
+            foreground: hex(0xcdd6f4),
+            primary: hex(0x89b4fa),
+            accent: hex(0xf5c2e7),
+        };
+        assert_eq!(check(&tokens), []);
+    }
+
+    #[test]
+    fn a_failing_palette_names_each_pairing() {
+        let tokens = ThemeTokens {
+            background: hex(0xffffff),
+            surface: hex(0xf0f0f0),
+            foreground: hex(0x333333),
+            primary: hex(0xffff00),
+            accent: hex(0xaaaaaa),
+        };
+        let failures = check(&tokens);
+
+        let pairs: Vec<_> = failures.iter().map(|failure| (failure.foreground, failure.background, failure.required)).collect();
+        assert_eq!(pairs, [("accent", "background", AA_NORMAL), ("primary", "background", AA_LARGE)]);
+        assert_eq!(failures[0].to_string(), "accent on background is 2.32:1, needs 4.5:1");
+        assert_eq!(failures[1].to_string(), "primary on background is 1.07:1, needs 3:1");
+    }
+}
@@ -0,0 +1,88 @@
+//! Platform diagnostics as versioned JSON, e.g. for attaching to bug reports.
+
+use serde::{Deserialize, Serialize};
+
+use crate::platform::{self, PlatformInfo};
+
+/// Bumped whenever a field is renamed, removed or changes meaning, so tools
+/// reading older exports can tell them apart. Adding fields doesn't bump it.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Suggested file name for saved exports.
+pub const FILE_NAME: &str = "diagnostics.json";
+
+/// The exported document. Fields serialize in declaration order, so exports
+/// from different machines diff line by line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Diagnostics {
+    pub schema_version: u32,
+    pub platform: PlatformInfo,
+}
+
+impl Diagnostics {
+    pub fn collect() -> Self {
+        Self { schema_version: SCHEMA_VERSION, platform: platform::collect() }
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn from_json(raw: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Diagnostics {
+        let mut platform = slint_skills_core::MockPlatform::default().info;
+        platform.virtualization = Some("KVM".to_string());
+        Diagnostics { schema_version: SCHEMA_VERSION, platform }
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let diagnostics = sample();
+        let json = diagnostics.to_json().unwrap();
+        assert_eq!(Diagnostics::from_json(&json).unwrap(), diagnostics);
+    }
+
+    #[test]
+    fn fields_keep_their_declaration_order() {
+        let json = sample().to_json().unwrap();
+        let keys = ["\"schema_version\"", "\"platform\"", "\"os\"", "\"version\"", "\"backend\"", "\"features\"", "\"virtualization\""];
+        let positions: Vec<usize> = keys.iter().map(|key| json.find(key).unwrap()).collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]), "{json}");
+    }
+
+    // Exports written before `virtualization` existed still load
+    #[test]
+    fn reads_exports_without_newer_fields() {
+        let json = r#"{
+            "schema_version": 1,
+            "platform": {
+                "os": "Linux",
+                "version": "Ubuntu 22.04",
+                "backend": "X11 (femtovg)",
+                "cpu_cores": 8,
+                "total_memory": null,
+                "available_memory": null,
+                "locale": "en-US",
+                "timezone": "UTC",
+                "features": ["Basic UI"]
+            }
+        }"#;
+        let diagnostics = Diagnostics::from_json(json).unwrap();
+        assert_eq!(diagnostics.platform.os, "Linux");
+        assert_eq!(diagnostics.platform.virtualization, None);
+    }
+
+    #[test]
+    fn rejects_other_documents() {
+        assert!(Diagnostics::from_json("{}").is_err());
+        assert!(Diagnostics::from_json("not json").is_err());
+    }
+}
@@ -0,0 +1,53 @@
+//! Native file dialogs (a browser file picker on the web). Without the
+//! `file-dialogs` feature rfd isn't linked and no dialog ever opens.
+
+use std::path::PathBuf;
+
+/// Whether this build can show dialogs; the UI hides "Open File" otherwise.
+pub const AVAILABLE: bool = cfg!(feature = "file-dialogs");
+
+/// A file chosen by the user. Browsers never reveal paths, only contents.
+pub enum PickedFile {
+    Path(PathBuf),
+    Bytes { name: String, bytes: Vec<u8> },
+}
+
+/// Shows a blocking open dialog. Only for code that isn't on the UI thread;
+/// UI callbacks should use [`pick_file_async`].
+#[cfg(all(feature = "file-dialogs", not(target_arch = "wasm32")))]
+pub fn pick_file() -> Option<PathBuf> {
+    rfd::FileDialog::new().pick_file()
+}
+
+/// Shows an open dialog without blocking the event loop. Drive it with
+/// `slint::spawn_local`.
+#[cfg(feature = "file-dialogs")]
+pub async fn pick_file_async() -> Option<PickedFile> {
+    let handle = rfd::AsyncFileDialog::new().pick_file().await?;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    return Some(PickedFile::Path(handle.path().to_path_buf()));
+
+    #[cfg(target_arch = "wasm32")]
+    return Some(PickedFile::Bytes {
+        name: handle.file_name(),
+        bytes: handle.read().await,
+    });
+}
+
+#[cfg(not(feature = "file-dialogs"))]
+pub async fn pick_file_async() -> Option<PickedFile> {
+    None
+}
+
+/// Shows a save dialog without blocking the event loop. Browsers can't save
+/// to a chosen path, so this is desktop-only.
+#[cfg(all(feature = "file-dialogs", not(target_arch = "wasm32")))]
+pub async fn save_file_async(file_name: &str) -> Option<PathBuf> {
+    let handle = rfd::AsyncFileDialog::new()
+        .set_file_name(file_name)
+        .add_filter("JSON", &["json"])
+        .save_file()
+        .await?;
+    Some(handle.path().to_path_buf())
+}
@@ -0,0 +1,191 @@
+//! Files dragged from the OS and dropped onto the window.
+//!
+//! Desktop drops arrive as winit events, one per file; the web build listens
+//! for the page's DOM drag events. Either way `drag-over` is set while
+//! something is held over the window, and the items of one drop are
+//! delivered together.
+
+use std::cell::RefCell;
+#[cfg(not(target_arch = "wasm32"))]
+use std::rc::Rc;
+
+use crate::file_dialog::PickedFile;
+use crate::CrossPlatformApp;
+
+/// One entry of a drop.
+pub enum DroppedItem {
+    File(PickedFile),
+    /// Folders are reported by name but never read.
+    Directory(String),
+}
+
+/// Called with everything dropped at once.
+pub type OnDrop = fn(&CrossPlatformApp, Vec<DroppedItem>);
+
+/// Collects winit's per-file drop events for one window. Feed it from the
+/// window's event filter.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct DropTarget {
+    pending: Rc<RefCell<Vec<std::path::PathBuf>>>,
+    on_drop: OnDrop,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DropTarget {
+    pub fn new(on_drop: OnDrop) -> Self {
+        Self {
+            pending: Rc::default(),
+            on_drop,
+        }
+    }
+
+    pub fn handle_winit_event(&self, app: &CrossPlatformApp, event: &slint::winit_030::winit::event::WindowEvent) {
+        use slint::winit_030::winit::event::WindowEvent;
+        use slint::ComponentHandle;
+
+        match event {
+            WindowEvent::HoveredFile(_) => app.set_drag_over(true),
+            WindowEvent::HoveredFileCancelled => app.set_drag_over(false),
+            WindowEvent::DroppedFile(path) => {
+                app.set_drag_over(false);
+                let first = {
+                    let mut pending = self.pending.borrow_mut();
+                    pending.push(path.clone());
+                    pending.len() == 1
+                };
+                // The rest of the drop is already queued behind this event;
+                // deliver once they've all been collected.
+                if first {
+                    let pending = self.pending.clone();
+                    let on_drop = self.on_drop;
+                    let app_weak = app.as_weak();
+                    slint::Timer::single_shot(std::time::Duration::ZERO, move || {
+                        let paths = pending.take();
+                        if let Some(app) = app_weak.upgrade() {
+                            on_drop(&app, paths.into_iter().map(classify).collect());
+                        }
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn classify(path: std::path::PathBuf) -> DroppedItem {
+    if path.is_dir() {
+        DroppedItem::Directory(path.display().to_string())
+    } else {
+        DroppedItem::File(PickedFile::Path(path))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+thread_local! {
+    static LISTENERS: RefCell<Vec<(&'static str, wasm_bindgen::closure::Closure<dyn Fn(web_sys::DragEvent)>)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Listens for drops anywhere on the page. Files are read in full before
+/// `on_drop` runs. Replaces listeners installed for an earlier window.
+#[cfg(target_arch = "wasm32")]
+pub fn listen(app: &CrossPlatformApp, on_drop: OnDrop) {
+    use slint::ComponentHandle;
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    detach();
+
+    let app_weak = app.as_weak();
+    let highlight = move |on: bool| {
+        let app_weak = app_weak.clone();
+        move |event: web_sys::DragEvent| {
+            // Without this the browser opens the file in the tab
+            event.prevent_default();
+            if let Some(app) = app_weak.upgrade() {
+                app.set_drag_over(on);
+            }
+        }
+    };
+
+    let app_weak = app.as_weak();
+    let drop = move |event: web_sys::DragEvent| {
+        event.prevent_default();
+        let Some(app) = app_weak.upgrade() else {
+            return;
+        };
+        app.set_drag_over(false);
+
+        // The transfer is only readable during the event, so sort the
+        // entries now and read the files afterwards.
+        let entries = event.data_transfer().map(|transfer| dropped_entries(&transfer)).unwrap_or_default();
+        let app_weak = app.as_weak();
+        let _ = slint::spawn_local(async move {
+            let mut items = Vec::with_capacity(entries.len());
+            for entry in entries {
+                items.push(match entry {
+                    Ok(file) => DroppedItem::File(read_file(file).await),
+                    Err(name) => DroppedItem::Directory(name),
+                });
+            }
+            if let Some(app) = app_weak.upgrade() {
+                on_drop(&app, items);
+            }
+        });
+    };
+
+    let listeners: Vec<(&'static str, Closure<dyn Fn(web_sys::DragEvent)>)> = vec![
+        ("dragover", Closure::new(highlight(true))),
+        ("dragleave", Closure::new(highlight(false))),
+        ("drop", Closure::new(drop)),
+    ];
+    for (event, listener) in &listeners {
+        let _ = window.add_event_listener_with_callback(event, listener.as_ref().unchecked_ref());
+    }
+    LISTENERS.set(listeners);
+}
+
+/// Removes the drop listeners, if any are installed.
+#[cfg(target_arch = "wasm32")]
+pub fn detach() {
+    use wasm_bindgen::JsCast;
+
+    let listeners = LISTENERS.take();
+    if let Some(window) = web_sys::window() {
+        for (event, listener) in &listeners {
+            let _ = window.remove_event_listener_with_callback(event, listener.as_ref().unchecked_ref());
+        }
+    }
+}
+
+// Files as `Ok`, folders as `Err(name)`. Browsers only tell the two apart
+// through the (WebKit-prefixed, but universally supported) entry API.
+#[cfg(target_arch = "wasm32")]
+fn dropped_entries(transfer: &web_sys::DataTransfer) -> Vec<Result<web_sys::File, String>> {
+    let items = transfer.items();
+    (0..items.length())
+        .filter_map(|index| items.get(index))
+        .filter(|item| item.kind() == "file")
+        .filter_map(|item| {
+            let is_directory = item.webkit_get_as_entry().ok().flatten().is_some_and(|entry| entry.is_directory());
+            let file = item.get_as_file().ok().flatten()?;
+            Some(if is_directory { Err(file.name()) } else { Ok(file) })
+        })
+        .collect()
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn read_file(file: web_sys::File) -> PickedFile {
+    let bytes = match wasm_bindgen_futures::JsFuture::from(file.array_buffer()).await {
+        Ok(buffer) => js_sys::Uint8Array::new(&buffer).to_vec(),
+        Err(_) => {
+            tracing::warn!(name = %file.name(), "could not read dropped file");
+            Vec::new()
+        }
+    };
+    PickedFile::Bytes { name: file.name(), bytes }
+}
@@ -0,0 +1,49 @@
+//! Toggling a window between windowed and fullscreen.
+
+/// Per-window fullscreen state. Remembers the windowed geometry so leaving
+/// fullscreen lands exactly where the window was, even on platforms where
+/// the window manager forgets it.
+#[derive(Default)]
+pub struct Fullscreen {
+    #[cfg(not(target_arch = "wasm32"))]
+    restore: std::cell::Cell<Option<crate::geometry::WindowGeometry>>,
+}
+
+impl Fullscreen {
+    /// Flips `window` and returns whether it is now fullscreen.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn toggle(&self, window: &slint::Window) -> bool {
+        use crate::geometry::WindowGeometry;
+
+        if window.is_fullscreen() {
+            window.set_fullscreen(false);
+            if let Some(geometry) = self.restore.take() {
+                geometry.apply(window);
+            }
+            false
+        } else {
+            self.restore.set(Some(WindowGeometry::capture(window)));
+            window.set_fullscreen(true);
+            true
+        }
+    }
+
+    /// Uses the browser's Fullscreen API on the app canvas; the canvas keeps
+    /// its own size, so there is nothing to restore. Entering may still be
+    /// refused by the browser, e.g. without a user gesture.
+    #[cfg(target_arch = "wasm32")]
+    pub fn toggle(&self, _window: &slint::Window) -> bool {
+        let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+            return false;
+        };
+
+        if document.fullscreen_element().is_some() {
+            document.exit_fullscreen();
+            false
+        } else {
+            document
+                .get_element_by_id("canvas")
+                .is_some_and(|canvas| canvas.request_fullscreen().is_ok())
+        }
+    }
+}
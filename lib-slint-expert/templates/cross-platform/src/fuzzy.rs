@@ -0,0 +1,163 @@
+//! Fuzzy matching for filterable lists such as the command palette.
+//!
+//! A query matches a candidate when its characters appear in it in order,
+//! ignoring case ("rpi" matches "Refresh Platform Info"). Among matches,
+//! characters at the start of a word and runs of adjacent characters score
+//! higher, so abbreviations and prefixes rank first. Everything here is
+//! pure.
+
+/// Score for each matched character.
+const MATCH: i32 = 1;
+
+/// Extra for a match at the start of a word: the first character, one after
+/// a space or punctuation, or an uppercase letter after a lowercase one.
+const WORD_START: i32 = 8;
+
+/// Extra for a match right after the previous one.
+const CONSECUTIVE: i32 = 10;
+
+/// How well `query` matches `candidate` (higher is better), or `None` when
+/// it doesn't. An empty query matches everything with a score of 0.
+///
+/// Of all the ways the query could line up with the candidate, the best
+/// scoring one counts, so "tt" takes the two word starts in "Toggle Theme"
+/// rather than the first two "t"s.
+pub fn score(query: &str, candidate: &str) -> Option<i32> {
+    let query: Vec<char> = query.chars().map(fold_case).collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let original: Vec<char> = candidate.chars().collect();
+    let folded: Vec<char> = original.iter().copied().map(fold_case).collect();
+    let word_start: Vec<bool> = (0..original.len())
+        .map(|index| {
+            index == 0 || {
+                let (before, at) = (original[index - 1], original[index]);
+                !before.is_alphanumeric() || (before.is_lowercase() && at.is_uppercase())
+            }
+        })
+        .collect();
+
+    // best[j]: the best score with the query so far matched and its last
+    // character at candidate position j
+    let mut best: Vec<Option<i32>> = Vec::new();
+    for (position, &wanted) in query.iter().enumerate() {
+        let mut next = vec![None; folded.len()];
+        // Best score of the previous query character at least two positions
+        // back, i.e. with a gap before position j
+        let mut with_gap: Option<i32> = None;
+
+        for (j, &c) in folded.iter().enumerate() {
+            if j >= 2 {
+                with_gap = with_gap.max(best.get(j - 2).copied().flatten());
+            }
+            if c != wanted {
+                continue;
+            }
+
+            let here = MATCH + if word_start[j] { WORD_START } else { 0 };
+            next[j] = if position == 0 {
+                Some(here)
+            } else {
+                let adjacent = j.checked_sub(1).and_then(|previous| best[previous]).map(|score| score + CONSECUTIVE);
+                adjacent.max(with_gap).map(|score| score + here)
+            };
+        }
+        best = next;
+    }
+
+    best.into_iter().flatten().max()
+}
+
+/// The items matching `query`, best first. Equal scores put the shorter
+/// candidate first, then keep the order of `items`. An empty query returns
+/// every item in its original order.
+pub fn filter_and_rank<'a, T: AsRef<str>>(query: &str, items: &'a [T]) -> Vec<&'a T> {
+    let mut matches: Vec<(i32, &T)> = items
+        .iter()
+        .filter_map(|item| Some((score(query, item.as_ref())?, item)))
+        .collect();
+    if !query.is_empty() {
+        // Stable, so remaining ties keep their order
+        matches.sort_by_key(|(score, item)| (std::cmp::Reverse(*score), item.as_ref().chars().count()));
+    }
+    matches.into_iter().map(|(_, item)| item).collect()
+}
+
+// Single-character lowercase, so positions line up with the original text
+fn fold_case(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_empty_query_matches_everything_in_order() {
+        assert_eq!(score("", "anything"), Some(0));
+        assert_eq!(score("", ""), Some(0));
+        assert_eq!(filter_and_rank("", &["b", "a", "c"]), [&"b", &"a", &"c"]);
+    }
+
+    #[test]
+    fn characters_must_appear_in_order() {
+        assert_eq!(score("ba", "ab"), None);
+        assert_eq!(score("abc", "ab"), None);
+        assert_eq!(score("a", ""), None);
+        assert_eq!(score("rpi", "Refresh Platform Info"), Some(27));
+    }
+
+    #[test]
+    fn case_is_ignored() {
+        assert_eq!(score("TT", "Toggle Theme"), score("tt", "Toggle Theme"));
+        assert_eq!(score("tt", "TOGGLE THEME"), score("tt", "Toggle Theme"));
+    }
+
+    #[test]
+    fn non_ascii_case_is_folded() {
+        assert_eq!(score("éc", "École"), Some(20));
+        assert_eq!(score("ÉC", "école"), Some(20));
+        assert_eq!(score("σ", "Σύνοψη"), Some(9));
+        // Lowercases to two characters; only the first is kept so positions
+        // still line up with the candidate
+        assert_eq!(score("is", "İstanbul"), Some(20));
+    }
+
+    // "tt" takes both word starts rather than the first two "t"s
+    #[test]
+    fn the_best_alignment_counts() {
+        assert_eq!(score("tt", "Toggle Theme"), Some(18));
+        assert_eq!(score("tt", "test"), Some(10));
+    }
+
+    #[test]
+    fn word_starts_include_camel_case_humps() {
+        assert_eq!(score("fb", "fooBar"), Some(18));
+        assert_eq!(score("fb", "foobar"), Some(10));
+        assert_eq!(score("fb", "foo_bar"), Some(18));
+    }
+
+    #[test]
+    fn consecutive_matches_beat_word_starts() {
+        assert_eq!(filter_and_rank("ab", &["xab", "a b", "ab"]), [&"ab", &"a b", &"xab"]);
+        assert_eq!([score("ab", "ab"), score("ab", "a b"), score("ab", "xab")], [Some(20), Some(18), Some(12)]);
+    }
+
+    #[test]
+    fn ties_rank_the_shorter_candidate_first_then_keep_the_order() {
+        assert_eq!(filter_and_rank("ab", &["xaby", "xab"]), [&"xab", &"xaby"]);
+        assert_eq!(filter_and_rank("ab", &["cab", "dab"]), [&"cab", &"dab"]);
+        assert_eq!(filter_and_rank("ab", &["dab", "cab"]), [&"dab", &"cab"]);
+    }
+
+    #[test]
+    fn palette_commands() {
+        let commands = ["Toggle Theme", "Refresh Platform Info", "Test Features", "Export Diagnostics"];
+        assert_eq!(score("tf", "Test Features"), Some(18));
+        assert_eq!(score("tf", "Refresh Platform Info"), Some(12));
+        assert_eq!(filter_and_rank("tf", &commands), [&"Test Features", &"Refresh Platform Info"]);
+        assert!(filter_and_rank("xyz", &commands).is_empty());
+    }
+}
@@ -0,0 +1,233 @@
+//! Saves the window size and position on close and restores it on launch.
+//!
+//! Desktop only: browsers own the canvas size, so there is nothing to persist
+//! on the web.
+
+use serde::{Deserialize, Serialize};
+use slint::winit_030::WinitWindowAccessor;
+use slint::ComponentHandle;
+
+use crate::storage::Store;
+
+const STORAGE_KEY: &str = "window";
+
+// Smallest size we'll restore to, so a bad save can't produce a sliver.
+const MIN_WIDTH: u32 = 320;
+const MIN_HEIGHT: u32 = 240;
+
+/// Window geometry in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub width: u32,
+    pub height: u32,
+    /// `None` where the platform doesn't let apps read or set the position
+    /// (Wayland).
+    pub position: Option<(i32, i32)>,
+}
+
+/// The area of one connected monitor in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonitorBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl MonitorBounds {
+    fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && y >= self.y && x < self.x + self.width as i32 && y < self.y + self.height as i32
+    }
+}
+
+impl WindowGeometry {
+    pub fn load() -> Option<Self> {
+        serde_json::from_str(&Store::get(STORAGE_KEY)?).ok()
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let raw = serde_json::to_string_pretty(self)?;
+        Store::set(STORAGE_KEY, &raw)
+    }
+
+    pub fn capture(window: &slint::Window) -> Self {
+        let size = window.size();
+        let position = window.position();
+
+        Self {
+            width: size.width,
+            height: size.height,
+            position: position_supported().then_some((position.x, position.y)),
+        }
+    }
+
+    pub fn apply(&self, window: &slint::Window) {
+        window.set_size(slint::PhysicalSize::new(self.width, self.height));
+        if let Some((x, y)) = self.position {
+            window.set_position(slint::PhysicalPosition::new(x, y));
+        }
+    }
+
+    /// Fits the geometry onto one of `monitors`.
+    ///
+    /// The window stays on the monitor holding its top-left corner; if that
+    /// monitor is gone it moves to the first (primary) one. It is shrunk to
+    /// fit and shifted so no edge hangs off-screen. With no monitor info the
+    /// geometry is returned unchanged.
+    pub fn clamp_to(&self, monitors: &[MonitorBounds]) -> Self {
+        let Some(first) = monitors.first() else {
+            return *self;
+        };
+
+        let monitor = self
+            .position
+            .and_then(|(x, y)| monitors.iter().find(|monitor| monitor.contains(x, y)))
+            .unwrap_or(first);
+
+        let width = self.width.clamp(MIN_WIDTH.min(monitor.width), monitor.width);
+        let height = self.height.clamp(MIN_HEIGHT.min(monitor.height), monitor.height);
+
+        let position = self.position.map(|(x, y)| {
+            let max_x = monitor.x + (monitor.width - width) as i32;
+            let max_y = monitor.y + (monitor.height - height) as i32;
+            (x.clamp(monitor.x, max_x), y.clamp(monitor.y, max_y))
+        });
+
+        Self {
+            width,
+            height,
+            position,
+        }
+    }
+}
+
+fn position_supported() -> bool {
+    #[cfg(target_os = "linux")]
+    return crate::platform::detect_linux_session() != "Wayland";
+
+    #[cfg(not(target_os = "linux"))]
+    return true;
+}
+
+fn connected_monitors(window: &slint::Window) -> Vec<MonitorBounds> {
+    window
+        .with_winit_window(|winit_window| {
+            winit_window
+                .available_monitors()
+                .map(|monitor| MonitorBounds {
+                    x: monitor.position().x,
+                    y: monitor.position().y,
+                    width: monitor.size().width,
+                    height: monitor.size().height,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Restores the saved geometry and saves it again when the window closes.
+pub fn track<C: ComponentHandle + 'static>(component: &C) {
+    if let Some(saved) = WindowGeometry::load() {
+        saved.apply(component.window());
+
+        // Monitors can only be enumerated once the native window exists, so
+        // clamp on the first event loop iteration, before anything is drawn.
+        let weak = component.as_weak();
+        slint::Timer::single_shot(std::time::Duration::ZERO, move || {
+            if let Some(component) = weak.upgrade() {
+                let window = component.window();
+                let clamped = saved.clamp_to(&connected_monitors(window));
+                if clamped != saved {
+                    clamped.apply(window);
+                }
+            }
+        });
+    }
+
+    let weak = component.as_weak();
+    component.window().on_close_requested(move || {
+        if let Some(component) = weak.upgrade() {
+            // Losing the geometry isn't worth blocking the close over.
+            let _ = WindowGeometry::capture(component.window()).save();
+        }
+        slint::CloseRequestResponse::HideWindow
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIMARY: MonitorBounds = MonitorBounds {
+        x: 0,
+        y: 0,
+        width: 1920,
+        height: 1080,
+    };
+
+    // A second screen to the left of the primary one
+    const LEFT: MonitorBounds = MonitorBounds {
+        x: -2560,
+        y: 0,
+        width: 2560,
+        height: 1440,
+    };
+
+    fn geometry(width: u32, height: u32, position: Option<(i32, i32)>) -> WindowGeometry {
+        WindowGeometry { width, height, position }
+    }
+
+    #[test]
+    fn fitting_geometry_is_unchanged() {
+        let saved = geometry(800, 600, Some((100, 100)));
+        assert_eq!(saved.clamp_to(&[PRIMARY]), saved);
+        assert_eq!(geometry(800, 600, Some((-1200, 300))).clamp_to(&[PRIMARY, LEFT]), geometry(800, 600, Some((-1200, 300))));
+    }
+
+    #[test]
+    fn window_on_a_disconnected_monitor_moves_to_the_primary() {
+        let saved = geometry(800, 600, Some((-1200, 300)));
+        assert_eq!(saved.clamp_to(&[PRIMARY]), geometry(800, 600, Some((0, 300))));
+    }
+
+    #[test]
+    fn window_hanging_off_the_edge_is_pulled_back() {
+        let saved = geometry(800, 600, Some((1800, 900)));
+        assert_eq!(saved.clamp_to(&[PRIMARY]), geometry(800, 600, Some((1120, 480))));
+    }
+
+    #[test]
+    fn oversized_window_is_shrunk_to_the_monitor() {
+        let saved = geometry(4000, 3000, Some((10, 10)));
+        assert_eq!(saved.clamp_to(&[PRIMARY]), geometry(1920, 1080, Some((0, 0))));
+    }
+
+    #[test]
+    fn tiny_window_grows_to_the_minimum() {
+        let saved = geometry(10, 10, None);
+        assert_eq!(saved.clamp_to(&[PRIMARY]), geometry(MIN_WIDTH, MIN_HEIGHT, None));
+    }
+
+    // A monitor smaller than the minimum size still fits the window
+    #[test]
+    fn minimum_never_exceeds_the_monitor() {
+        let small = MonitorBounds {
+            x: 0,
+            y: 0,
+            width: 240,
+            height: 160,
+        };
+        assert_eq!(geometry(10, 10, Some((0, 0))).clamp_to(&[small]), geometry(240, 160, Some((0, 0))));
+    }
+
+    #[test]
+    fn size_only_geometry_keeps_no_position() {
+        assert_eq!(geometry(4000, 600, None).clamp_to(&[PRIMARY]), geometry(1920, 600, None));
+    }
+
+    #[test]
+    fn no_monitor_info_leaves_geometry_alone() {
+        let saved = geometry(4000, 3000, Some((-5000, -5000)));
+        assert_eq!(saved.clamp_to(&[]), saved);
+    }
+}
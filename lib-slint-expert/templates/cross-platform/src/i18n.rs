@@ -0,0 +1,15 @@
+//! Runtime language switching over the catalogs bundled from `translations/`.
+
+/// Switches every `@tr` string in the UI to `language`.
+///
+/// Strings that Rust has already pushed into properties keep their old text;
+/// callers re-render those afterwards.
+pub fn set_language(language: &str) -> Result<(), slint::SelectBundledTranslationError> {
+    slint::select_bundled_translation(language)
+}
+
+/// The catalog language for a locale such as "zh-CN", i.e. its language
+/// part ("zh").
+pub fn language_of(locale: &str) -> &str {
+    locale.split(['-', '_']).next().unwrap_or(locale)
+}
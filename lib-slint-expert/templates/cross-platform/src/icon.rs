@@ -0,0 +1,37 @@
+//! The application icon from `assets/icon.png` (`app-icon` feature).
+//!
+//! build.rs embeds the PNG into `main.slint` through `@image-url`, where it
+//! becomes the window and taskbar icon without any decoding at runtime. This
+//! module hands the same image to what lives outside the Slint window: the
+//! tray and the browser tab.
+
+#[cfg(not(target_arch = "wasm32"))]
+use slint::ComponentHandle;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{AppIcon, CrossPlatformApp};
+
+/// The icon as RGBA8 pixels plus width and height, for the tray.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn rgba(app: &CrossPlatformApp) -> Option<(Vec<u8>, u32, u32)> {
+    let pixels = app.global::<AppIcon>().get_image().to_rgba8()?;
+    Some((pixels.as_bytes().to_vec(), pixels.width(), pixels.height()))
+}
+
+/// Adds the favicon link to the page. It points at `assets/icon.png`, which
+/// is deployed next to index.html; builds without the feature add no link,
+/// so the browser doesn't request a file that isn't there.
+#[cfg(target_arch = "wasm32")]
+pub fn add_favicon() {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let (Ok(link), Ok(Some(head))) = (document.create_element("link"), document.query_selector("head")) else {
+        return;
+    };
+
+    let _ = link.set_attribute("rel", "icon");
+    let _ = link.set_attribute("type", "image/png");
+    let _ = link.set_attribute("href", "assets/icon.png");
+    let _ = head.append_child(&link);
+}
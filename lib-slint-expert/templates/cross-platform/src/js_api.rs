@@ -0,0 +1,55 @@
+//! Functions the host page can call from JavaScript, for embedding the app
+//! in a larger web page.
+//!
+//! They act on the running app, which exists once the module's start
+//! function (`main`) has created the window. Until then, and after the app is
+//! gone, every call throws a "not running" error instead of panicking, so
+//! call them after `await init()` resolves.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use slint::ComponentHandle;
+use wasm_bindgen::prelude::*;
+
+use crate::{platform, wasm_router, CrossPlatformApp};
+
+thread_local! {
+    static APP: RefCell<Option<Rc<slint::Weak<CrossPlatformApp>>>> = const { RefCell::new(None) };
+}
+
+/// Makes `app` the target of the exported functions.
+pub fn register(app: &CrossPlatformApp) {
+    APP.set(Some(Rc::new(app.as_weak())));
+}
+
+fn running_app() -> Result<CrossPlatformApp, JsError> {
+    APP.with_borrow(|app| app.as_ref().and_then(|weak| weak.upgrade()))
+        .ok_or_else(|| JsError::new("the app is not running yet"))
+}
+
+/// Switches to a theme mode such as "dark" or "system".
+#[wasm_bindgen]
+pub fn set_theme(theme: &str) -> Result<(), JsError> {
+    let app = running_app()?;
+    if !crate::set_theme_by_name(&app, theme) {
+        return Err(JsError::new(&format!("unknown theme {theme:?}")));
+    }
+    wasm_router::write_route(&crate::current_route(&app));
+    Ok(())
+}
+
+/// Cycles to the next theme, like the Toggle Theme button.
+#[wasm_bindgen]
+pub fn toggle_theme() -> Result<(), JsError> {
+    running_app()?.invoke_toggle_theme();
+    Ok(())
+}
+
+/// The detected platform details as JSON, in the same shape as the
+/// `platform` section of an exported diagnostics file.
+#[wasm_bindgen]
+pub fn get_platform_info_json() -> Result<String, JsError> {
+    running_app()?;
+    serde_json::to_string(&platform::collect()).map_err(|err| JsError::new(&err.to_string()))
+}
@@ -0,0 +1,276 @@
+//! Debug-only live reload: runs `src/ui/main.slint` through the Slint
+//! interpreter and swaps in a fresh window whenever a `.slint` file under
+//! `src/ui` is saved.
+//!
+//! Only compiled with the `live-reload` feature in debug builds; release
+//! builds always use the UI compiled by `build.rs` and never watch files.
+//!
+//! The interpreted window has no generated Rust API, so the handlers in
+//! `main.rs` can't be attached to it. Only the callbacks wired in
+//! [`wire_callbacks`] work after a (re)load; the rest are no-ops. Public
+//! property values are carried over from the old window to the new one.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use notify::{EventKind, RecursiveMode, Watcher};
+use slint::ComponentHandle;
+use slint_interpreter::{Compiler, ComponentDefinition, ComponentInstance, DiagnosticLevel, Value};
+
+use crate::{i18n, platform, renderer, theme, AppOptions};
+
+/// Set by `build.rs` for debug builds with the feature enabled.
+const UI_PATH: &str = match option_env!("SLINT_UI_PATH") {
+    Some(path) => path,
+    None => concat!(env!("CARGO_MANIFEST_DIR"), "/src/ui/main.slint"),
+};
+
+const COMPONENT: &str = "CrossPlatformApp";
+
+thread_local! {
+    static CURRENT: RefCell<Option<(ComponentDefinition, ComponentInstance)>> = const { RefCell::new(None) };
+}
+
+/// Alternative to `run_app` that interprets the UI and reloads it on change.
+/// `options` apply to the first window; reloads carry its state over.
+pub fn run_with_reload(options: AppOptions) -> Result<(), slint::PlatformError> {
+    // Headless runs and screenshots render once and exit, so there is
+    // nothing to reload; they use the compiled UI
+    if options.headless || options.screenshot.is_some() {
+        return crate::run_app(options);
+    }
+
+    let ui_path = Path::new(UI_PATH);
+    tracing::info!(path = %ui_path.display(), "live reload enabled");
+
+    if !options.renderer.as_deref().is_some_and(renderer::select) {
+        renderer::select_default();
+    }
+
+    if !reload() {
+        return Err(slint::PlatformError::Other(format!("{} failed to compile", ui_path.display())));
+    }
+    CURRENT.with_borrow(|current| {
+        if let Some((_, instance)) = current {
+            apply_options(instance, &options);
+        }
+    });
+
+    // Watch the directory rather than the file: many editors save by
+    // replacing the file, which would drop a watch on the file itself
+    let mut watcher = notify::recommended_watcher(|event: notify::Result<notify::Event>| {
+        let Ok(event) = event else { return };
+        let touches_slint = event.paths.iter().any(|path| path.extension().is_some_and(|ext| ext == "slint"));
+        if touches_slint && matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+            let _ = slint::invoke_from_event_loop(|| {
+                reload();
+            });
+        }
+    })
+    .map_err(|err| slint::PlatformError::Other(err.to_string()))?;
+
+    let ui_dir = ui_path.parent().unwrap_or(ui_path);
+    watcher
+        .watch(ui_dir, RecursiveMode::NonRecursive)
+        .map_err(|err| slint::PlatformError::Other(err.to_string()))?;
+
+    // The watcher must outlive the event loop
+    let result = slint::run_event_loop();
+    drop(watcher);
+    result
+}
+
+// Recompiles the UI and replaces the current window. On a compile error the
+// old window stays up and the diagnostics are logged. Returns whether a new
+// window is showing.
+fn reload() -> bool {
+    let Some(definition) = compile() else {
+        return false;
+    };
+    let instance = match definition.create() {
+        Ok(instance) => instance,
+        Err(err) => {
+            tracing::error!(%err, "could not create the reloaded window");
+            return false;
+        }
+    };
+
+    wire_callbacks(&instance);
+
+    let previous = CURRENT.take();
+    if let Some((old_definition, old_instance)) = &previous {
+        carry_over_state(old_definition, old_instance, &instance);
+    }
+
+    if let Err(err) = instance.show() {
+        tracing::error!(%err, "could not show the reloaded window");
+        CURRENT.set(previous);
+        return false;
+    }
+    if let Some((_, old_instance)) = previous {
+        let _ = old_instance.hide();
+    }
+
+    tracing::info!("UI reloaded");
+    CURRENT.set(Some((definition, instance)));
+    true
+}
+
+// Interprets the UI with the library paths build.rs uses, logging the
+// diagnostics. `None` when it doesn't compile.
+fn compile() -> Option<ComponentDefinition> {
+    let mut compiler = Compiler::default();
+    compiler.set_library_paths(HashMap::from([
+        ("fonts".to_string(), fonts_library()),
+        ("icon".to_string(), icon_library()),
+    ]));
+    let result = spin_on::spin_on(compiler.build_from_path(UI_PATH));
+
+    for diagnostic in result.diagnostics() {
+        match diagnostic.level() {
+            DiagnosticLevel::Error => tracing::error!("{diagnostic}"),
+            _ => tracing::warn!("{diagnostic}"),
+        }
+    }
+
+    result.component(COMPONENT)
+}
+
+// The startup options `run_app` applies that make sense for the interpreted
+// window: theme, language and size.
+fn apply_options(instance: &ComponentInstance, options: &AppOptions) {
+    if let Some(name) = &options.theme {
+        let system_unavailable = name == theme::SYSTEM_MODE && !theme::system_theme_supported();
+        if theme::THEME_MODES.contains(&name.as_str()) && !system_unavailable {
+            let _ = instance.set_property("theme-mode", Value::String(name.as_str().into()));
+            let _ = instance.set_property("current-theme", Value::String(theme::resolve_theme(name).into()));
+        } else {
+            tracing::warn!(theme = %name, "unknown or unsupported theme");
+        }
+    }
+
+    if let Some(language) = &options.language {
+        if i18n::set_language(language).is_ok() {
+            let _ = instance.set_property("language", Value::String(language.as_str().into()));
+        } else {
+            tracing::warn!(%language, "no translation for the configured language");
+        }
+    }
+
+    if options.width.is_some() || options.height.is_some() {
+        let window = instance.window();
+        let current = window.size().to_logical(window.scale_factor());
+        let width = options.width.map_or(current.width, |width| width as f32);
+        let height = options.height.map_or(current.height, |height| height as f32);
+        window.set_size(slint::LogicalSize::new(width, height));
+    }
+}
+
+// Mirrors the `@fonts` mapping in build.rs.
+fn fonts_library() -> PathBuf {
+    let ui_dir = Path::new(UI_PATH).parent().unwrap_or(Path::new("."));
+    if cfg!(feature = "bundled-fonts") {
+        ui_dir.join("../../assets/fonts/fonts.slint")
+    } else {
+        ui_dir.join("system-fonts.slint")
+    }
+}
+
+// Mirrors the `@icon` mapping in build.rs, which generates the file with the
+// feature on.
+fn icon_library() -> PathBuf {
+    if cfg!(feature = "app-icon") {
+        PathBuf::from(concat!(env!("OUT_DIR"), "/icon.slint"))
+    } else {
+        Path::new(UI_PATH).parent().unwrap_or(Path::new(".")).join("no-icon.slint")
+    }
+}
+
+// Copies public properties and the window geometry so a reload doesn't reset
+// what's on screen. Properties that were renamed or retyped are skipped.
+fn carry_over_state(old_definition: &ComponentDefinition, old: &ComponentInstance, new: &ComponentInstance) {
+    for (name, _) in old_definition.properties() {
+        if let Ok(value) = old.get_property(&name) {
+            let _ = new.set_property(&name, value);
+        }
+    }
+
+    new.window().set_size(old.window().size());
+    new.window().set_position(old.window().position());
+}
+
+// The subset of the app's callbacks that work in live-reload mode.
+fn wire_callbacks(instance: &ComponentInstance) {
+    let weak = instance.as_weak();
+    let _ = instance.set_callback("toggle-theme", move |_| {
+        if let Some(instance) = weak.upgrade() {
+            let current = match instance.get_property("theme-mode") {
+                Ok(Value::String(mode)) => mode.to_string(),
+                _ => theme::DEFAULT_THEME.to_string(),
+            };
+            let next = theme::next_mode(&current, theme::system_theme_supported());
+            let _ = instance.set_property("theme-mode", Value::String(next.into()));
+            let _ = instance.set_property("current-theme", Value::String(theme::resolve_theme(next).into()));
+        }
+        Value::Void
+    });
+
+    for name in ["show-platform-info", "refresh-platform-info"] {
+        let weak = instance.as_weak();
+        let _ = instance.set_callback(name, move |_| {
+            if let Some(instance) = weak.upgrade() {
+                // The translated summary lives in `Messages`, which needs the
+                // full set of arguments `main.rs` builds; a plain one will do
+                let info = platform::collect();
+                let summary = format!("{} ({})\n{}", info.os_label(), info.backend, info.locale);
+                let _ = instance.set_property("platform-info", Value::String(summary.into()));
+            }
+            Value::Void
+        });
+    }
+
+    // Let keys fall through to the focused widget
+    let _ = instance.set_callback("key-pressed", |_| Value::Bool(false));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The interpreter resolves imports itself; this catches a library path
+    // that build.rs maps but this module doesn't
+    #[test]
+    fn ui_compiles_in_the_interpreter() {
+        let definition = compile().expect("src/ui/main.slint should compile");
+        assert!(definition.properties().any(|(name, _)| name == "theme-mode"));
+    }
+
+    #[test]
+    fn startup_options_apply_to_the_interpreted_window() {
+        i_slint_backend_testing::init_no_event_loop();
+        let instance = compile().unwrap().create().unwrap();
+        let options = AppOptions {
+            theme: Some("dark".to_string()),
+            width: Some(800),
+            height: Some(600),
+            ..AppOptions::default()
+        };
+
+        apply_options(&instance, &options);
+        assert_eq!(instance.get_property("theme-mode").unwrap(), Value::String("dark".into()));
+        assert_eq!(instance.get_property("current-theme").unwrap(), Value::String("dark".into()));
+        let size = instance.window().size().to_logical(instance.window().scale_factor());
+        assert_eq!((size.width, size.height), (800.0, 600.0));
+    }
+
+    #[test]
+    fn unknown_themes_are_ignored() {
+        i_slint_backend_testing::init_no_event_loop();
+        let instance = compile().unwrap().create().unwrap();
+        let before = instance.get_property("theme-mode").unwrap();
+
+        apply_options(&instance, &AppOptions { theme: Some("neon".to_string()), ..AppOptions::default() });
+        assert_eq!(instance.get_property("theme-mode").unwrap(), before);
+    }
+}
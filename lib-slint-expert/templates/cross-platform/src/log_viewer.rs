@@ -0,0 +1,212 @@
+//! A `tracing` layer feeding the in-app log panel.
+//!
+//! Events can come from any thread, so the layer only formats the record and
+//! appends it to a small locked queue; the UI thread drains that queue into a
+//! `VecModel` on its next event loop iteration. Both are capped at
+//! [`CAPACITY`] entries and drop the oldest ones first.
+
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use slint::{Model, ModelRc, VecModel};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::LogEntry;
+
+/// Most log lines kept for display.
+pub const CAPACITY: usize = 500;
+
+// Records waiting for the UI thread, and whether a drain is already queued.
+static PENDING: Mutex<VecDeque<Record>> = Mutex::new(VecDeque::new());
+static DRAIN_QUEUED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static ENTRIES: Rc<VecModel<LogEntry>> = Rc::new(VecModel::default());
+}
+
+// Thread-safe copy of an event; `LogEntry` holds Slint strings.
+struct Record {
+    level: tracing::Level,
+    time: String,
+    target: String,
+    message: String,
+}
+
+/// The layer to register next to the regular output.
+pub fn layer() -> LogViewerLayer {
+    LogViewerLayer
+}
+
+/// Every log line captured so far, oldest first. Only valid on the UI thread.
+pub fn entries() -> ModelRc<LogEntry> {
+    ENTRIES.with(|entries| ModelRc::from(entries.clone()))
+}
+
+/// Whether `entry` should show for the filter text `filter`; matching is
+/// case-insensitive over level, target and message.
+pub fn matches(entry: &LogEntry, filter: &str) -> bool {
+    if filter.is_empty() {
+        return true;
+    }
+    let filter = filter.to_lowercase();
+    [&entry.level, &entry.target, &entry.message]
+        .iter()
+        .any(|text| text.to_lowercase().contains(&filter))
+}
+
+pub struct LogViewerLayer;
+
+impl<S: Subscriber> Layer<S> for LogViewerLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let record = Record {
+            level: *metadata.level(),
+            time: chrono::Local::now().format("%H:%M:%S").to_string(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+        };
+
+        // A poisoned lock only means another thread panicked mid-push; the
+        // queue itself is still usable.
+        let mut pending = PENDING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if pending.len() == CAPACITY {
+            pending.pop_front();
+        }
+        pending.push_back(record);
+        drop(pending);
+
+        // Before the first window exists there's no event loop to post to;
+        // the records then wait for the next event after it's up.
+        if !DRAIN_QUEUED.swap(true, Ordering::AcqRel) && slint::invoke_from_event_loop(drain).is_err() {
+            DRAIN_QUEUED.store(false, Ordering::Release);
+        }
+    }
+}
+
+fn drain() {
+    DRAIN_QUEUED.store(false, Ordering::Release);
+    let records: Vec<Record> = {
+        let mut pending = PENDING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        pending.drain(..).collect()
+    };
+
+    ENTRIES.with(|entries| {
+        for record in records {
+            entries.push(LogEntry {
+                level: record.level.as_str().into(),
+                time: record.time.into(),
+                target: record.target.into(),
+                message: record.message.into(),
+            });
+        }
+        let overflow = entries.row_count().saturating_sub(CAPACITY);
+        for _ in 0..overflow {
+            entries.remove(0);
+        }
+    });
+}
+
+// Formats the `message` field, then any other fields as `key=value`.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let fields = std::mem::take(&mut self.message);
+            let _ = write!(self.message, "{:?}{}", value, fields);
+        } else {
+            let _ = write!(self.message, " {}={:?}", field.name(), value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.record_debug(field, &format_args!("{}", value));
+        } else {
+            let _ = write!(self.message, " {}={}", field.name(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+
+    fn entry(level: &str, target: &str, message: &str) -> LogEntry {
+        LogEntry { level: level.into(), time: "12:00:00".into(), target: target.into(), message: message.into() }
+    }
+
+    #[test]
+    fn filter_matches_level_target_and_message() {
+        let entry = entry("WARN", "slint_cross_platform::storage", "could not save the theme");
+
+        assert!(matches(&entry, ""));
+        assert!(matches(&entry, "warn"));
+        assert!(matches(&entry, "STORAGE"));
+        assert!(matches(&entry, "Save The"));
+        assert!(!matches(&entry, "error"));
+        // Fields are matched one by one, not across their boundaries
+        assert!(!matches(&entry, "storage could"));
+    }
+
+    // The only test going through the shared queue, so nothing else drains it
+    #[test]
+    fn layer_formats_records_and_keeps_the_newest() {
+        let subscriber = tracing_subscriber::registry().with(layer());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(path = "/tmp/prefs.json", attempt = 2, "could not save");
+            for line in 0..CAPACITY {
+                tracing::info!("line {line}");
+            }
+        });
+        // No event loop runs in tests; drain by hand as the UI thread would
+        drain();
+
+        let entries = entries();
+        assert_eq!(entries.row_count(), CAPACITY);
+
+        // The warning was the oldest record and got dropped
+        let first = entries.row_data(0).unwrap();
+        assert_eq!(first.level, "INFO");
+        assert_eq!(first.message, "line 0");
+        assert_eq!(first.target, module_path!());
+        assert_eq!(entries.row_data(CAPACITY - 1).unwrap().message, format!("line {}", CAPACITY - 1));
+    }
+
+    #[test]
+    fn message_comes_before_the_other_fields() {
+        let layer = FieldsOnly::default();
+        let formatted = layer.formatted.clone();
+        tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+            tracing::warn!(path = "/tmp/prefs.json", attempt = 2, "could not save");
+        });
+        assert_eq!(*formatted.lock().unwrap(), ["could not save path=/tmp/prefs.json attempt=2"]);
+    }
+
+    // Records what `MessageVisitor` makes of each event, without the queue
+    #[derive(Default)]
+    struct FieldsOnly {
+        formatted: std::sync::Arc<Mutex<Vec<String>>>,
+    }
+
+    impl<S: Subscriber> Layer<S> for FieldsOnly {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let mut visitor = MessageVisitor::default();
+            event.record(&mut visitor);
+            self.formatted.lock().unwrap().push(visitor.message);
+        }
+    }
+}
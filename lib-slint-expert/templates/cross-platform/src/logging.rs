@@ -0,0 +1,60 @@
+//! `tracing` setup: stderr on desktop, the browser console on the web, plus
+//! the in-app log panel on both.
+
+use std::sync::Once;
+
+/// Levels accepted by `--log-level`.
+pub const LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+/// Used when neither `--log-level` nor `RUST_LOG` says otherwise.
+pub const DEFAULT_LEVEL: &str = "info";
+
+static INIT: Once = Once::new();
+
+/// Installs the global subscriber. `level` (e.g. from `--log-level`) wins
+/// over `RUST_LOG`, which wins over [`DEFAULT_LEVEL`]; `RUST_LOG` may also
+/// hold per-target directives such as `slint_cross_platform=debug`.
+///
+/// Each entry point calls it once, before anything logs; later calls have
+/// no effect.
+pub fn init_logging(level: Option<&str>) {
+    INIT.call_once(|| install(level));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn install(level: Option<&str>) {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+    use tracing_subscriber::EnvFilter;
+
+    let filter = match level {
+        Some(level) => EnvFilter::new(level),
+        None => EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_LEVEL)),
+    };
+
+    // Another subscriber may already be set by an embedding host or test;
+    // theirs wins.
+    let _ = tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+        .with(crate::log_viewer::layer())
+        .try_init();
+}
+
+// There is no environment in the browser, so only `level` applies.
+#[cfg(target_arch = "wasm32")]
+fn install(level: Option<&str>) {
+    use tracing_subscriber::filter::LevelFilter;
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let level = level
+        .and_then(|level| level.parse().ok())
+        .unwrap_or(tracing::Level::INFO);
+
+    let _ = tracing_subscriber::registry()
+        .with(LevelFilter::from_level(level))
+        .with(tracing_wasm::WASMLayer::new(tracing_wasm::WASMLayerConfig::default()))
+        .with(crate::log_viewer::layer())
+        .try_init();
+}
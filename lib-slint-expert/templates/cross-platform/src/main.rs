@@ -1,3 +1,65 @@
+use std::cell::RefCell;
+
+use slint::ComponentHandle;
+
+#[cfg(all(feature = "async-runtime", not(target_arch = "wasm32")))]
+mod async_runtime;
+mod build_info;
+#[cfg(not(target_arch = "wasm32"))]
+mod cli;
+mod clipboard;
+mod command_palette;
+#[cfg(not(target_arch = "wasm32"))]
+mod config;
+mod connectivity;
+mod contrast;
+mod diagnostics;
+mod file_dialog;
+mod file_drop;
+mod fullscreen;
+mod fuzzy;
+#[cfg(not(target_arch = "wasm32"))]
+mod geometry;
+mod i18n;
+#[cfg(target_arch = "wasm32")]
+mod js_api;
+#[cfg(feature = "app-icon")]
+mod icon;
+#[cfg(all(feature = "live-reload", debug_assertions, not(target_arch = "wasm32")))]
+mod live_reload;
+mod log_viewer;
+mod logging;
+mod motion;
+mod notification;
+#[cfg(target_arch = "wasm32")]
+mod panic_overlay;
+mod platform;
+mod registry;
+#[cfg(not(target_arch = "wasm32"))]
+mod renderer;
+#[cfg(all(feature = "async-runtime", not(target_arch = "wasm32")))]
+mod repo_info;
+mod scale;
+#[cfg(not(target_arch = "wasm32"))]
+mod screenshot;
+mod shortcuts;
+mod startup_error;
+mod storage;
+mod theme;
+mod theme_tokens;
+#[cfg(not(target_arch = "wasm32"))]
+mod tray;
+#[cfg(target_arch = "wasm32")]
+mod viewport;
+#[cfg(target_arch = "wasm32")]
+mod wasm_router;
+#[cfg(not(target_arch = "wasm32"))]
+mod window_controls;
+
+use theme::ThemePrefs;
+
+slint::include_modules!();
+
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
@@ -5,158 +67,1638 @@ use wasm_bindgen::prelude::*;
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen(start)]
 pub fn main() {
-    console_error_panic_hook::set_once();
-    run_app()
+    logging::init_logging(None);
+
+    // Panics go to the console and to an overlay in the page
+    panic_overlay::install();
+
+    // Without WebGL, draw with Slint's software renderer instead; the
+    // platform info reports which renderer is in use
+    if platform::detect_web_renderer() == platform::WebRenderer::Software {
+        if let Err(err) = slint::BackendSelector::new().renderer_name("software".into()).select() {
+            startup_error::report(&err);
+            return;
+        }
+    }
+
+    if let Err(err) = run_app(AppOptions::default()) {
+        startup_error::report(&err);
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn main() -> Result<(), slint::PlatformError> {
-    run_app()
+fn main() -> std::process::ExitCode {
+    // Flags win over RUST_LOG and SLINT_BACKEND, which win over config.toml,
+    // which wins over the built-in behaviour. The config may set the log
+    // level, so it is read before logging starts and a broken file is
+    // reported once it has.
+    let config = config::Config::load();
+    let options = config::merge(cli::parse(), &config::Env::read(), config.clone().unwrap_or_default());
+    logging::init_logging(options.log_level.as_deref());
+    if let Err(err) = &config {
+        tracing::warn!(%err, "ignoring {}", config::FILE_NAME);
+    }
+
+    // Debug builds with `live-reload` interpret the .slint sources instead
+    // of using the compiled UI, reloading them on save
+    #[cfg(all(feature = "live-reload", debug_assertions))]
+    let result = live_reload::run_with_reload(options);
+    #[cfg(not(all(feature = "live-reload", debug_assertions)))]
+    let result = run_app(options);
+
+    match result {
+        Ok(()) => std::process::ExitCode::SUCCESS,
+        Err(err) => {
+            startup_error::report(&err);
+            std::process::ExitCode::FAILURE
+        }
+    }
+}
+
+/// Startup overrides, e.g. from the command line.
+#[derive(Debug, Clone, Default)]
+pub struct AppOptions {
+    pub theme: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// Catalog language such as "zh"; `None` follows the system locale.
+    pub language: Option<String>,
+    /// Renderer such as "skia" (desktop only); `None` lets Slint choose.
+    pub renderer: Option<String>,
+    /// Run the event handlers once, print their results and exit.
+    pub headless: bool,
+    /// Render the window to this PNG and exit (desktop only).
+    pub screenshot: Option<std::path::PathBuf>,
+    /// Log level such as "debug"; `None` defers to `RUST_LOG`.
+    pub log_level: Option<String>,
+}
+
+fn run_app(options: AppOptions) -> Result<(), slint::PlatformError> {
+    run_app_with(options, CrossPlatformApp::new)
 }
 
-fn run_app() -> Result<(), slint::PlatformError> {
+// `new_window` is the window constructor, passed in so a failing one can be
+// substituted when exercising the error path.
+fn run_app_with(
+    options: AppOptions,
+    new_window: impl FnOnce() -> Result<CrossPlatformApp, slint::PlatformError>,
+) -> Result<(), slint::PlatformError> {
+    tracing::info!(?options, "starting");
+
+    // The renderer has to be picked before the first window exists.
+    // Headless runs and screenshots use the software renderer, without a
+    // display, so they work in CI.
+    #[cfg(not(target_arch = "wasm32"))]
+    if options.headless || options.screenshot.is_some() {
+        screenshot::use_headless_platform()?;
+    } else if !options.renderer.as_deref().is_some_and(renderer::select) {
+        renderer::select_default();
+    }
+
     // Initialize the main window
-    let main_window = CrossPlatformApp::new()?;
+    let main_window = new_window()?;
+
+    // Warn in the log about built-in palettes below WCAG AA
+    contrast::validate_builtin_themes(&main_window);
+
+    // The window icon comes from `@icon` in main.slint; the page needs a
+    // favicon link of its own
+    #[cfg(all(feature = "app-icon", target_arch = "wasm32"))]
+    icon::add_favicon();
+
+    // Start in the configured language, else the system one, when there's a
+    // catalog for it; otherwise the untranslated (English) strings stay
+    let language = options
+        .language
+        .clone()
+        .unwrap_or_else(|| i18n::language_of(&platform::detect_locale()).to_string());
+    if i18n::set_language(&language).is_ok() {
+        main_window.set_language(language.into());
+    } else if options.language.is_some() {
+        tracing::warn!(%language, "no translation for the configured language");
+    }
+
+    // Restore the theme chosen in the previous session, or follow the OS
+    let system_supported = theme::system_theme_supported();
+    let prefs = ThemePrefs::load();
+    let mode = if prefs.theme == theme::SYSTEM_MODE && !system_supported {
+        theme::DEFAULT_THEME
+    } else {
+        prefs.theme.as_str()
+    };
+    main_window.set_system_theme_supported(system_supported);
+    main_window.set_theme_mode(mode.into());
+    main_window.set_current_theme(theme::resolve_theme(mode).into());
+    main_window.set_pinned(prefs.always_on_top && platform::always_on_top_supported());
+    main_window.global::<Motion>().set_reduced_motion(platform::detect_reduced_motion());
+    motion::apply(&main_window, prefs.animation_speed);
+    main_window.set_ui_scale(scale::clamp_override(prefs.ui_scale));
+    main_window.set_show_logs(prefs.last_view == "logs");
+    main_window.set_show_about(prefs.last_view == "about");
+
+    show_build_info(&main_window);
+
+    // Custom title bar instead of OS decorations; the web build keeps the page's
+    #[cfg(all(feature = "frameless", not(target_arch = "wasm32")))]
+    main_window.set_frameless(true);
+
+    if let Some(name) = &options.theme {
+        if !set_theme_by_name(&main_window, name) {
+            tracing::warn!(theme = %name, "unknown or unsupported theme");
+        }
+    }
+
+    // Deep links: the URL hash picks the theme and panel, and the back button
+    // returns to earlier ones
+    #[cfg(target_arch = "wasm32")]
+    setup_routing(&main_window);
+
+    if options.headless {
+        return run_headless(&main_window);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(path) = &options.screenshot {
+        return run_screenshot(&main_window, path, &options);
+    }
+
+    // Reopen at the size and position the window was closed with
+    #[cfg(not(target_arch = "wasm32"))]
+    geometry::track(&main_window);
+
+    // The web canvas fills the page and follows browser resizes
+    #[cfg(target_arch = "wasm32")]
+    viewport::fit_to_viewport(&main_window);
+
+    // Explicit sizes win over the restored geometry
+    if options.width.is_some() || options.height.is_some() {
+        let current = main_window.window().size().to_logical(main_window.window().scale_factor());
+        let width = options.width.map_or(current.width, |width| width as f32);
+        let height = options.height.map_or(current.height, |height| height as f32);
+        main_window.window().set_size(slint::LogicalSize::new(width, height));
+    }
+
+    registry::register(&main_window);
+
+    // Apply the UI scale override once the window is on screen, and again
+    // whenever it moves to a display with another scale factor
+    scale::track(&main_window);
+    #[cfg(not(target_arch = "wasm32"))]
+    install_winit_event_filter(&main_window);
+
+    // Dropped files are reported in the status bar
+    #[cfg(target_arch = "wasm32")]
+    file_drop::listen(&main_window, on_file_dropped);
+
+    // Tokio runtime for async I/O, shut down when this function returns
+    #[cfg(all(feature = "async-runtime", not(target_arch = "wasm32")))]
+    let _runtime = async_runtime::start()
+        .inspect_err(|err| tracing::warn!(%err, "could not start the async runtime"))
+        .ok();
+
+    // Ctrl+K palette entries, shared by every window
+    command_palette::register_defaults();
 
     // Set up platform-specific event handlers
     setup_event_handlers(&main_window)?;
+    setup_shared_services(&main_window);
+
+    // Let the host page drive this window from JavaScript
+    #[cfg(target_arch = "wasm32")]
+    js_api::register(&main_window);
+
+    // Record which graphics API the renderer ends up using. Collecting the
+    // platform info reads sysinfo and os-release, so it waits until the
+    // first frame is on screen instead of delaying it.
+    let window_weak = main_window.as_weak();
+    install_graphics_probe(&main_window, move || {
+        if let Some(window) = window_weak.upgrade() {
+            show_platform_info(&window, &platform::RealPlatform);
+        }
+    });
 
-    // Show platform info
-    show_platform_info(&main_window);
+    // With a tray icon, closing the window only hides it; keep the event loop
+    // running until "Quit" is picked from the tray menu.
+    #[cfg(not(target_arch = "wasm32"))]
+    if main_window.get_tray_available() {
+        main_window.show()?;
+        let result = slint::run_event_loop_until_quit();
+        tray::remove();
+        return result;
+    }
 
     main_window.run()
 }
 
+// Exercises the same callbacks the buttons trigger and prints what they
+// produced, for smoke tests and scripted runs.
+fn run_headless(app: &CrossPlatformApp) -> Result<(), slint::PlatformError> {
+    setup_event_handlers(app)?;
+
+    app.invoke_show_platform_info();
+    app.invoke_test_features();
+
+    println!("Theme: {}", app.get_current_theme());
+    println!("{}", app.get_platform_info());
+    println!("{}", app.get_test_results());
+
+    Ok(())
+}
+
+// Fills in the platform info like a normal start, then renders the window
+// at the requested size (or its default one) instead of showing it.
+#[cfg(not(target_arch = "wasm32"))]
+fn run_screenshot(app: &CrossPlatformApp, path: &std::path::Path, options: &AppOptions) -> Result<(), slint::PlatformError> {
+    setup_event_handlers(app)?;
+    show_platform_info(app, &platform::RealPlatform);
+
+    let (default_width, default_height) = screenshot::DEFAULT_SIZE;
+    let width = options.width.unwrap_or(default_width);
+    let height = options.height.unwrap_or(default_height);
+    screenshot::render_to_png(app, path, width, height).map_err(slint::PlatformError::Other)?;
+
+    tracing::info!(path = %path.display(), width, height, "screenshot saved");
+    Ok(())
+}
+
 fn setup_event_handlers(app: &CrossPlatformApp) -> Result<(), slint::PlatformError> {
     // Handle platform info request
     let app_weak = app.as_weak();
     app.on_show_platform_info(move || {
         if let Some(app) = app_weak.upgrade() {
-            show_platform_info(&app);
+            show_platform_info(&app, &platform::RealPlatform);
         }
     });
 
     // Handle feature test
     let app_weak = app.as_weak();
     app.on_test_features(move || {
+        let _span = tracing::info_span!("test_features").entered();
         if let Some(app) = app_weak.upgrade() {
-            test_platform_features(&app);
+            test_platform_features(&app, &platform::RealPlatform);
+
+            let title = app.global::<Messages>().invoke_features_tested().to_string();
+            let app_weak = app.as_weak();
+            notification::send_system_notification(title, app.get_test_results().to_string(), move |title, _| {
+                if let Some(app) = app_weak.upgrade() {
+                    app.set_status_text(title.into());
+                }
+            });
         }
     });
 
     // Handle theme toggle
     let app_weak = app.as_weak();
     app.on_toggle_theme(move || {
+        let _span = tracing::info_span!("toggle_theme").entered();
+        if let Some(app) = app_weak.upgrade() {
+            // The theme is app-wide: switch every open window
+            let new_mode = theme::next_mode(&app.get_theme_mode(), app.get_system_theme_supported());
+            tracing::info!(from = %app.get_theme_mode(), to = new_mode, "switching theme");
+            registry::for_each(|window| {
+                set_theme_by_name(window, new_mode);
+            });
+            #[cfg(target_arch = "wasm32")]
+            wasm_router::write_route(&current_route(&app));
+
+            let prefs = ThemePrefs {
+                theme: new_mode.to_string(),
+                ..ThemePrefs::load()
+            };
+            let messages = app.global::<Messages>();
+            let status = match prefs.save() {
+                Ok(()) => messages.invoke_theme_changed(new_mode.into()),
+                Err(err) => {
+                    tracing::warn!(%err, "could not save theme preference");
+                    messages.invoke_theme_not_saved(new_mode.into(), err.to_string().into())
+                }
+            };
+            app.set_status_text(status);
+        }
+    });
+
+    // Handle the always-on-top checkbox, which has already flipped `pinned`
+    let app_weak = app.as_weak();
+    app.on_toggle_always_on_top(move || {
+        if let Some(app) = app_weak.upgrade() {
+            let messages = app.global::<Messages>();
+            let pinned = app.get_pinned();
+
+            if pinned && !platform::always_on_top_supported() {
+                app.set_pinned(false);
+                #[cfg(target_arch = "wasm32")]
+                app.set_status_text(messages.invoke_always_on_top_web());
+                #[cfg(not(target_arch = "wasm32"))]
+                app.set_status_text(messages.invoke_always_on_top_unsupported());
+                return;
+            }
+
+            // App-wide like the theme
+            registry::for_each(|window| window.set_pinned(pinned));
+
+            let prefs = ThemePrefs {
+                always_on_top: pinned,
+                ..ThemePrefs::load()
+            };
+            let status = match prefs.save() {
+                Ok(()) if pinned => messages.invoke_always_on_top_enabled(),
+                Ok(()) => messages.invoke_always_on_top_disabled(),
+                Err(err) => messages.invoke_preference_not_saved(err.to_string().into()),
+            };
+            app.set_status_text(status);
+        }
+    });
+
+    // Handle the UI scale picker, e.g. "125%"
+    let app_weak = app.as_weak();
+    app.on_change_ui_scale(move |label| {
+        if let Some(app) = app_weak.upgrade() {
+            let Some(ui_scale) = scale::parse_percent(&label) else {
+                return;
+            };
+
+            // App-wide like the theme; each window keeps its own display factor
+            registry::for_each(|window| {
+                window.set_ui_scale(ui_scale);
+                scale::apply(window);
+            });
+
+            let prefs = ThemePrefs {
+                ui_scale,
+                ..ThemePrefs::load()
+            };
+            let messages = app.global::<Messages>();
+            let status = match prefs.save() {
+                Ok(()) => messages.invoke_ui_scale_changed(label),
+                Err(err) => messages.invoke_preference_not_saved(err.to_string().into()),
+            };
+            app.set_status_text(status);
+        }
+    });
+
+    // Handle the animation speed picker
+    let app_weak = app.as_weak();
+    app.on_change_animation_speed(move |index| {
+        let (Some(app), Some(speed)) = (app_weak.upgrade(), motion::speed_at(index)) else {
+            return;
+        };
+
+        let mut animation_speed = speed;
+        registry::for_each(|window| animation_speed = motion::apply(window, speed));
+
+        let prefs = ThemePrefs {
+            animation_speed,
+            ..ThemePrefs::load()
+        };
+        let messages = app.global::<Messages>();
+        let status = match prefs.save() {
+            Ok(()) => messages.invoke_animation_speed_changed(animation_speed),
+            Err(err) => messages.invoke_preference_not_saved(err.to_string().into()),
+        };
+        app.set_status_text(status);
+    });
+
+    // Handle new window request
+    let app_weak = app.as_weak();
+    app.on_new_window(move || {
+        if let Some(app) = app_weak.upgrade() {
+            let messages = app.global::<Messages>();
+            let status = match open_window(&app) {
+                Ok(()) => messages.invoke_window_opened(),
+                Err(err) => messages.invoke_window_failed(err.to_string().into()),
+            };
+            app.set_status_text(status);
+        }
+    });
+
+    // Handle switching between windowed and fullscreen
+    let app_weak = app.as_weak();
+    let fullscreen = fullscreen::Fullscreen::default();
+    app.on_toggle_fullscreen(move || {
+        if let Some(app) = app_weak.upgrade() {
+            let messages = app.global::<Messages>();
+            let status = if fullscreen.toggle(app.window()) {
+                messages.invoke_fullscreen_entered()
+            } else {
+                messages.invoke_fullscreen_left()
+            };
+            app.set_status_text(status);
+        }
+    });
+
+    // Handle re-querying the platform state
+    let app_weak = app.as_weak();
+    app.on_refresh_platform_info(move || {
+        if let Some(app) = app_weak.upgrade() {
+            show_platform_info(&app, &platform::RealPlatform);
+            refresh_live_status();
+        }
+    });
+
+    // Handle copying the platform diagnostics
+    let app_weak = app.as_weak();
+    app.on_copy_platform_info(move || {
+        if let Some(app) = app_weak.upgrade() {
+            let app_weak = app.as_weak();
+            clipboard::copy_text(app.get_platform_info().into(), move |result| {
+                if let Some(app) = app_weak.upgrade() {
+                    let messages = app.global::<Messages>();
+                    match result {
+                        Ok(()) => flash_status(&app, messages.invoke_copied()),
+                        Err(err) => app.set_status_text(messages.invoke_copy_failed(err.into())),
+                    }
+                }
+            });
+        }
+    });
+
+    // Handle exporting the diagnostics as JSON: saved to a file on desktop,
+    // copied to the clipboard in the browser or without file dialogs
+    let app_weak = app.as_weak();
+    app.on_export_diagnostics(move || {
+        let Some(app) = app_weak.upgrade() else {
+            return;
+        };
+        let json = match diagnostics::Diagnostics::collect().to_json() {
+            Ok(json) => json,
+            Err(err) => {
+                let messages = app.global::<Messages>();
+                app.set_status_text(messages.invoke_diagnostics_failed(err.to_string().into()));
+                return;
+            }
+        };
+
+        #[cfg(all(feature = "file-dialogs", not(target_arch = "wasm32")))]
+        {
+            let app_weak = app_weak.clone();
+            let _ = slint::spawn_local(async move {
+                let path = file_dialog::save_file_async(diagnostics::FILE_NAME).await;
+                let Some(app) = app_weak.upgrade() else {
+                    return;
+                };
+                let messages = app.global::<Messages>();
+                let status = match path {
+                    Some(path) => match std::fs::write(&path, json) {
+                        Ok(()) => messages.invoke_diagnostics_saved(path.display().to_string().into()),
+                        Err(err) => messages.invoke_diagnostics_failed(err.to_string().into()),
+                    },
+                    None => messages.invoke_no_file_selected(),
+                };
+                app.set_status_text(status);
+            });
+        }
+
+        #[cfg(any(not(feature = "file-dialogs"), target_arch = "wasm32"))]
+        {
+            let app_weak = app_weak.clone();
+            clipboard::copy_text(json, move |result| {
+                if let Some(app) = app_weak.upgrade() {
+                    let messages = app.global::<Messages>();
+                    match result {
+                        Ok(()) => flash_status(&app, messages.invoke_copied()),
+                        Err(err) => app.set_status_text(messages.invoke_copy_failed(err.into())),
+                    }
+                }
+            });
+        }
+    });
+
+    // Handle loading a theme from a JSON token file. The palette is
+    // app-wide, so every open window switches to it.
+    let app_weak = app.as_weak();
+    app.on_load_theme_file(move || {
+        let app_weak = app_weak.clone();
+        let _ = slint::spawn_local(async move {
+            let picked = file_dialog::pick_file_async().await;
+            let Some(app) = app_weak.upgrade() else {
+                return;
+            };
+            let messages = app.global::<Messages>();
+            let (file, contents) = match picked {
+                Some(file_dialog::PickedFile::Path(path)) => {
+                    (path.display().to_string(), std::fs::read_to_string(&path).map_err(|err| err.to_string()))
+                }
+                Some(file_dialog::PickedFile::Bytes { name, bytes }) => {
+                    (name, String::from_utf8(bytes).map_err(|err| err.to_string()))
+                }
+                None => {
+                    app.set_status_text(messages.invoke_no_file_selected());
+                    return;
+                }
+            };
+            let status = match contents.and_then(|json| theme_tokens::ThemeTokens::from_json(&json)) {
+                Ok(tokens) => {
+                    registry::for_each(|window| tokens.apply(window));
+                    let failures = contrast::check(&tokens);
+                    if failures.is_empty() {
+                        messages.invoke_theme_loaded(file.into())
+                    } else {
+                        let failures = failures.iter().map(ToString::to_string).collect::<Vec<_>>();
+                        messages.invoke_theme_low_contrast(file.into(), failures.join("; ").into())
+                    }
+                }
+                Err(err) => messages.invoke_theme_invalid(err.into()),
+            };
+            app.set_status_text(status);
+        });
+    });
+
+    // Handle exporting the current palette: saved to a file on desktop,
+    // copied to the clipboard in the browser or without file dialogs
+    let app_weak = app.as_weak();
+    app.on_export_theme(move || {
+        let Some(app) = app_weak.upgrade() else {
+            return;
+        };
+        let json = theme_tokens::ThemeTokens::current(&app).to_json();
+
+        #[cfg(all(feature = "file-dialogs", not(target_arch = "wasm32")))]
+        {
+            let app_weak = app_weak.clone();
+            let _ = slint::spawn_local(async move {
+                let path = file_dialog::save_file_async(theme_tokens::FILE_NAME).await;
+                let Some(app) = app_weak.upgrade() else {
+                    return;
+                };
+                let messages = app.global::<Messages>();
+                let status = match path {
+                    Some(path) => match std::fs::write(&path, json) {
+                        Ok(()) => messages.invoke_theme_exported(path.display().to_string().into()),
+                        Err(err) => messages.invoke_theme_export_failed(err.to_string().into()),
+                    },
+                    None => messages.invoke_no_file_selected(),
+                };
+                app.set_status_text(status);
+            });
+        }
+
+        #[cfg(any(not(feature = "file-dialogs"), target_arch = "wasm32"))]
+        {
+            let app_weak = app_weak.clone();
+            clipboard::copy_text(json, move |result| {
+                if let Some(app) = app_weak.upgrade() {
+                    let messages = app.global::<Messages>();
+                    match result {
+                        Ok(()) => flash_status(&app, messages.invoke_copied()),
+                        Err(err) => app.set_status_text(messages.invoke_copy_failed(err.into())),
+                    }
+                }
+            });
+        }
+    });
+
+    // Fetch JSON over HTTP on the tokio runtime
+    #[cfg(all(feature = "async-runtime", not(target_arch = "wasm32")))]
+    setup_repo_fetch(app);
+
+    // Handle opening a file
+    app.set_file_dialogs_available(file_dialog::AVAILABLE);
+    let app_weak = app.as_weak();
+    app.on_open_file(move || {
+        let app_weak = app_weak.clone();
+        // The dialog is awaited on the event loop, so the UI keeps painting
+        // while it is open.
+        let _ = slint::spawn_local(async move {
+            let picked = file_dialog::pick_file_async().await;
+            let Some(app) = app_weak.upgrade() else {
+                return;
+            };
+            let messages = app.global::<Messages>();
+            let status = match picked {
+                Some(file_dialog::PickedFile::Path(path)) => {
+                    messages.invoke_file_opened(path.display().to_string().into())
+                }
+                Some(file_dialog::PickedFile::Bytes { name, bytes }) => {
+                    messages.invoke_file_loaded(name.into(), bytes.len() as i32)
+                }
+                None => messages.invoke_no_file_selected(),
+            };
+            app.set_status_text(status);
+        });
+    });
+
+    // Feed the log panel, filtered by this window's search text
+    let filter_text = std::rc::Rc::new(RefCell::new(String::new()));
+    let filtered = {
+        let filter_text = filter_text.clone();
+        std::rc::Rc::new(slint::FilterModel::new(log_viewer::entries(), move |entry| {
+            log_viewer::matches(entry, &filter_text.borrow())
+        }))
+    };
+    app.set_log_entries(filtered.clone().into());
+    app.on_log_filter_changed(move |text| {
+        *filter_text.borrow_mut() = text.to_string();
+        filtered.reset();
+    });
+
+    // Handle the custom title bar of frameless windows
+    #[cfg(not(target_arch = "wasm32"))]
+    setup_window_controls(app);
+
+    // Handle keyboard shortcuts
+    app.set_shortcut_modifier(shortcuts::modifier_label().into());
+    let app_weak = app.as_weak();
+    app.on_key_pressed(move |key| {
+        let Some(app) = app_weak.upgrade() else {
+            return false;
+        };
+        let f5: slint::SharedString = slint::platform::Key::F5.into();
+        let f11: slint::SharedString = slint::platform::Key::F11.into();
+        let letter = key.text.to_lowercase();
+
+        match (shortcuts::command_held(&key), letter.as_str()) {
+            (true, "k") => command_palette::open(&app),
+            (true, "t") => app.invoke_toggle_theme(),
+            (true, "q") => {
+                let _ = slint::quit_event_loop();
+            }
+            (false, _) if key.text == f5 => app.invoke_refresh_platform_info(),
+            (false, _) if key.text == f11 => app.invoke_toggle_fullscreen(),
+            _ => return false,
+        }
+        true
+    });
+
+    // Ctrl+K command palette
+    command_palette::install(app);
+
+    // Handle language selection
+    let app_weak = app.as_weak();
+    app.on_change_language(move |language| {
         if let Some(app) = app_weak.upgrade() {
-            let current_theme = app.get_current_theme();
-            let new_theme = if current_theme == "light" { "dark" } else { "light" };
-            app.set_current_theme(new_theme.into());
+            let messages = app.global::<Messages>();
+            match i18n::set_language(&language) {
+                Ok(()) => {
+                    // Translations are process-wide; re-render the text Rust
+                    // built in every window.
+                    registry::for_each(|window| {
+                        window.set_language(language.clone());
+                        show_platform_info(window, &platform::RealPlatform);
+                        if window.get_features_tested() {
+                            test_platform_features(window, &platform::RealPlatform);
+                        }
+                    });
+                    app.set_status_text(messages.invoke_language_changed(language));
+                }
+                Err(_) => app.set_status_text(messages.invoke_language_unavailable(language)),
+            }
+        }
+    });
 
-            let status = format!("Theme changed to {}", new_theme);
-            app.set_status_text(status.into());
+    // Remember the open panel for the next launch
+    let app_weak = app.as_weak();
+    app.on_navigated(move || {
+        if let Some(app) = app_weak.upgrade() {
+            let last_view = if app.get_show_about() {
+                "about"
+            } else if app.get_show_logs() {
+                "logs"
+            } else {
+                ""
+            };
+            let prefs = ThemePrefs {
+                last_view: last_view.to_string(),
+                ..ThemePrefs::load()
+            };
+            if let Err(err) = prefs.save() {
+                tracing::warn!(%err, "could not save the open panel");
+            }
+
+            #[cfg(target_arch = "wasm32")]
+            wasm_router::write_route(&current_route(&app));
         }
     });
 
     Ok(())
 }
 
-fn show_platform_info(app: &CrossPlatformApp) {
-    let platform = get_platform_info();
-    let backend = get_backend_info();
-    let features = get_available_features();
+#[cfg(target_arch = "wasm32")]
+fn setup_routing(app: &CrossPlatformApp) {
+    // Without a route in the URL the restored preferences stay
+    let route = wasm_router::read_route();
+    if route != wasm_router::Route::default() {
+        apply_route(app, &route);
+    }
+
+    let app_weak = app.as_weak();
+    wasm_router::on_route_change(move |route| {
+        if let Some(app) = app_weak.upgrade() {
+            apply_route(&app, &route);
+        }
+    });
+}
+
+#[cfg(target_arch = "wasm32")]
+fn apply_route(app: &CrossPlatformApp, route: &wasm_router::Route) {
+    if let Some(theme) = &route.theme {
+        set_theme_by_name(app, theme);
+    }
+    app.set_show_logs(route.view == wasm_router::View::Logs);
+    app.set_show_about(route.view == wasm_router::View::About);
+}
 
-    let info = format!(
-        "Platform: {}\nBackend: {}\nFeatures: {}",
-        platform,
-        backend,
-        features.join(", ")
-    );
+#[cfg(target_arch = "wasm32")]
+fn current_route(app: &CrossPlatformApp) -> wasm_router::Route {
+    let view = if app.get_show_about() {
+        wasm_router::View::About
+    } else if app.get_show_logs() {
+        wasm_router::View::Logs
+    } else {
+        wasm_router::View::Home
+    };
 
-    app.set_platform_info(info.into());
+    wasm_router::Route {
+        view,
+        theme: Some(app.get_theme_mode().to_string()),
+    }
 }
 
-fn test_platform_features(app: &CrossPlatformApp) {
-    let mut test_results = Vec::new();
+#[cfg(not(target_arch = "wasm32"))]
+fn setup_window_controls(app: &CrossPlatformApp) {
+    let app_weak = app.as_weak();
+    app.on_title_bar_drag(move || {
+        if let Some(app) = app_weak.upgrade() {
+            window_controls::start_move(app.window());
+        }
+    });
 
-    // Test window operations
-    test_results.push("Window operations: OK".to_string());
+    let app_weak = app.as_weak();
+    app.on_resize_from_edge(move |edge| {
+        if let Some(app) = app_weak.upgrade() {
+            window_controls::start_resize(app.window(), edge);
+        }
+    });
 
-    // Test threading (if available)
+    let app_weak = app.as_weak();
+    app.on_toggle_maximized(move || {
+        if let Some(app) = app_weak.upgrade() {
+            let maximized = window_controls::toggle_maximized(app.window());
+            app.set_maximized(maximized);
+        }
+    });
+
+    let app_weak = app.as_weak();
+    app.on_minimize_window(move || {
+        if let Some(app) = app_weak.upgrade() {
+            window_controls::minimize(app.window());
+        }
+    });
+
+    let app_weak = app.as_weak();
+    app.on_close_window(move || {
+        if let Some(app) = app_weak.upgrade() {
+            window_controls::close(app.window());
+        }
+    });
+}
+
+// Shows `text` in the status bar for two seconds, then restores the previous
+// status unless something else has replaced it in the meantime.
+fn flash_status(app: &CrossPlatformApp, text: slint::SharedString) {
+    let previous = app.get_status_text();
+    app.set_status_text(text.clone());
+
+    let app_weak = app.as_weak();
+    slint::Timer::single_shot(std::time::Duration::from_secs(2), move || {
+        if let Some(app) = app_weak.upgrade() {
+            if app.get_status_text() == text {
+                app.set_status_text(previous);
+            }
+        }
+    });
+}
+
+// App-wide services that exist once, no matter how many windows are open.
+// The request runs on a tokio worker; only the result comes back to the UI
+// thread, where the window handle is upgraded again.
+#[cfg(all(feature = "async-runtime", not(target_arch = "wasm32")))]
+fn setup_repo_fetch(app: &CrossPlatformApp) {
+    app.set_repo_fetch_available(true);
+
+    let app_weak = app.as_weak();
+    app.on_fetch_repo(move || {
+        let Some(app) = app_weak.upgrade() else {
+            return;
+        };
+        let messages = app.global::<Messages>();
+        app.set_status_text(messages.invoke_fetching(repo_info::DEMO_REPO.into()));
+
+        let app_weak = app_weak.clone();
+        let started = async_runtime::spawn_async(repo_info::fetch(repo_info::DEMO_REPO), move |result| {
+            let Some(app) = app_weak.upgrade() else {
+                return;
+            };
+            let messages = app.global::<Messages>();
+            let status = match result {
+                Ok(repo) => messages.invoke_repo_summary(
+                    repo.full_name.into(),
+                    repo.stargazers_count.min(i32::MAX as u64) as i32,
+                    repo.description.unwrap_or_default().into(),
+                ),
+                Err(err) => messages.invoke_fetch_failed(err.into()),
+            };
+            app.set_status_text(status);
+        });
+        if !started {
+            app.set_status_text(messages.invoke_fetch_failed("the async runtime isn't running".into()));
+        }
+    });
+}
+
+fn setup_shared_services(main_window: &CrossPlatformApp) {
+    // Minimize to the system tray instead of quitting, where a tray exists
     #[cfg(not(target_arch = "wasm32"))]
     {
-        test_results.push("Threading: Available".to_string());
-    }
-    #[cfg(target_arch = "wasm32")]
-    {
-        test_results.push("Threading: Limited".to_string());
+        let tray_available = tray::install(main_window);
+        platform::record_system_tray(tray_available);
+        main_window.set_tray_available(tray_available);
     }
 
-    // Test file system access
+    // Keep connectivity and battery readings current
+    refresh_live_status();
+    LIVE_REFRESH.with(|timer| {
+        timer.start(slint::TimerMode::Repeated, LIVE_REFRESH_INTERVAL, refresh_live_status);
+    });
+
+    // Online/offline indicator
+    connectivity::watch(|status| {
+        slint::invoke_from_event_loop(move || {
+            registry::for_each(|window| window.set_connectivity_status(status));
+        })
+        .is_ok()
+    });
+
+    // Keep "system" mode in sync with the OS while the app is open
+    theme::watch_system_theme(move |system_theme| {
+        slint::invoke_from_event_loop(move || {
+            registry::for_each(|window| {
+                if window.get_theme_mode() == theme::SYSTEM_MODE {
+                    window.set_current_theme(system_theme.as_str().into());
+                }
+            });
+        })
+        .is_ok()
+    });
+}
+
+const LIVE_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+thread_local! {
+    static LIVE_REFRESH: slint::Timer = slint::Timer::default();
+}
+
+// Re-reads the dynamic platform state and pushes it to every window. On
+// desktop the reading happens on a worker thread so a slow sysfs read never
+// stalls the UI; a refresh requested while one is running is dropped.
+fn refresh_live_status() {
     #[cfg(not(target_arch = "wasm32"))]
     {
-        test_results.push("File system: Available".to_string());
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        static IN_FLIGHT: AtomicBool = AtomicBool::new(false);
+        if IN_FLIGHT.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        std::thread::spawn(|| {
+            let status = platform::collect_live();
+            let _ = slint::invoke_from_event_loop(move || apply_live_status(status));
+            IN_FLIGHT.store(false, Ordering::Release);
+        });
     }
+
     #[cfg(target_arch = "wasm32")]
-    {
-        test_results.push("File system: Browser storage".to_string());
+    apply_live_status(platform::collect_live());
+}
+
+fn apply_live_status(status: platform::LiveStatus) {
+    let updated = chrono::Local::now().format("%H:%M:%S").to_string();
+    registry::for_each(|window| {
+        window.set_battery_percent(status.battery.map_or(-1, |battery| i32::from(battery.percent)));
+        window.set_battery_charging(status.battery.is_some_and(|battery| battery.state == platform::BatteryState::Charging));
+        window.set_last_updated(updated.as_str().into());
+    });
+}
+
+/// Opens another independent window that shares the opener's theme. The app
+/// keeps running until the last window is closed.
+#[cfg(not(target_arch = "wasm32"))]
+fn open_window(opener: &CrossPlatformApp) -> Result<(), slint::PlatformError> {
+    let window = CrossPlatformApp::new()?;
+    window.set_language(opener.get_language());
+    window.set_connectivity_status(opener.get_connectivity_status());
+    window.set_pinned(opener.get_pinned());
+    window.set_frameless(opener.get_frameless());
+    window.set_system_theme_supported(opener.get_system_theme_supported());
+    window.set_ui_scale(opener.get_ui_scale());
+    let opener_motion = opener.global::<Motion>();
+    window.global::<Motion>().set_reduced_motion(opener_motion.get_reduced_motion());
+    motion::apply(&window, opener_motion.get_speed());
+    set_theme_by_name(&window, &opener.get_theme_mode());
+
+    setup_event_handlers(&window)?;
+    show_platform_info(&window, &platform::RealPlatform);
+    show_build_info(&window);
+
+    let id = registry::register(&window);
+    scale::track(&window);
+    install_winit_event_filter(&window);
+    window.window().on_close_requested(move || {
+        // Drop our handle after the close has been processed, not from
+        // inside the window's own callback.
+        slint::Timer::single_shot(std::time::Duration::ZERO, move || registry::unregister(id));
+        slint::CloseRequestResponse::HideWindow
+    });
+
+    window.show()
+}
+
+// The browser build renders into a single canvas.
+#[cfg(target_arch = "wasm32")]
+fn open_window(_opener: &CrossPlatformApp) -> Result<(), slint::PlatformError> {
+    Err(slint::PlatformError::Other(
+        "multiple windows are not supported in the browser".into(),
+    ))
+}
+
+// winit takes a single event filter per window, so this one hands each event
+// to every module that needs it (desktop only).
+#[cfg(not(target_arch = "wasm32"))]
+fn install_winit_event_filter(app: &CrossPlatformApp) {
+    use slint::winit_030::{WinitWindowAccessor, WinitWindowEventResult};
+
+    let drops = file_drop::DropTarget::new(on_file_dropped);
+
+    let app_weak = app.as_weak();
+    app.window().on_winit_window_event(move |_, event| {
+        if let Some(app) = app_weak.upgrade() {
+            scale::handle_winit_event(&app, event);
+            drops.handle_winit_event(&app, event);
+        }
+        WinitWindowEventResult::Propagate
+    });
+}
+
+// Lists what was dropped in the status bar: paths on desktop, names and
+// sizes in the browser. Folders are listed separately and never opened.
+fn on_file_dropped(app: &CrossPlatformApp, items: Vec<file_drop::DroppedItem>) {
+    let messages = app.global::<Messages>();
+    let mut files = Vec::new();
+    let mut folders = Vec::new();
+    for item in items {
+        match item {
+            file_drop::DroppedItem::File(file_dialog::PickedFile::Path(path)) => files.push(path.display().to_string()),
+            file_drop::DroppedItem::File(file_dialog::PickedFile::Bytes { name, bytes }) => {
+                files.push(messages.invoke_file_size(name.into(), bytes.len() as i32).into())
+            }
+            file_drop::DroppedItem::Directory(name) => folders.push(name),
+        }
+    }
+    tracing::info!(?files, ?folders, "files dropped");
+
+    let mut parts = Vec::new();
+    if !files.is_empty() {
+        parts.push(messages.invoke_files_dropped(files.len() as i32, files.join(", ").into()).to_string());
+    }
+    if !folders.is_empty() {
+        parts.push(messages.invoke_folders_skipped(folders.join(", ").into()).to_string());
+    }
+    if !parts.is_empty() {
+        app.set_status_text(parts.join(" · ").into());
+    }
+}
+
+/// Switches directly to a theme or to "system" mode, e.g. from a CLI flag.
+/// Unknown names keep the current theme and return `false`.
+fn set_theme_by_name(app: &CrossPlatformApp, name: &str) -> bool {
+    let system_unavailable = name == theme::SYSTEM_MODE && !app.get_system_theme_supported();
+    if !theme::THEME_MODES.contains(&name) || system_unavailable {
+        return false;
+    }
+
+    app.set_theme_mode(name.into());
+    app.set_current_theme(theme::resolve_theme(name).into());
+    true
+}
+
+fn show_build_info(app: &CrossPlatformApp) {
+    let info = build_info::BuildInfo::current();
+    let messages = app.global::<Messages>();
+    let or_unknown = |value: &str| {
+        if value == build_info::UNKNOWN {
+            messages.invoke_unknown()
+        } else {
+            value.into()
+        }
+    };
+
+    app.set_build_version(info.version.into());
+    app.set_build_commit(or_unknown(info.git_hash));
+    app.set_build_date(or_unknown(&info.built_at));
+}
+
+// Takes the provider as a parameter so the rendering can be exercised with
+// `MockPlatform` values.
+fn show_platform_info(app: &CrossPlatformApp, provider: &dyn platform::PlatformProvider) {
+    let platform = provider.collect();
+    // Re-enumerated on every call, so refreshing picks up hot-plugged screens
+    let monitors = platform::enumerate_monitors(app.window());
+
+    let info = platform_info_text(
+        &app.global::<Messages>(),
+        &platform,
+        &monitors,
+        app.window().scale_factor(),
+        app.get_display_scale(),
+        app.get_ui_scale(),
+    );
+    app.set_platform_info(info.into());
+}
+
+// The info panel text. Everything it shows comes in as arguments, so fixed
+// platform values and monitors always give the same text.
+fn platform_info_text(
+    messages: &Messages<'_>,
+    platform: &platform::PlatformInfo,
+    monitors: &[platform::MonitorInfo],
+    window_scale: f32,
+    display_scale: f32,
+    ui_scale: f32,
+) -> String {
+    let cores = match platform.cpu_cores {
+        Some(cores) => cores.to_string().into(),
+        None => messages.invoke_unknown(),
+    };
+    let memory = match (platform.available_memory, platform.total_memory) {
+        (Some(available), Some(total)) => messages.invoke_memory_usage(gib(available).into(), gib(total).into()),
+        _ => messages.invoke_unknown(),
+    };
+
+    let monitors = if monitors.is_empty() {
+        messages.invoke_unknown()
+    } else {
+        let described: Vec<String> = monitors.iter().map(|monitor| describe_monitor(messages, monitor)).collect();
+        format!("{} ({})", monitors.len(), described.join("; ")).into()
+    };
+
+    let info = messages.invoke_platform_summary(
+        platform.os_label().into(),
+        platform.backend.as_str().into(),
+        cores,
+        memory,
+        monitors,
+        platform.locale.as_str().into(),
+        platform.timezone.as_str().into(),
+        platform.features.join(", ").into(),
+    );
+    let scale = messages.invoke_scale_details(
+        format!("{}", window_scale).into(),
+        format!("{}", display_scale).into(),
+        format!("{}", ui_scale).into(),
+    );
+
+    // Only when detected: bare metal gets no line
+    match &platform.virtualization {
+        Some(kind) => format!("{}\n{}\n{}", info, messages.invoke_running_in(kind.as_str().into()), scale),
+        None => format!("{}\n{}", info, scale),
     }
+}
+
+// e.g. "DELL U2720Q 3840×2160 at 1.5x, 60 Hz"
+fn describe_monitor(messages: &Messages<'_>, monitor: &platform::MonitorInfo) -> String {
+    let name = match &monitor.name {
+        Some(name) => name.as_str().into(),
+        None => messages.invoke_unnamed_display(),
+    };
+    let refresh = match monitor.refresh_rate_hz {
+        Some(hertz) => messages.invoke_refresh_rate(format!("{:.0}", hertz).into()),
+        None => messages.invoke_unknown(),
+    };
+
+    messages
+        .invoke_monitor_details(
+            name,
+            monitor.width as i32,
+            monitor.height as i32,
+            format!("{}", monitor.scale_factor).into(),
+            refresh,
+        )
+        .into()
+}
+
+// Bytes as GiB with one decimal, e.g. "15.6".
+fn gib(bytes: u64) -> String {
+    format!("{:.1}", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+}
+
+fn test_platform_features(app: &CrossPlatformApp, provider: &dyn platform::PlatformProvider) {
+    // Report which backend the checks below ran against
+    let backend = provider.backend();
+
+    // Test window operations
+    let window = "OK".to_string();
+
+    // Test threading (if available)
+    let threading = test_threading();
+
+    // Test file system access
+    let file_system = test_file_system();
 
     // Test graphics capabilities
-    test_results.push("Graphics: Hardware accelerated".to_string());
+    let graphics = test_graphics();
+
+    tracing::debug!(%backend, %window, %threading, %file_system, %graphics, "feature checks");
+
+    // The labels are translated, the results are not
+    let results = app.global::<Messages>().invoke_feature_results(
+        backend.into(),
+        window.into(),
+        threading.into(),
+        file_system.into(),
+        graphics.into(),
+    );
+    app.set_test_results(results);
+    app.set_features_tested(true);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn test_threading() -> String {
+    let worker = std::thread::Builder::new()
+        .name("feature-test".into())
+        .spawn(|| (1..=100u64).sum::<u64>());
 
-    app.set_test_results(test_results.join("\n").into());
+    match worker.map(|handle| handle.join()) {
+        Ok(Ok(5050)) => {
+            let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+            format!("OK ({} cores)", cores)
+        }
+        _ => "Failed".to_string(),
+    }
 }
 
-fn get_platform_info() -> &'static str {
-    #[cfg(target_os = "windows")]
-    return "Windows";
+// Browser threads need SharedArrayBuffer, which is only exposed on
+// cross-origin isolated pages (COOP/COEP headers).
+#[cfg(target_arch = "wasm32")]
+fn test_threading() -> String {
+    let global = js_sys::global();
+    let has_shared_memory = js_sys::Reflect::has(&global, &"SharedArrayBuffer".into()).unwrap_or(false);
+    let isolated = js_sys::Reflect::get(&global, &"crossOriginIsolated".into())
+        .ok()
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false);
+
+    if has_shared_memory && isolated {
+        "Limited (SharedArrayBuffer available)".to_string()
+    } else {
+        "Unavailable (page is not cross-origin isolated)".to_string()
+    }
+}
 
-    #[cfg(target_os = "macos")]
-    return "macOS";
+#[cfg(not(target_arch = "wasm32"))]
+fn test_file_system() -> String {
+    // Unique per call, so checks running at the same time (e.g. from two
+    // windows, or in tests) don't share a file
+    static CHECKS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    let check = CHECKS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let path = std::env::temp_dir().join(format!("slint-feature-test-{}-{}.tmp", std::process::id(), check));
+    let payload = b"slint feature test";
 
-    #[cfg(target_os = "linux")]
-    return "Linux";
+    let result = std::fs::write(&path, payload).and_then(|_| std::fs::read(&path));
+    // Clean up even when the read failed halfway.
+    let _ = std::fs::remove_file(&path);
 
-    #[cfg(target_arch = "wasm32")]
-    return "WebAssembly";
+    match result {
+        Ok(contents) if contents == payload => "OK (rw verified)".to_string(),
+        Ok(_) => "Failed (read back different bytes)".to_string(),
+        Err(err) => format!("Failed ({})", err),
+    }
+}
 
-    #[cfg(target_os = "android")]
-    return "Android";
+// localStorage access throws in some private-browsing modes and sandboxed
+// iframes, so probe it instead of assuming it exists.
+#[cfg(target_arch = "wasm32")]
+fn test_file_system() -> String {
+    let storage = web_sys::window().map(|window| window.local_storage());
 
-    #[cfg(target_os = "ios")]
-    return "iOS";
+    match storage {
+        Some(Ok(Some(storage))) => {
+            let key = "slint-feature-test";
+            let writable = storage.set_item(key, "ok").is_ok();
+            let _ = storage.remove_item(key);
+            if writable {
+                "Browser storage (localStorage)".to_string()
+            } else {
+                "Browser storage (read-only)".to_string()
+            }
+        }
+        _ => "Unavailable (localStorage blocked)".to_string(),
+    }
+}
 
-    "Unknown"
+thread_local! {
+    // Filled in by the rendering notifier once the renderer has set up its
+    // graphics context; `None` until the first frame.
+    static GRAPHICS_INFO: RefCell<Option<String>> = const { RefCell::new(None) };
 }
 
-fn get_backend_info() -> &'static str {
-    #[cfg(target_os = "windows")]
-    return "Win32";
+// Also runs `after_first_frame` once, from a timer right after the first
+// frame is rendered, so startup work that isn't needed for it can wait.
+fn install_graphics_probe(app: &CrossPlatformApp, after_first_frame: impl FnOnce() + 'static) {
+    let pending: std::rc::Rc<RefCell<Option<Box<dyn FnOnce()>>>> = std::rc::Rc::new(RefCell::new(Some(Box::new(after_first_frame))));
+
+    let first_frame = pending.clone();
+    let result = app.window().set_rendering_notifier(move |state, graphics_api| match state {
+        slint::RenderingState::RenderingSetup => {
+            let info = describe_graphics_api(graphics_api);
+            GRAPHICS_INFO.with(|cell| *cell.borrow_mut() = Some(info));
+        }
+        slint::RenderingState::AfterRendering => {
+            if let Some(callback) = first_frame.borrow_mut().take() {
+                tracing::info!("first frame rendered");
+                // Not from inside the renderer: the callback updates properties
+                slint::Timer::single_shot(std::time::Duration::ZERO, callback);
+            }
+        }
+        _ => {}
+    });
 
-    #[cfg(target_os = "macos")]
-    return "Cocoa";
+    // The software renderer has no graphics context to report and rejects
+    // rendering notifiers outright; run the callback once the loop starts.
+    if let Err(slint::SetRenderingNotifierError::Unsupported) = result {
+        GRAPHICS_INFO.with(|cell| *cell.borrow_mut() = Some("Software fallback".to_string()));
+        if let Some(callback) = pending.borrow_mut().take() {
+            slint::Timer::single_shot(std::time::Duration::ZERO, callback);
+        }
+    }
+}
 
-    #[cfg(target_os = "linux")]
-    return "X11/Wayland";
+fn describe_graphics_api(graphics_api: &slint::GraphicsAPI<'_>) -> String {
+    match graphics_api {
+        slint::GraphicsAPI::NativeOpenGL { get_proc_address } => {
+            let renderer = platform::renderer_name();
+            match opengl_device(*get_proc_address) {
+                Some(device) => format!("GPU ({}, OpenGL: {})", renderer, device),
+                None => format!("GPU ({}, OpenGL)", renderer),
+            }
+        }
+        slint::GraphicsAPI::WebGL { context_type, .. } => format!("GPU ({})", context_type),
+        _ => format!("GPU ({})", platform::renderer_name()),
+    }
+}
 
-    #[cfg(target_arch = "wasm32")]
-    return "WebGL";
+// Asks the driver for "vendor / device" through glGetString. Only valid while
+// the renderer's GL context is current, i.e. inside the rendering notifier.
+fn opengl_device(get_proc_address: &dyn Fn(&std::ffi::CStr) -> *const std::ffi::c_void) -> Option<String> {
+    const GL_VENDOR: u32 = 0x1F00;
+    const GL_RENDERER: u32 = 0x1F01;
 
-    "Default"
+    let gl_get_string = get_proc_address(c"glGetString");
+    if gl_get_string.is_null() {
+        return None;
+    }
+
+    // SAFETY: glGetString has this signature on every GL implementation and
+    // the pointer was just resolved from the current context.
+    let gl_get_string: extern "system" fn(u32) -> *const std::ffi::c_char =
+        unsafe { std::mem::transmute(gl_get_string) };
+
+    let read = |name| {
+        let ptr = gl_get_string(name);
+        // SAFETY: non-null results are static NUL-terminated strings owned by the driver.
+        (!ptr.is_null()).then(|| unsafe { std::ffi::CStr::from_ptr(ptr) }.to_string_lossy().into_owned())
+    };
+
+    match (read(GL_VENDOR), read(GL_RENDERER)) {
+        (Some(vendor), Some(device)) => Some(format!("{} / {}", vendor, device)),
+        (None, Some(device)) => Some(device),
+        _ => None,
+    }
+}
+
+fn test_graphics() -> String {
+    GRAPHICS_INFO.with(|cell| match cell.borrow().as_deref() {
+        Some(info) => info.to_string(),
+        None => "Unknown (nothing rendered yet)".to_string(),
+    })
 }
 
-fn get_available_features() -> Vec<&'static str> {
-    let mut features = vec!["Basic UI", "Animations", "Theming"];
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    // A window wired the way `run_app_with` wires it, minus the event loop.
+    // Dropping it takes the window out of the registry again.
     #[cfg(not(target_arch = "wasm32"))]
-    {
-        features.extend_from_slice(&["File dialogs", "System tray", "Multiple windows"]);
+    struct TestApp {
+        app: CrossPlatformApp,
+        id: u32,
     }
 
-    #[cfg(target_arch = "wasm32")]
-    {
-        features.extend_from_slice(&["Web integration", "Browser storage"]);
+    #[cfg(not(target_arch = "wasm32"))]
+    impl TestApp {
+        fn new() -> Self {
+            i_slint_backend_testing::init_no_event_loop();
+            let app = CrossPlatformApp::new().unwrap();
+            let id = registry::register(&app);
+            command_palette::register_defaults();
+            setup_event_handlers(&app).unwrap();
+            Self { app, id }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl std::ops::Deref for TestApp {
+        type Target = CrossPlatformApp;
+
+        fn deref(&self) -> &CrossPlatformApp {
+            &self.app
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl Drop for TestApp {
+        fn drop(&mut self) {
+            registry::unregister(self.id);
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn command(text: &str) -> KeyPress {
+        KeyPress { text: text.into(), control: true, ..Default::default() }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn file_system_check_round_trips_a_temp_file() {
+        assert_eq!(test_file_system(), "OK (rw verified)");
+    }
+
+    // `main` reports whatever comes back here through `startup_error`
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn window_creation_failures_reach_the_caller() {
+        let options = AppOptions { headless: true, ..AppOptions::default() };
+        let result = run_app_with(options, || Err(slint::PlatformError::Other("no GPU".into())));
+
+        match result {
+            Err(slint::PlatformError::Other(message)) => assert_eq!(message, "no GPU"),
+            other => panic!("expected the injected error, got {other:?}"),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn switching_language_changes_a_visible_label() {
+        i_slint_backend_testing::init_no_event_loop();
+        let app = CrossPlatformApp::new().unwrap();
+        assert_eq!(app.get_test_results(), "Click to test features");
+
+        i18n::set_language("zh").unwrap();
+        assert_eq!(app.get_test_results(), "点击测试功能");
+
+        i18n::set_language("en").unwrap();
+        assert_eq!(app.get_test_results(), "Click to test features");
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn feature_results_are_rendered_in_the_current_language() {
+        i_slint_backend_testing::init_no_event_loop();
+        let app = CrossPlatformApp::new().unwrap();
+        let provider = slint_skills_core::MockPlatform::default();
+
+        test_platform_features(&app, &provider);
+        assert!(app.get_features_tested());
+        assert!(app.get_test_results().starts_with("Backend: X11 (femtovg)\nWindow operations: OK\n"));
+
+        i18n::set_language("zh").unwrap();
+        test_platform_features(&app, &provider);
+        assert!(app.get_test_results().starts_with("后端：X11 (femtovg)\n窗口操作：OK\n"));
+        i18n::set_language("en").unwrap();
+    }
+
+    // Catches label and order changes in the info panel; the second monitor
+    // covers the unnamed display and unknown refresh rate
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn platform_info_text_matches_the_golden_copy() {
+        use platform::PlatformProvider;
+
+        i_slint_backend_testing::init_no_event_loop();
+        let app = CrossPlatformApp::new().unwrap();
+        let platform = slint_skills_core::MockPlatform::default().collect();
+        let monitors = [
+            platform::MonitorInfo { name: Some("Test".into()), width: 1920, height: 1080, scale_factor: 1.0, refresh_rate_hz: Some(60.0) },
+            platform::MonitorInfo { name: None, width: 2560, height: 1440, scale_factor: 1.5, refresh_rate_hz: None },
+        ];
+
+        let text = platform_info_text(&app.global::<Messages>(), &platform, &monitors, 1.0, 1.0, 1.0);
+        assert_eq!(text, include_str!("../tests/platform_info.golden"));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn platform_info_is_filled_in_and_refreshed() {
+        let app = TestApp::new();
+        let placeholder = app.get_platform_info();
+
+        app.invoke_show_platform_info();
+        assert_ne!(app.get_platform_info(), placeholder);
+
+        app.set_platform_info(placeholder.clone());
+        app.invoke_refresh_platform_info();
+        assert_ne!(app.get_platform_info(), placeholder);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_features_fills_in_the_results() {
+        let app = TestApp::new();
+        app.invoke_test_features();
+        assert!(app.get_features_tested());
+        assert!(app.get_test_results().starts_with("Backend: "));
+        // Without system notifications the title goes to the status bar
+        #[cfg(not(feature = "system-notifications"))]
+        assert_eq!(app.get_status_text(), app.global::<Messages>().invoke_features_tested());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn toggle_theme_moves_to_the_next_mode_and_saves_it() {
+        let app = TestApp::new();
+        let before = app.get_theme_mode();
+        let expected = theme::next_mode(&before, app.get_system_theme_supported());
+
+        app.invoke_toggle_theme();
+        assert_eq!(app.get_theme_mode(), expected);
+        assert_eq!(app.get_current_theme(), theme::resolve_theme(expected));
+        assert_eq!(app.get_status_text(), app.global::<Messages>().invoke_theme_changed(expected.into()));
+        assert_eq!(ThemePrefs::load().theme, expected);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn always_on_top_is_kept_only_where_supported() {
+        let app = TestApp::new();
+        let messages = app.global::<Messages>();
+        app.set_pinned(true);
+        app.invoke_toggle_always_on_top();
+
+        if platform::always_on_top_supported() {
+            assert!(app.get_pinned());
+            assert_eq!(app.get_status_text(), messages.invoke_always_on_top_enabled());
+            assert!(ThemePrefs::load().always_on_top);
+
+            app.set_pinned(false);
+            app.invoke_toggle_always_on_top();
+            assert_eq!(app.get_status_text(), messages.invoke_always_on_top_disabled());
+        } else {
+            assert!(!app.get_pinned());
+            assert_eq!(app.get_status_text(), messages.invoke_always_on_top_unsupported());
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn toggle_fullscreen_enters_and_leaves() {
+        let app = TestApp::new();
+        let messages = app.global::<Messages>();
+
+        app.invoke_toggle_fullscreen();
+        assert!(app.window().is_fullscreen());
+        assert_eq!(app.get_status_text(), messages.invoke_fullscreen_entered());
+
+        app.invoke_toggle_fullscreen();
+        assert!(!app.window().is_fullscreen());
+        assert_eq!(app.get_status_text(), messages.invoke_fullscreen_left());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn change_language_switches_or_explains() {
+        let app = TestApp::new();
+        let messages = app.global::<Messages>();
+
+        app.invoke_change_language("zh".into());
+        assert_eq!(app.get_language(), "zh");
+        assert_eq!(app.get_status_text(), messages.invoke_language_changed("zh".into()));
+
+        app.invoke_change_language("xx".into());
+        assert_eq!(app.get_language(), "zh");
+        assert_eq!(app.get_status_text(), messages.invoke_language_unavailable("xx".into()));
+
+        app.invoke_change_language("en".into());
+        assert_eq!(app.get_language(), "en");
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn change_ui_scale_parses_the_label() {
+        let app = TestApp::new();
+        app.invoke_change_ui_scale("125%".into());
+        assert_eq!(app.get_ui_scale(), 1.25);
+        assert_eq!(app.get_status_text(), app.global::<Messages>().invoke_ui_scale_changed("125%".into()));
+        assert_eq!(ThemePrefs::load().ui_scale, 1.25);
+
+        app.invoke_change_ui_scale("large".into());
+        assert_eq!(app.get_ui_scale(), 1.25);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn change_animation_speed_sets_the_motion_global() {
+        let app = TestApp::new();
+        app.invoke_change_animation_speed(0);
+        assert_eq!(app.global::<Motion>().get_speed(), motion::SPEEDS[0]);
+        assert_eq!(ThemePrefs::load().animation_speed, motion::SPEEDS[0]);
+
+        app.invoke_change_animation_speed(3);
+        assert_eq!(app.global::<Motion>().get_speed(), motion::SPEEDS[3]);
+
+        // Past the end of the picker
+        app.invoke_change_animation_speed(99);
+        assert_eq!(app.global::<Motion>().get_speed(), motion::SPEEDS[3]);
+    }
+
+    // Succeeds with a clipboard and fails without one (e.g. in CI); either
+    // way the status bar says so
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn copy_platform_info_reports_the_outcome() {
+        let app = TestApp::new();
+        let before = app.get_status_text();
+        app.invoke_copy_platform_info();
+        assert_ne!(app.get_status_text(), before);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn log_filter_keeps_the_matching_lines() {
+        use slint::Model;
+
+        let app = TestApp::new();
+        let log = log_viewer::entries();
+        let log = log.as_any().downcast_ref::<slint::VecModel<LogEntry>>().unwrap();
+        for (level, message) in [("INFO", "starting"), ("WARN", "could not save")] {
+            log.push(LogEntry { level: level.into(), time: "12:00:00".into(), target: "app".into(), message: message.into() });
+        }
+
+        app.invoke_log_filter_changed("warn".into());
+        let shown: Vec<_> = app.get_log_entries().iter().map(|entry| entry.message).collect();
+        assert_eq!(shown, ["could not save"]);
+
+        app.invoke_log_filter_changed("".into());
+        assert_eq!(app.get_log_entries().row_count(), 2);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn key_pressed_runs_the_shortcuts() {
+        let app = TestApp::new();
+
+        let before = app.get_theme_mode();
+        assert!(app.invoke_key_pressed(command("T")));
+        assert_ne!(app.get_theme_mode(), before);
+
+        assert!(app.invoke_key_pressed(command("k")));
+        assert!(app.get_show_palette());
+
+        let f11 = KeyPress { text: slint::platform::Key::F11.into(), ..Default::default() };
+        assert!(app.invoke_key_pressed(f11));
+        assert!(app.window().is_fullscreen());
+
+        let f5 = KeyPress { text: slint::platform::Key::F5.into(), ..Default::default() };
+        assert!(app.invoke_key_pressed(f5));
+
+        // Letters without the modifier are typing, not shortcuts
+        assert!(!app.invoke_key_pressed(KeyPress { text: "t".into(), ..Default::default() }));
+        assert!(!app.invoke_key_pressed(command("x")));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn the_palette_ranks_and_runs_commands() {
+        use slint::Model;
+
+        let app = TestApp::new();
+        app.invoke_palette_query_changed("tf".into());
+        assert_eq!(app.get_palette_results().row_data(0).unwrap(), "Test Features");
+        assert_eq!(app.get_palette_index(), 0);
+
+        let before = app.get_theme_mode();
+        app.invoke_palette_run("Toggle Theme".into());
+        assert_ne!(app.get_theme_mode(), before);
     }
 
-    features
-}
\ No newline at end of file
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn navigated_saves_the_open_panel() {
+        let app = TestApp::new();
+        app.set_show_logs(true);
+        app.invoke_navigated();
+        assert_eq!(ThemePrefs::load().last_view, "logs");
+
+        app.set_show_logs(false);
+        app.invoke_navigated();
+        assert_eq!(ThemePrefs::load().last_view, "");
+    }
+}
@@ -0,0 +1,44 @@
+//! Animation speed multiplier, applied through the `Motion` global.
+
+use slint::ComponentHandle;
+
+use crate::platform::clamp_animation_speed;
+use crate::{CrossPlatformApp, Motion};
+
+/// Speeds offered by the picker, in its order; 0 turns animations off.
+pub const SPEEDS: [f32; 5] = [0.0, 0.5, 1.0, 1.5, 2.0];
+
+/// The speed at a picker index.
+pub fn speed_at(index: i32) -> Option<f32> {
+    SPEEDS.get(usize::try_from(index).ok()?).copied()
+}
+
+/// Plays `app`'s animations at `speed`, clamped to 0.5–2.0 (or 0 for off).
+/// Returns the speed that was applied.
+pub fn apply(app: &CrossPlatformApp, speed: f32) -> f32 {
+    let speed = clamp_animation_speed(speed);
+    app.global::<Motion>().set_speed(speed);
+    speed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picker_indices() {
+        assert_eq!(speed_at(0), Some(0.0));
+        assert_eq!(speed_at(2), Some(1.0));
+        assert_eq!(speed_at(4), Some(2.0));
+        assert_eq!(speed_at(5), None);
+        assert_eq!(speed_at(-1), None);
+    }
+
+    // Every offered speed survives clamping, so the picker shows what is applied
+    #[test]
+    fn offered_speeds_are_in_range() {
+        for speed in SPEEDS {
+            assert_eq!(clamp_animation_speed(speed), speed);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! System notifications with the app's own status bar as the fallback.
+//!
+//! With the `system-notifications` feature, desktop builds go through
+//! `notify-rust`: the freedesktop `org.freedesktop.Notifications` D-Bus
+//! service on Linux, toast notifications on Windows and the Notification
+//! Center on macOS. The web build uses the browser's Notification API once
+//! the user has granted permission.
+
+/// Shows a system notification, or calls `fallback` with the same title and
+/// body when the platform can't show it (no notification server, permission
+/// denied, or a build without the feature). Native APIs can take a while to
+/// answer, so desktop builds send the request from a background thread and
+/// the web build awaits the permission prompt; `fallback` always runs on
+/// the UI thread.
+#[cfg(all(feature = "system-notifications", not(target_arch = "wasm32")))]
+pub fn send_system_notification(title: String, body: String, fallback: impl FnOnce(String, String) + Send + 'static) {
+    let spawned = std::thread::Builder::new().name("notification".into()).spawn(move || {
+        let shown = notify_rust::Notification::new()
+            .appname("Slint Cross-Platform")
+            .summary(&title)
+            .body(&body)
+            .show();
+        if let Err(err) = shown {
+            tracing::debug!(%err, "system notification failed, notifying in the app");
+            let _ = slint::invoke_from_event_loop(move || fallback(title, body));
+        }
+    });
+    if let Err(err) = spawned {
+        tracing::warn!(%err, "could not start the notification thread");
+    }
+}
+
+// Browsers ask the user once per site; until they answer, the permission is
+// "default" and the request below shows the prompt. "denied" is final for
+// the page, so the status bar is used from then on.
+#[cfg(all(feature = "system-notifications", target_arch = "wasm32"))]
+pub fn send_system_notification(title: String, body: String, fallback: impl FnOnce(String, String) + Send + 'static) {
+    use web_sys::{Notification, NotificationOptions, NotificationPermission};
+
+    wasm_bindgen_futures::spawn_local(async move {
+        let granted = match Notification::permission() {
+            NotificationPermission::Granted => true,
+            NotificationPermission::Default => match Notification::request_permission() {
+                Ok(promise) => wasm_bindgen_futures::JsFuture::from(promise)
+                    .await
+                    .is_ok_and(|answer| answer.as_string().as_deref() == Some("granted")),
+                Err(_) => false,
+            },
+            _ => false,
+        };
+
+        let options = NotificationOptions::new();
+        options.set_body(&body);
+        if !granted || Notification::new_with_options(&title, &options).is_err() {
+            tracing::debug!(granted, "browser notification not shown, notifying in the app");
+            fallback(title, body);
+        }
+    });
+}
+
+#[cfg(not(feature = "system-notifications"))]
+pub fn send_system_notification(title: String, body: String, fallback: impl FnOnce(String, String) + Send + 'static) {
+    fallback(title, body);
+}
@@ -0,0 +1,72 @@
+//! Shows panics in the page, so a crash isn't just a blank canvas.
+//!
+//! Browser only: on desktop the panic message goes to stderr as usual.
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+const OVERLAY_ID: &str = "error-overlay";
+
+/// Installs a panic hook that logs to the console (as
+/// `console_error_panic_hook` does) and then renders the message with a
+/// Reload button into `#error-overlay`, creating the element if the page
+/// doesn't have one.
+pub fn install() {
+    std::panic::set_hook(Box::new(|info| {
+        console_error_panic_hook::hook(info);
+        show(&info.to_string());
+    }));
+}
+
+fn show(message: &str) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let Some(body) = document.body() else {
+        return;
+    };
+
+    let overlay = match document.get_element_by_id(OVERLAY_ID) {
+        Some(overlay) => overlay,
+        None => {
+            let Ok(overlay) = document.create_element("div") else {
+                return;
+            };
+            overlay.set_id(OVERLAY_ID);
+            let _ = body.append_child(&overlay);
+            overlay
+        }
+    };
+
+    // Cover the canvas, replacing whatever an earlier panic left behind
+    let _ = overlay.set_attribute(
+        "style",
+        "position: fixed; inset: 0; z-index: 1000; padding: 2em; overflow: auto; \
+         background: rgba(255, 255, 255, 0.95); font-family: sans-serif;",
+    );
+    overlay.set_text_content(None);
+
+    if let Ok(heading) = document.create_element("h2") {
+        heading.set_text_content(Some("Something went wrong"));
+        let _ = overlay.append_child(&heading);
+    }
+
+    if let Ok(details) = document.create_element("pre") {
+        details.set_text_content(Some(message));
+        let _ = details.set_attribute("style", "color: #c0392b; white-space: pre-wrap;");
+        let _ = overlay.append_child(&details);
+    }
+
+    if let Ok(button) = document.create_element("button") {
+        button.set_text_content(Some("Reload"));
+        let reload = Closure::<dyn Fn()>::new(|| {
+            if let Some(window) = web_sys::window() {
+                let _ = window.location().reload();
+            }
+        });
+        let _ = button.add_event_listener_with_callback("click", reload.as_ref().unchecked_ref());
+        // Lives until the page reloads
+        reload.forget();
+        let _ = overlay.append_child(&button);
+    }
+}
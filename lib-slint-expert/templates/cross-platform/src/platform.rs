@@ -0,0 +1,84 @@
+//! Platform facts shown in the info panel. Detection lives in the shared
+//! `slint-skills-core` crate; this module adds what needs a Slint window.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "linux")]
+pub use slint_skills_core::detect_linux_session;
+#[cfg(not(target_arch = "wasm32"))]
+pub use slint_skills_core::{record_renderer, record_system_tray};
+#[cfg(target_arch = "wasm32")]
+pub use slint_skills_core::{detect_web_renderer, WebRenderer};
+pub use slint_skills_core::{
+    battery_status, clamp_animation_speed, collect, collect_live, detect_locale, detect_reduced_motion, renderer_name, BatteryState, LiveStatus, PlatformInfo, PlatformProvider, RealPlatform,
+};
+
+/// Whether windows can be kept above others. Wayland leaves stacking to the
+/// compositor and silently ignores the request; browsers have no concept of
+/// it.
+pub fn always_on_top_supported() -> bool {
+    #[cfg(target_arch = "wasm32")]
+    return false;
+
+    #[cfg(target_os = "linux")]
+    return detect_linux_session() != "Wayland";
+
+    #[cfg(not(any(target_arch = "wasm32", target_os = "linux")))]
+    return true;
+}
+
+/// One connected display.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorInfo {
+    /// Name reported by the OS, if any.
+    pub name: Option<String>,
+    /// Resolution in physical pixels.
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f64,
+    pub refresh_rate_hz: Option<f32>,
+}
+
+/// Lists the connected monitors. winit only enumerates monitors through an
+/// existing native window, so this needs one; before it is shown the list
+/// is empty. Cheap enough to call on every refresh, so hot-plugged screens
+/// show up.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn enumerate_monitors(window: &slint::Window) -> Vec<MonitorInfo> {
+    use slint::winit_030::WinitWindowAccessor;
+
+    window
+        .with_winit_window(|winit_window| {
+            winit_window
+                .available_monitors()
+                .map(|monitor| MonitorInfo {
+                    name: monitor.name(),
+                    width: monitor.size().width,
+                    height: monitor.size().height,
+                    scale_factor: monitor.scale_factor(),
+                    refresh_rate_hz: monitor.refresh_rate_millihertz().map(|millihertz| millihertz as f32 / 1000.0),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The browser exposes a single screen: the one showing the canvas.
+#[cfg(target_arch = "wasm32")]
+pub fn enumerate_monitors(window: &slint::Window) -> Vec<MonitorInfo> {
+    let Some(screen) = web_sys::window().and_then(|browser| browser.screen().ok()) else {
+        return Vec::new();
+    };
+    let scale_factor = window.scale_factor() as f64;
+    let (Ok(width), Ok(height)) = (screen.width(), screen.height()) else {
+        return Vec::new();
+    };
+
+    vec![MonitorInfo {
+        name: None,
+        width: (width as f64 * scale_factor).round() as u32,
+        height: (height as f64 * scale_factor).round() as u32,
+        scale_factor,
+        refresh_rate_hz: None,
+    }]
+}
@@ -0,0 +1,41 @@
+//! Every open `CrossPlatformApp` window, so app-wide state such as the theme
+//! can be pushed to all of them.
+
+use std::cell::{Cell, RefCell};
+
+use slint::ComponentHandle;
+
+use crate::CrossPlatformApp;
+
+thread_local! {
+    static WINDOWS: RefCell<Vec<(u32, CrossPlatformApp)>> = const { RefCell::new(Vec::new()) };
+    static NEXT_ID: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Keeps `window` alive and includes it in [`for_each`] until [`unregister`]
+/// is called with the returned id.
+pub fn register(window: &CrossPlatformApp) -> u32 {
+    let id = NEXT_ID.with(|next| next.replace(next.get() + 1));
+    WINDOWS.with(|windows| windows.borrow_mut().push((id, window.clone_strong())));
+    id
+}
+
+pub fn unregister(id: u32) {
+    // Take the handle out before dropping it, in case dropping the component
+    // re-enters the registry.
+    let removed = WINDOWS.with(|windows| {
+        let mut windows = windows.borrow_mut();
+        let index = windows.iter().position(|(window_id, _)| *window_id == id)?;
+        Some(windows.remove(index))
+    });
+    drop(removed);
+}
+
+pub fn for_each(mut f: impl FnMut(&CrossPlatformApp)) {
+    // Clone the handles so `f` may open or close windows.
+    let windows: Vec<CrossPlatformApp> =
+        WINDOWS.with(|windows| windows.borrow().iter().map(|(_, window)| window.clone_strong()).collect());
+    for window in &windows {
+        f(window);
+    }
+}
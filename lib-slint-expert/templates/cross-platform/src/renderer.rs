@@ -0,0 +1,66 @@
+//! Picking Slint's renderer at startup (desktop only).
+//!
+//! `--renderer` and the `preferred_renderer` config key request one through
+//! `slint::BackendSelector`. Without a request, or when the requested one
+//! can't be created (e.g. because it wasn't compiled in), the renderer from
+//! `SLINT_BACKEND` or the first one that works is selected instead. Either
+//! way the choice is recorded, since Slint can't be asked for it later.
+
+/// Renderers that can be requested. Which ones work depends on the Slint
+/// features the app is built with; "skia" needs `renderer-skia`.
+pub const RENDERERS: [&str; 3] = ["femtovg", "skia", "software"];
+
+/// Asks Slint for `renderer`. Must run before the first window is created.
+/// Returns whether it was selected; on failure Slint keeps its default and
+/// a warning is logged.
+pub fn select(renderer: &str) -> bool {
+    let Some(&renderer) = RENDERERS.iter().find(|&&known| known == renderer) else {
+        tracing::warn!(renderer, "unknown renderer, using the default");
+        return false;
+    };
+
+    match try_select(renderer) {
+        Ok(()) => {
+            tracing::info!(renderer, "renderer selected");
+            true
+        }
+        Err(err) => {
+            tracing::warn!(renderer, %err, "could not select the renderer, using the default");
+            false
+        }
+    }
+}
+
+/// Selects a renderer when none was requested, so the platform info can
+/// name the one in use: the one in `SLINT_BACKEND` if it is set to a winit
+/// renderer, otherwise the first of [`RENDERERS`] that Slint can create.
+/// Other `SLINT_BACKEND` values (e.g. "qt") are left to Slint.
+pub fn select_default() {
+    let requested = std::env::var("SLINT_BACKEND").unwrap_or_default();
+    let candidates: Vec<&'static str> = match requested.strip_prefix("winit-").unwrap_or(&requested) {
+        "" | "winit" => RENDERERS.to_vec(),
+        "sw" => vec!["software"],
+        requested => match RENDERERS.iter().find(|&&known| known == requested) {
+            Some(&renderer) => vec![renderer],
+            None => return,
+        },
+    };
+
+    for renderer in candidates {
+        match try_select(renderer) {
+            Ok(()) => {
+                tracing::debug!(renderer, "renderer selected");
+                return;
+            }
+            Err(err) => tracing::debug!(renderer, %err, "renderer unavailable"),
+        }
+    }
+}
+
+// Slint has no getter for the selected renderer, so it is recorded for
+// the platform info
+fn try_select(renderer: &'static str) -> Result<(), slint::PlatformError> {
+    slint::BackendSelector::new().backend_name("winit".into()).renderer_name(renderer.into()).select()?;
+    crate::platform::record_renderer(renderer);
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! Example of real async I/O: fetches a GitHub repository's metadata as
+//! JSON on the tokio runtime (see `async_runtime`).
+
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// The repository the "Fetch Repo" button asks about.
+pub const DEMO_REPO: &str = "slint-ui/slint";
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The fields of GitHub's repository JSON the status bar shows.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RepoInfo {
+    pub full_name: String,
+    pub stargazers_count: u64,
+    pub description: Option<String>,
+}
+
+/// `repo` is "owner/name". Errors are returned as text for the status bar.
+pub async fn fetch(repo: &str) -> Result<RepoInfo, String> {
+    let client = reqwest::Client::builder()
+        .timeout(TIMEOUT)
+        // GitHub rejects requests without a User-Agent
+        .user_agent(concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|err| err.to_string())?;
+
+    client
+        .get(format!("https://api.github.com/repos/{}", repo))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|err| err.to_string())?
+        .json::<RepoInfo>()
+        .await
+        .map_err(|err| err.to_string())
+}
@@ -0,0 +1,105 @@
+//! UI scale override on top of the display's own scale factor.
+//!
+//! The backend reports each monitor's factor (1.5 on a 150% display) and the
+//! user's override multiplies it, so the UI stays proportionally larger or
+//! smaller on every screen. When a window moves to a monitor with another
+//! factor, winit resets it to that monitor's factor and the override is
+//! applied on top again.
+
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use slint::winit_030::winit;
+use slint::ComponentHandle;
+
+use crate::CrossPlatformApp;
+
+pub const MIN_OVERRIDE: f32 = 0.75;
+pub const MAX_OVERRIDE: f32 = 2.0;
+
+/// Keeps `value` within [`MIN_OVERRIDE`, `MAX_OVERRIDE`]. Anything that isn't
+/// a number (e.g. from a hand-edited prefs file) means no override.
+pub fn clamp_override(value: f32) -> f32 {
+    if value.is_finite() {
+        value.clamp(MIN_OVERRIDE, MAX_OVERRIDE)
+    } else {
+        1.0
+    }
+}
+
+/// Parses a label from the scale picker, e.g. "125%".
+pub fn parse_percent(label: &str) -> Option<f32> {
+    let percent: f32 = label.trim().strip_suffix('%')?.trim().parse().ok()?;
+    Some(clamp_override(percent / 100.0))
+}
+
+/// Records the window's display factor once it is showing and applies
+/// `ui-scale` on top. On the desktop, later changes come in through
+/// `handle_winit_event`.
+pub fn track(app: &CrossPlatformApp) {
+    let app_weak = app.as_weak();
+    slint::Timer::single_shot(Duration::ZERO, move || {
+        if let Some(app) = app_weak.upgrade() {
+            app.set_display_scale(app.window().scale_factor());
+            apply(&app);
+        }
+    });
+}
+
+/// Re-reads the display factor when the window moves to another monitor.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn handle_winit_event(app: &CrossPlatformApp, event: &winit::event::WindowEvent) {
+    if let winit::event::WindowEvent::ScaleFactorChanged { scale_factor, .. } = event {
+        let display_scale = *scale_factor as f32;
+        let app_weak = app.as_weak();
+        // Slint applies the monitor's factor while handling this event; the
+        // override goes on top once it has.
+        slint::Timer::single_shot(Duration::ZERO, move || {
+            if let Some(app) = app_weak.upgrade() {
+                app.set_display_scale(display_scale);
+                apply(&app);
+            }
+        });
+    }
+}
+
+/// Renders the window at its display factor times its `ui-scale`.
+pub fn apply(app: &CrossPlatformApp) {
+    let scale_factor = app.get_display_scale() * clamp_override(app.get_ui_scale());
+    if scale_factor != app.window().scale_factor() {
+        app.window()
+            .dispatch_event(slint::platform::WindowEvent::ScaleFactorChanged { scale_factor });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_are_clamped_to_the_range() {
+        assert_eq!(clamp_override(1.25), 1.25);
+        assert_eq!(clamp_override(MIN_OVERRIDE), MIN_OVERRIDE);
+        assert_eq!(clamp_override(MAX_OVERRIDE), MAX_OVERRIDE);
+        assert_eq!(clamp_override(0.5), MIN_OVERRIDE);
+        assert_eq!(clamp_override(0.0), MIN_OVERRIDE);
+        assert_eq!(clamp_override(-3.0), MIN_OVERRIDE);
+        assert_eq!(clamp_override(5.0), MAX_OVERRIDE);
+    }
+
+    #[test]
+    fn non_numbers_mean_no_override() {
+        assert_eq!(clamp_override(f32::NAN), 1.0);
+        assert_eq!(clamp_override(f32::INFINITY), 1.0);
+        assert_eq!(clamp_override(f32::NEG_INFINITY), 1.0);
+    }
+
+    #[test]
+    fn picker_labels() {
+        assert_eq!(parse_percent("125%"), Some(1.25));
+        assert_eq!(parse_percent(" 75 % "), Some(0.75));
+        assert_eq!(parse_percent("300%"), Some(MAX_OVERRIDE));
+        assert_eq!(parse_percent("125"), None);
+        assert_eq!(parse_percent("big%"), None);
+    }
+}
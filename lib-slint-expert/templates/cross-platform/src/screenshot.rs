@@ -0,0 +1,122 @@
+//! `--screenshot out.png`: renders the main window to a PNG with Slint's
+//! software renderer, without a display, for docs and CI (desktop only).
+//!
+//! The winit backend is replaced by a platform whose only window is a
+//! `MinimalSoftwareWindow`, so this has to be set up before the first
+//! window is created. `--headless` runs on the same platform.
+
+use std::path::Path;
+use std::rc::Rc;
+
+use slint::platform::software_renderer::{MinimalSoftwareWindow, RepaintBufferType, Rgb8Pixel};
+use slint::platform::{Platform, WindowAdapter};
+use slint::{ComponentHandle, PhysicalSize, PlatformError, SharedPixelBuffer};
+
+use crate::CrossPlatformApp;
+
+/// The window's size in `main.slint`, used when no size is given.
+pub const DEFAULT_SIZE: (u32, u32) = (600, 500);
+
+thread_local! {
+    static WINDOW: std::cell::OnceCell<Rc<MinimalSoftwareWindow>> = const { std::cell::OnceCell::new() };
+}
+
+struct HeadlessPlatform {
+    window: Rc<MinimalSoftwareWindow>,
+}
+
+impl Platform for HeadlessPlatform {
+    fn create_window_adapter(&self) -> Result<Rc<dyn WindowAdapter>, PlatformError> {
+        Ok(self.window.clone())
+    }
+}
+
+/// Makes windows render into memory instead of on screen. Fails when a
+/// backend is already in use.
+pub fn use_headless_platform() -> Result<(), PlatformError> {
+    let window = MinimalSoftwareWindow::new(RepaintBufferType::NewBuffer);
+    slint::platform::set_platform(Box::new(HeadlessPlatform { window: window.clone() }))
+        .map_err(|err| PlatformError::Other(err.to_string()))?;
+    WINDOW.with(|cell| cell.set(window)).map_err(|_| PlatformError::Other("headless platform already set up".into()))?;
+    crate::platform::record_renderer("software");
+    Ok(())
+}
+
+/// Renders `app` as it is now at `width`×`height` physical pixels and
+/// writes it to `path` as an RGB PNG. Needs [`use_headless_platform`].
+///
+/// Setting the size lays the window out again, and a redraw is requested
+/// explicitly, so this also works right after the window was created.
+pub fn render_to_png(app: &CrossPlatformApp, path: &Path, width: u32, height: u32) -> Result<(), String> {
+    let window = WINDOW
+        .with(|cell| cell.get().cloned())
+        .ok_or("screenshots need the headless platform")?;
+    if width == 0 || height == 0 {
+        return Err(format!("invalid screenshot size {}x{}", width, height));
+    }
+
+    app.show().map_err(|err| err.to_string())?;
+    app.window().set_size(PhysicalSize::new(width, height));
+    slint::platform::update_timers_and_animations();
+    window.request_redraw();
+
+    let mut pixels = SharedPixelBuffer::<Rgb8Pixel>::new(width, height);
+    let drawn = window.draw_if_needed(|renderer| {
+        renderer.render(pixels.make_mut_slice(), width as usize);
+    });
+    if !drawn {
+        return Err("the window did not render".to_string());
+    }
+
+    write_png(path, width, height, pixels.as_bytes()).map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+fn write_png(path: &Path, width: u32, height: u32, rgb: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    let mut encoder = png::Encoder::new(file, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(rgb)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Slint's platform is per thread, and every test runs on its own
+    fn headless_app() -> CrossPlatformApp {
+        use_headless_platform().unwrap();
+        CrossPlatformApp::new().unwrap()
+    }
+
+    #[test]
+    fn writes_a_png_of_the_requested_size() {
+        let app = headless_app();
+        let path = std::env::temp_dir().join(format!("slint-screenshot-test-{}.png", std::process::id()));
+
+        render_to_png(&app, &path, 320, 240).unwrap();
+        let reader = png::Decoder::new(std::fs::File::open(&path).unwrap()).read_info().unwrap();
+        let info = reader.info();
+        assert_eq!((info.width, info.height), (320, 240));
+        assert_eq!((info.color_type, info.bit_depth), (png::ColorType::Rgb, png::BitDepth::Eight));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn rejects_an_empty_size() {
+        let app = headless_app();
+        let path = std::env::temp_dir().join("never-written.png");
+        assert_eq!(render_to_png(&app, &path, 0, 240), Err("invalid screenshot size 0x240".to_string()));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn needs_the_headless_platform() {
+        i_slint_backend_testing::init_no_event_loop();
+        let app = CrossPlatformApp::new().unwrap();
+        let result = render_to_png(&app, Path::new("unused.png"), 10, 10);
+        assert_eq!(result, Err("screenshots need the headless platform".to_string()));
+    }
+}
@@ -0,0 +1,83 @@
+//! The platform's shortcut modifier: Cmd on Apple systems, Ctrl elsewhere.
+//!
+//! Native macOS builds need nothing special, because Slint already reports
+//! Cmd as `control` there (and the Control key as `meta`). The web backend
+//! passes the browser's modifiers through unchanged, so in Safari or Chrome
+//! on a Mac, Cmd arrives as `meta` and the page has to check the platform
+//! itself.
+
+use crate::KeyPress;
+
+/// Whether `key` was pressed with the platform's shortcut modifier held.
+pub fn command_held(key: &KeyPress) -> bool {
+    command_held_on(key, web_on_apple())
+}
+
+/// How the help text spells the modifier, e.g. "Ctrl+" in "Ctrl+T".
+pub fn modifier_label() -> &'static str {
+    label_on(cfg!(target_os = "macos") || web_on_apple())
+}
+
+fn command_held_on(key: &KeyPress, cmd_is_meta: bool) -> bool {
+    if cmd_is_meta {
+        key.meta
+    } else {
+        key.control
+    }
+}
+
+fn label_on(apple: bool) -> &'static str {
+    if apple {
+        "⌘"
+    } else {
+        "Ctrl+"
+    }
+}
+
+// navigator.platform is deprecated but still the only synchronous answer in
+// every browser; iPads with a keyboard report "MacIntel" too.
+#[cfg(target_arch = "wasm32")]
+fn web_on_apple() -> bool {
+    web_sys::window()
+        .and_then(|window| window.navigator().platform().ok())
+        .is_some_and(|platform| platform.starts_with("Mac") || platform.starts_with("iP"))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn web_on_apple() -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(control: bool, meta: bool) -> KeyPress {
+        KeyPress {
+            text: "t".into(),
+            control,
+            meta,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn ctrl_is_the_modifier_off_the_web_on_apple() {
+        assert!(command_held_on(&key(true, false), false));
+        assert!(!command_held_on(&key(false, true), false));
+        assert!(!command_held_on(&key(false, false), false));
+    }
+
+    #[test]
+    fn cmd_arrives_as_meta_in_browsers_on_apple() {
+        assert!(command_held_on(&key(false, true), true));
+        // The Control key doesn't trigger shortcuts there
+        assert!(!command_held_on(&key(true, false), true));
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(label_on(false), "Ctrl+");
+        assert_eq!(label_on(true), "⌘");
+    }
+}
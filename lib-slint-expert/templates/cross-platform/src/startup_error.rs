@@ -0,0 +1,64 @@
+//! Telling the user why the app couldn't start, instead of failing silently.
+
+/// Logs `error` and shows it to the user: a native message box on desktop,
+/// a notice in the page on the web.
+pub fn report(error: &slint::PlatformError) {
+    tracing::error!(%error, "startup failed");
+    show(&describe(error));
+}
+
+fn describe(error: &slint::PlatformError) -> String {
+    format!(
+        "The application could not start because no usable window could be created.\n\n\
+         Details: {}\n\n\
+         Make sure a graphical session is available, or try another renderer \
+         with SLINT_BACKEND (e.g. SLINT_BACKEND=winit-software).",
+        error
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn show(message: &str) {
+    // Without a display even the dialog fails; stderr still has the log line.
+    rfd::MessageDialog::new()
+        .set_level(rfd::MessageLevel::Error)
+        .set_title("Slint Cross-Platform Demo")
+        .set_description(message)
+        .set_buttons(rfd::MessageButtons::Ok)
+        .show();
+}
+
+// Replaces the canvas area with a plain text notice.
+#[cfg(target_arch = "wasm32")]
+fn show(message: &str) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let Some(body) = document.body() else {
+        return;
+    };
+    let Ok(notice) = document.create_element("pre") else {
+        return;
+    };
+
+    notice.set_id("startup-error");
+    notice.set_text_content(Some(message));
+    let _ = notice.set_attribute("style", "color: #c0392b; padding: 1em; white-space: pre-wrap;");
+    let _ = body.append_child(&notice);
+
+    if let Some(canvas) = document.get_element_by_id("canvas") {
+        canvas.remove();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn description_includes_the_error_and_a_way_out() {
+        let message = describe(&slint::PlatformError::Other("no GPU".into()));
+        assert!(message.contains("Details: no GPU"));
+        assert!(message.contains("SLINT_BACKEND=winit-software"));
+    }
+}
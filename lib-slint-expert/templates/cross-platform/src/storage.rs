@@ -0,0 +1,195 @@
+//! Small key/value store for persisted settings.
+//!
+//! Each key maps to a `<key>.json` file in the platform config directory on
+//! desktop ([`FileStore`]), and to a `slint-cross-platform.<key>` entry in
+//! `localStorage` on the web ([`WebStore`]). Call sites use the [`Store`]
+//! alias so they read the same on both.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub type Store = FileStore;
+
+#[cfg(target_arch = "wasm32")]
+pub type Store = WebStore;
+
+/// The app's directory in the platform config location, e.g.
+/// `~/.config/slint-cross-platform` on Linux. Under `cargo test` it is an
+/// empty temporary directory per test thread instead, so tests that save
+/// preferences neither see nor change the user's.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn config_dir() -> Option<std::path::PathBuf> {
+    #[cfg(test)]
+    return Some(test_config_dir());
+
+    #[cfg(not(test))]
+    directories::ProjectDirs::from("com", "example", "slint-cross-platform").map(|dirs| dirs.config_dir().to_path_buf())
+}
+
+// Every test runs on a thread of its own, and Slint windows are bound to it
+#[cfg(all(test, not(target_arch = "wasm32")))]
+fn test_config_dir() -> std::path::PathBuf {
+    thread_local! {
+        static DIR: std::path::PathBuf = {
+            let thread = format!("{:?}", std::thread::current().id()).replace(|c: char| !c.is_ascii_alphanumeric(), "");
+            let dir = std::env::temp_dir().join(format!("slint-config-test-{}-{}", std::process::id(), thread));
+            let _ = std::fs::remove_dir_all(&dir);
+            dir
+        };
+    }
+    DIR.with(Clone::clone)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileStore;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStore {
+    fn dir() -> std::io::Result<std::path::PathBuf> {
+        config_dir().ok_or_else(|| std::io::Error::other("no config directory for this platform"))
+    }
+
+    pub fn get(key: &str) -> Option<String> {
+        Self::get_in(&Self::dir().ok()?, key)
+    }
+
+    pub fn set(key: &str, raw: &str) -> std::io::Result<()> {
+        Self::set_in(&Self::dir()?, key, raw)
+    }
+
+    pub fn remove(key: &str) -> std::io::Result<()> {
+        match Self::dir() {
+            Ok(dir) => Self::remove_in(&dir, key),
+            Err(_) => Ok(()),
+        }
+    }
+
+    // The operations on an explicit directory, so tests can use a temporary one
+
+    fn get_in(dir: &std::path::Path, key: &str) -> Option<String> {
+        std::fs::read_to_string(dir.join(format!("{}.json", key))).ok()
+    }
+
+    fn set_in(dir: &std::path::Path, key: &str, raw: &str) -> std::io::Result<()> {
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(dir.join(format!("{}.json", key)), raw)
+    }
+
+    fn remove_in(dir: &std::path::Path, key: &str) -> std::io::Result<()> {
+        match std::fs::remove_file(dir.join(format!("{}.json", key))) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// `localStorage`, falling back to memory for the rest of the session when
+/// the browser blocks storage (e.g. some private modes) or it is full.
+#[cfg(target_arch = "wasm32")]
+pub struct WebStore;
+
+#[cfg(target_arch = "wasm32")]
+thread_local! {
+    static MEMORY: std::cell::RefCell<std::collections::HashMap<String, String>> = Default::default();
+}
+
+#[cfg(target_arch = "wasm32")]
+impl WebStore {
+    fn local_storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+
+    fn item_key(key: &str) -> String {
+        format!("slint-cross-platform.{}", key)
+    }
+
+    pub fn get(key: &str) -> Option<String> {
+        if let Some(raw) = MEMORY.with_borrow(|memory| memory.get(key).cloned()) {
+            return Some(raw);
+        }
+        Self::local_storage()?.get_item(&Self::item_key(key)).ok()?
+    }
+
+    pub fn set(key: &str, raw: &str) -> std::io::Result<()> {
+        let stored = match Self::local_storage() {
+            Some(storage) => match storage.set_item(&Self::item_key(key), raw) {
+                Ok(()) => true,
+                Err(err) if is_quota_exceeded(&err) => {
+                    tracing::warn!(key, "localStorage is full; keeping the value for this session only");
+                    false
+                }
+                Err(_) => return Err(std::io::Error::other("localStorage rejected the write")),
+            },
+            None => false,
+        };
+
+        MEMORY.with_borrow_mut(|memory| {
+            if stored {
+                memory.remove(key);
+            } else {
+                memory.insert(key.to_string(), raw.to_string());
+            }
+        });
+        Ok(())
+    }
+
+    pub fn remove(key: &str) -> std::io::Result<()> {
+        MEMORY.with_borrow_mut(|memory| memory.remove(key));
+        if let Some(storage) = Self::local_storage() {
+            storage
+                .remove_item(&Self::item_key(key))
+                .map_err(|_| std::io::Error::other("localStorage rejected the removal"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn is_quota_exceeded(err: &wasm_bindgen::JsValue) -> bool {
+    use wasm_bindgen::JsCast;
+
+    err.dyn_ref::<web_sys::DomException>()
+        .is_some_and(|exception| exception.name() == "QuotaExceededError")
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    // A fresh directory per test that doesn't exist yet
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("slint-store-test-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn set_creates_the_directory_and_get_reads_it_back() {
+        let dir = temp_dir("round-trip");
+        FileStore::set_in(&dir, "prefs", r#"{"theme":"dark"}"#).unwrap();
+        assert_eq!(FileStore::get_in(&dir, "prefs").as_deref(), Some(r#"{"theme":"dark"}"#));
+        assert!(dir.join("prefs.json").exists());
+
+        FileStore::set_in(&dir, "prefs", "{}").unwrap();
+        assert_eq!(FileStore::get_in(&dir, "prefs").as_deref(), Some("{}"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_keys() {
+        let dir = temp_dir("missing");
+        assert_eq!(FileStore::get_in(&dir, "window"), None);
+        // Nothing to remove is not an error
+        FileStore::remove_in(&dir, "window").unwrap();
+    }
+
+    #[test]
+    fn remove_deletes_only_its_key() {
+        let dir = temp_dir("remove");
+        FileStore::set_in(&dir, "prefs", "{}").unwrap();
+        FileStore::set_in(&dir, "window", "{}").unwrap();
+
+        FileStore::remove_in(&dir, "prefs").unwrap();
+        assert_eq!(FileStore::get_in(&dir, "prefs"), None);
+        assert_eq!(FileStore::get_in(&dir, "window").as_deref(), Some("{}"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
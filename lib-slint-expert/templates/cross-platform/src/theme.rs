@@ -0,0 +1,221 @@
+//! Theme names and the preferences persisted between launches.
+
+use serde::{Deserialize, Serialize};
+
+use crate::storage::Store;
+
+/// Themes with a palette in `main.slint`'s `Palette` global.
+pub const THEMES: &[&str] = &["light", "dark", "high-contrast", "sepia"];
+
+/// Theme modes the toggle cycles through, in order. "system" is not a palette
+/// of its own; it resolves to whichever of light/dark the OS prefers.
+pub const THEME_MODES: &[&str] = &["light", "dark", "high-contrast", "sepia", SYSTEM_MODE];
+
+pub const SYSTEM_MODE: &str = "system";
+
+pub const DEFAULT_THEME: &str = "light";
+
+const STORAGE_KEY: &str = "prefs";
+
+// How often the desktop watcher re-reads the OS appearance.
+const WATCH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+impl Theme {
+    /// The theme name used by `main.slint`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+}
+
+impl From<dark_light::Mode> for Theme {
+    fn from(mode: dark_light::Mode) -> Self {
+        match mode {
+            dark_light::Mode::Dark => Theme::Dark,
+            // `Default` means the OS has no preference (or we can't read it).
+            dark_light::Mode::Light | dark_light::Mode::Default => Theme::Light,
+        }
+    }
+}
+
+/// Reads the OS light/dark appearance setting.
+pub fn detect_system_theme() -> Theme {
+    dark_light::detect().into()
+}
+
+/// Whether the OS reports an appearance preference at all. When it doesn't,
+/// "system" mode would be indistinguishable from light, so it is skipped.
+pub fn system_theme_supported() -> bool {
+    dark_light::detect() != dark_light::Mode::Default
+}
+
+/// Maps a theme mode to the palette name `main.slint` understands.
+pub fn resolve_theme(mode: &str) -> &str {
+    if mode == SYSTEM_MODE {
+        detect_system_theme().as_str()
+    } else {
+        mode
+    }
+}
+
+/// The mode after `current` in toggle order, skipping "system" when the OS
+/// can't report a preference.
+pub fn next_mode(current: &str, system_supported: bool) -> &'static str {
+    let available: Vec<&'static str> = THEME_MODES
+        .iter()
+        .copied()
+        .filter(|&mode| system_supported || mode != SYSTEM_MODE)
+        .collect();
+
+    match available.iter().position(|&mode| mode == current) {
+        Some(index) => available[(index + 1) % available.len()],
+        None => available[0],
+    }
+}
+
+/// Calls `on_change` whenever the OS appearance flips. The watcher stops once
+/// `on_change` returns `false`, e.g. because the event loop has shut down.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn watch_system_theme(on_change: impl Fn(Theme) -> bool + Send + 'static) {
+    std::thread::spawn(move || {
+        let mut last = detect_system_theme();
+        loop {
+            std::thread::sleep(WATCH_INTERVAL);
+            let current = detect_system_theme();
+            if current != last {
+                last = current;
+                if !on_change(current) {
+                    break;
+                }
+            }
+        }
+    });
+}
+
+/// Browsers answer `prefers-color-scheme` cheaply, so on the web the check
+/// runs on a UI-thread timer instead of a background thread.
+#[cfg(target_arch = "wasm32")]
+pub fn watch_system_theme(on_change: impl Fn(Theme) -> bool + 'static) {
+    thread_local! {
+        static WATCHER: slint::Timer = slint::Timer::default();
+    }
+
+    let last = std::cell::Cell::new(detect_system_theme());
+    WATCHER.with(|timer| {
+        timer.start(slint::TimerMode::Repeated, WATCH_INTERVAL, move || {
+            let current = detect_system_theme();
+            if current != last.replace(current) && !on_change(current) {
+                WATCHER.with(|timer| timer.stop());
+            }
+        });
+    });
+}
+
+/// User preferences restored on startup.
+///
+/// Stored as JSON in the platform config directory on desktop and in
+/// `localStorage` on the web.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemePrefs {
+    /// One of [`THEME_MODES`].
+    pub theme: String,
+    /// Keep windows above other windows.
+    pub always_on_top: bool,
+    /// Panel open when the app was last used: "", "logs" or "about".
+    pub last_view: String,
+    /// UI scale override on top of the display's factor, see [`crate::scale`].
+    pub ui_scale: f32,
+    /// Animation speed multiplier, see [`crate::motion`]; 0 turns them off.
+    pub animation_speed: f32,
+}
+
+impl Default for ThemePrefs {
+    fn default() -> Self {
+        Self {
+            theme: DEFAULT_THEME.to_string(),
+            always_on_top: false,
+            last_view: String::new(),
+            ui_scale: 1.0,
+            animation_speed: 1.0,
+        }
+    }
+}
+
+impl ThemePrefs {
+    /// Loads the saved preferences, following the OS appearance when nothing
+    /// usable has been saved yet.
+    pub fn load() -> Self {
+        Self::load_saved().unwrap_or_else(|| Self {
+            theme: SYSTEM_MODE.to_string(),
+            ..Self::default()
+        })
+    }
+
+    /// Loads the saved preferences, or `None` when the store is missing,
+    /// corrupt, or names a theme mode we no longer ship.
+    pub fn load_saved() -> Option<Self> {
+        let prefs: Self = serde_json::from_str(&Store::get(STORAGE_KEY)?).ok()?;
+        THEME_MODES.contains(&prefs.theme.as_str()).then_some(prefs)
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let raw = serde_json::to_string_pretty(self)?;
+        Store::set(STORAGE_KEY, &raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_modes_map_to_palettes() {
+        assert_eq!(Theme::from(dark_light::Mode::Dark).as_str(), "dark");
+        assert_eq!(Theme::from(dark_light::Mode::Light).as_str(), "light");
+        // No preference reported: start in light
+        assert_eq!(Theme::from(dark_light::Mode::Default).as_str(), "light");
+    }
+
+    #[test]
+    fn resolved_names_are_palettes() {
+        for &mode in THEME_MODES {
+            assert!(THEMES.contains(&resolve_theme(mode)), "{}", mode);
+        }
+    }
+
+    #[test]
+    fn toggle_cycles_through_every_mode() {
+        assert_eq!(next_mode("light", true), "dark");
+        assert_eq!(next_mode("sepia", true), SYSTEM_MODE);
+        assert_eq!(next_mode(SYSTEM_MODE, true), "light");
+    }
+
+    #[test]
+    fn toggle_skips_system_when_unsupported() {
+        assert_eq!(next_mode("sepia", false), "light");
+        assert_eq!(next_mode(SYSTEM_MODE, false), "light");
+        assert_eq!(next_mode("unknown", false), "light");
+    }
+
+    // Fields added in later versions fall back to their defaults
+    #[test]
+    fn prefs_from_an_older_version() {
+        let prefs: ThemePrefs = serde_json::from_str(r#"{ "theme": "dark" }"#).unwrap();
+        assert_eq!(
+            prefs,
+            ThemePrefs {
+                theme: "dark".to_string(),
+                ..ThemePrefs::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Custom themes as JSON color tokens, loaded and exported at runtime.
+//!
+//! A token file names each palette color as a hex string:
+//!
+//! ```json
+//! { "background": "#1e1e2e", "surface": "#313244", "foreground": "#cdd6f4",
+//!   "primary": "#89b4fa", "accent": "#f5c2e7" }
+//! ```
+//!
+//! Applying it fills the `custom-*` colors of `main.slint`'s `Palette` and
+//! switches to the "custom" theme.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use slint::{Color, ComponentHandle};
+
+use crate::{CrossPlatformApp, Palette};
+
+/// The `Palette.theme` name that shows the loaded tokens.
+pub const CUSTOM_THEME: &str = "custom";
+
+/// Suggested file name for exports.
+pub const FILE_NAME: &str = "theme.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeTokens {
+    #[serde(with = "hex")]
+    pub background: Color,
+    /// Panels and bars drawn over the background.
+    #[serde(with = "hex")]
+    pub surface: Color,
+    /// Text color.
+    #[serde(with = "hex")]
+    pub foreground: Color,
+    #[serde(with = "hex")]
+    pub primary: Color,
+    /// Secondary buttons and muted text.
+    #[serde(with = "hex")]
+    pub accent: Color,
+}
+
+impl ThemeTokens {
+    /// The palette `app` is showing right now.
+    pub fn current(app: &CrossPlatformApp) -> Self {
+        let palette = app.global::<Palette>();
+        Self {
+            background: palette.get_background(),
+            surface: palette.get_surface(),
+            foreground: palette.get_text(),
+            primary: palette.get_primary(),
+            accent: palette.get_secondary(),
+        }
+    }
+
+    /// Parses a token file. Every token is required; a missing, unknown or
+    /// malformed one is an error naming it.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|err| err.to_string())
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("tokens are plain strings")
+    }
+
+    /// Shows these colors in `app`.
+    pub fn apply(&self, app: &CrossPlatformApp) {
+        let palette = app.global::<Palette>();
+        palette.set_custom_background(self.background);
+        palette.set_custom_surface(self.surface);
+        palette.set_custom_text(self.foreground);
+        palette.set_custom_primary(self.primary);
+        palette.set_custom_secondary(self.accent);
+        app.set_current_theme(CUSTOM_THEME.into());
+    }
+}
+
+/// Parses "#rgb", "#rrggbb" or "#rrggbbaa".
+pub fn parse_hex_color(text: &str) -> Result<Color, String> {
+    let invalid = || format!("invalid color {:?}, expected #rrggbb", text);
+    let digits = text.strip_prefix('#').ok_or_else(invalid)?;
+    if !digits.chars().all(|digit| digit.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    let channel = |index: usize, width: usize| {
+        let value = u8::from_str_radix(&digits[index * width..(index + 1) * width], 16).unwrap_or(0);
+        if width == 1 { value * 17 } else { value }
+    };
+
+    match digits.len() {
+        3 => Ok(Color::from_rgb_u8(channel(0, 1), channel(1, 1), channel(2, 1))),
+        6 => Ok(Color::from_rgb_u8(channel(0, 2), channel(1, 2), channel(2, 2))),
+        8 => Ok(Color::from_argb_u8(channel(3, 2), channel(0, 2), channel(1, 2), channel(2, 2))),
+        _ => Err(invalid()),
+    }
+}
+
+/// "#rrggbb", or "#rrggbbaa" when not opaque.
+pub fn to_hex(color: Color) -> String {
+    let rgb = format!("#{:02x}{:02x}{:02x}", color.red(), color.green(), color.blue());
+    if color.alpha() == 255 {
+        rgb
+    } else {
+        format!("{}{:02x}", rgb, color.alpha())
+    }
+}
+
+// serde adapter for the `Color` fields
+mod hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_hex(*color))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        parse_hex_color(&text).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The file from the module docs
+    const MOCHA: &str = r##"{ "background": "#1e1e2e", "surface": "#313244", "foreground": "#cdd6f4",
+        "primary": "#89b4fa", "accent": "#f5c2e7" }"##;
+
+    fn mocha() -> ThemeTokens {
+        ThemeTokens {
+            background: Color::from_rgb_u8(0x1e, 0x1e, 0x2e),
+            surface: Color::from_rgb_u8(0x31, 0x32, 0x44),
+            foreground: Color::from_rgb_u8(0xcd, 0xd6, 0xf4),
+            primary: Color::from_rgb_u8(0x89, 0xb4, 0xfa),
+            accent: Color::from_rgb_u8(0xf5, 0xc2, 0xe7),
+        }
+    }
+
+    #[test]
+    fn loads_a_token_file() {
+        assert_eq!(ThemeTokens::from_json(MOCHA), Ok(mocha()));
+    }
+
+    #[test]
+    fn exports_round_trip() {
+        let mut tokens = mocha();
+        tokens.surface = Color::from_argb_u8(0x80, 0x31, 0x32, 0x44);
+
+        let json = tokens.to_json();
+        assert!(json.contains(r##""background": "#1e1e2e""##), "{json}");
+        assert!(json.contains(r##""surface": "#31324480""##), "{json}");
+        assert_eq!(ThemeTokens::from_json(&json), Ok(tokens));
+    }
+
+    #[test]
+    fn malformed_files_name_the_problem() {
+        let missing = ThemeTokens::from_json(r##"{ "background": "#000" }"##).unwrap_err();
+        assert!(missing.contains("missing field `surface`"), "{missing}");
+
+        let unknown = MOCHA.replace("\"accent\"", "\"border\"");
+        let unknown = ThemeTokens::from_json(&unknown).unwrap_err();
+        assert!(unknown.contains("unknown field `border`"), "{unknown}");
+
+        let bad_color = MOCHA.replace("#89b4fa", "blue");
+        let bad_color = ThemeTokens::from_json(&bad_color).unwrap_err();
+        assert!(bad_color.contains(r#"invalid color "blue""#), "{bad_color}");
+
+        assert!(ThemeTokens::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn hex_colors() {
+        assert_eq!(parse_hex_color("#f80"), Ok(Color::from_rgb_u8(0xff, 0x88, 0x00)));
+        assert_eq!(parse_hex_color("#1E1E2E"), Ok(Color::from_rgb_u8(0x1e, 0x1e, 0x2e)));
+        assert_eq!(parse_hex_color("#00000080"), Ok(Color::from_argb_u8(0x80, 0, 0, 0)));
+        for text in ["", "#", "1e1e2e", "#12", "#12345", "#gggggg", "#1e1e2e0", "#ééé"] {
+            assert!(parse_hex_color(text).is_err(), "{text:?}");
+        }
+
+        assert_eq!(to_hex(Color::from_rgb_u8(0xff, 0x88, 0x00)), "#ff8800");
+        assert_eq!(to_hex(Color::from_argb_u8(0, 0xff, 0xff, 0xff)), "#ffffff00");
+    }
+}
@@ -0,0 +1,144 @@
+//! System tray icon with a Show / Toggle Theme / Quit menu.
+//!
+//! While the tray is up, closing the window only hides it; "Quit" ends the
+//! app. If the tray can't be created (no GTK on Linux, or a desktop without a
+//! StatusNotifier host) `install` returns `false` and closing the window quits
+//! as usual.
+
+use std::cell::RefCell;
+
+use slint::ComponentHandle;
+use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+use crate::CrossPlatformApp;
+
+const SHOW_ID: &str = "show";
+const TOGGLE_THEME_ID: &str = "toggle-theme";
+const QUIT_ID: &str = "quit";
+
+thread_local! {
+    static TRAY: RefCell<Option<TrayIcon>> = const { RefCell::new(None) };
+}
+
+// On Linux the icon lives on this thread instead, see `spawn_tray`.
+#[cfg(target_os = "linux")]
+thread_local! {
+    static GTK_THREAD: RefCell<Option<std::thread::JoinHandle<()>>> = const { RefCell::new(None) };
+}
+
+/// Creates the tray icon and routes its menu to `app`. Returns whether the
+/// tray is available.
+pub fn install(app: &CrossPlatformApp) -> bool {
+    let app_weak = app.as_weak();
+    MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+        let app_weak = app_weak.clone();
+        // Menu events arrive on the tray's thread; hop to the UI thread.
+        let _ = slint::invoke_from_event_loop(move || {
+            let Some(app) = app_weak.upgrade() else {
+                return;
+            };
+            if event.id == SHOW_ID {
+                let _ = app.show();
+            } else if event.id == TOGGLE_THEME_ID {
+                app.invoke_toggle_theme();
+            } else if event.id == QUIT_ID {
+                remove();
+                let _ = slint::quit_event_loop();
+            }
+        });
+    }));
+
+    // Read here: the Slint image can't leave the UI thread, the pixels can
+    #[cfg(feature = "app-icon")]
+    let pixels = crate::icon::rgba(app);
+    #[cfg(not(feature = "app-icon"))]
+    let pixels = None;
+
+    spawn_tray(pixels)
+}
+
+/// Removes the tray icon, if any. On Linux this also stops the GTK thread
+/// and waits for it.
+pub fn remove() {
+    TRAY.with(|tray| tray.borrow_mut().take());
+
+    #[cfg(target_os = "linux")]
+    if let Some(thread) = GTK_THREAD.with(|slot| slot.borrow_mut().take()) {
+        // Queued onto the GTK thread's main loop; leaving `gtk::main` drops
+        // the icon there
+        gtk::glib::MainContext::default().invoke(gtk::main_quit);
+        let _ = thread.join();
+    }
+}
+
+// RGBA8 pixels with their width and height
+type Pixels = (Vec<u8>, u32, u32);
+
+fn build_tray(pixels: Option<Pixels>) -> Result<TrayIcon, Box<dyn std::error::Error>> {
+    let menu = Menu::new();
+    menu.append_items(&[
+        &MenuItem::with_id(SHOW_ID, "Show", true, None),
+        &MenuItem::with_id(TOGGLE_THEME_ID, "Toggle Theme", true, None),
+        &PredefinedMenuItem::separator(),
+        &MenuItem::with_id(QUIT_ID, "Quit", true, None),
+    ])?;
+
+    Ok(TrayIconBuilder::new()
+        .with_menu(Box::new(menu))
+        .with_tooltip("Slint Cross-Platform Demo")
+        .with_icon(tray_icon(pixels)?)
+        .build()?)
+}
+
+// The embedded app icon, or a flat square in the theme's primary blue when
+// the app is built without one.
+fn tray_icon(pixels: Option<Pixels>) -> Result<Icon, tray_icon::BadIcon> {
+    if let Some((rgba, width, height)) = pixels {
+        return Icon::from_rgba(rgba, width, height);
+    }
+
+    const SIZE: u32 = 32;
+    let rgba = [0x34, 0x98, 0xdb, 0xff].repeat((SIZE * SIZE) as usize);
+    Icon::from_rgba(rgba, SIZE, SIZE)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn spawn_tray(pixels: Option<Pixels>) -> bool {
+    match build_tray(pixels) {
+        Ok(tray) => {
+            TRAY.with(|slot| *slot.borrow_mut() = Some(tray));
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+// On Linux the tray needs a GTK main loop, so it lives on its own thread
+// until `remove` ends that loop.
+#[cfg(target_os = "linux")]
+fn spawn_tray(pixels: Option<Pixels>) -> bool {
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+
+    let thread = std::thread::spawn(move || {
+        if gtk::init().is_err() {
+            let _ = ready_tx.send(false);
+            return;
+        }
+        match build_tray(pixels) {
+            Ok(_tray) => {
+                let _ = ready_tx.send(true);
+                gtk::main();
+            }
+            Err(_) => {
+                let _ = ready_tx.send(false);
+            }
+        }
+    });
+
+    let ready = ready_rx.recv().unwrap_or(false);
+    if ready {
+        GTK_THREAD.with(|slot| *slot.borrow_mut() = Some(thread));
+    }
+    ready
+}
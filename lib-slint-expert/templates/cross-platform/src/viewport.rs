@@ -0,0 +1,68 @@
+//! Keeps the web canvas the size of the browser viewport.
+//!
+//! Browser only: desktop windows are resized by the window manager.
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+use slint::ComponentHandle;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// A drag-resize fires `resize` continuously; only apply the size once the
+/// events stop for this long.
+pub const DEBOUNCE: Duration = Duration::from_millis(100);
+
+thread_local! {
+    static LISTENER: RefCell<Option<Closure<dyn Fn()>>> = const { RefCell::new(None) };
+    static DEBOUNCE_TIMER: slint::Timer = slint::Timer::default();
+}
+
+/// Sizes the component's window to the viewport now and on every browser
+/// resize. Replaces a listener installed for an earlier component; the
+/// listener removes itself once the component is gone.
+pub fn fit_to_viewport<C: ComponentHandle + 'static>(component: &C) {
+    detach();
+    apply(component.window());
+
+    let weak = component.as_weak();
+    let listener = Closure::<dyn Fn()>::new(move || {
+        let weak = weak.clone();
+        DEBOUNCE_TIMER.with(|timer| {
+            timer.start(slint::TimerMode::SingleShot, DEBOUNCE, move || match weak.upgrade() {
+                Some(component) => apply(component.window()),
+                None => detach(),
+            })
+        });
+    });
+
+    if let Some(window) = web_sys::window() {
+        let _ = window.add_event_listener_with_callback("resize", listener.as_ref().unchecked_ref());
+    }
+    LISTENER.set(Some(listener));
+}
+
+/// Removes the resize listener, if one is installed.
+pub fn detach() {
+    DEBOUNCE_TIMER.with(slint::Timer::stop);
+    if let Some(listener) = LISTENER.take() {
+        if let Some(window) = web_sys::window() {
+            let _ = window.remove_event_listener_with_callback("resize", listener.as_ref().unchecked_ref());
+        }
+    }
+}
+
+// The viewport size in CSS pixels, scaled by `devicePixelRatio` so the canvas
+// backing store stays sharp on high-DPI screens.
+fn apply(window: &slint::Window) {
+    let Some(browser) = web_sys::window() else {
+        return;
+    };
+    let css_pixels = |value: Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue>| value.ok().and_then(|value| value.as_f64());
+    let (Some(width), Some(height)) = (css_pixels(browser.inner_width()), css_pixels(browser.inner_height())) else {
+        return;
+    };
+
+    let ratio = browser.device_pixel_ratio();
+    window.set_size(slint::PhysicalSize::new((width * ratio).round() as u32, (height * ratio).round() as u32));
+}
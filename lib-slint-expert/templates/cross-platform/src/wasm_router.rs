@@ -0,0 +1,119 @@
+//! Deep links for the web build through the URL hash.
+//!
+//! A route looks like `#/logs?theme=dark`: the path picks the open panel and
+//! the optional `theme` query overrides the saved theme. Every change writes
+//! a new hash, so the browser's back and forward buttons step through them.
+
+use std::cell::RefCell;
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+use crate::theme;
+
+/// Which panel is showing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum View {
+    #[default]
+    Home,
+    Logs,
+    About,
+}
+
+impl View {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            View::Home => "",
+            View::Logs => "logs",
+            View::About => "about",
+        }
+    }
+
+    fn parse(path: &str) -> Option<Self> {
+        match path {
+            "" => Some(View::Home),
+            "logs" => Some(View::Logs),
+            "about" => Some(View::About),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Route {
+    pub view: View,
+    /// One of [`theme::THEME_MODES`].
+    pub theme: Option<String>,
+}
+
+impl Route {
+    /// Parses a `location.hash` value, with or without the leading `#`.
+    /// Returns `None` for unknown views, unknown themes or unknown
+    /// parameters.
+    pub fn parse(hash: &str) -> Option<Self> {
+        let hash = hash.strip_prefix('#').unwrap_or(hash);
+        let hash = hash.strip_prefix('/').unwrap_or(hash);
+        let (path, query) = hash.split_once('?').unwrap_or((hash, ""));
+
+        let mut route = Route {
+            view: View::parse(path.trim_end_matches('/'))?,
+            theme: None,
+        };
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            match pair.split_once('=')? {
+                ("theme", name) if theme::THEME_MODES.contains(&name) => route.theme = Some(name.to_string()),
+                _ => return None,
+            }
+        }
+        Some(route)
+    }
+
+    pub fn to_hash(&self) -> String {
+        match &self.theme {
+            Some(theme) => format!("#/{}?theme={}", self.view.as_str(), theme),
+            None => format!("#/{}", self.view.as_str()),
+        }
+    }
+}
+
+thread_local! {
+    static LISTENER: RefCell<Option<Closure<dyn Fn()>>> = const { RefCell::new(None) };
+}
+
+/// The route in the current URL. A missing or malformed hash gives the
+/// default route.
+pub fn read_route() -> Route {
+    let hash = web_sys::window().and_then(|window| window.location().hash().ok()).unwrap_or_default();
+    Route::parse(&hash).unwrap_or_else(|| {
+        if !hash.is_empty() {
+            tracing::warn!(%hash, "ignoring malformed route");
+        }
+        Route::default()
+    })
+}
+
+/// Writes `route` to the URL, adding a history entry unless it's already the
+/// current one.
+pub fn write_route(route: &Route) {
+    let Some(location) = web_sys::window().map(|window| window.location()) else {
+        return;
+    };
+    let hash = route.to_hash();
+    if location.hash().ok().as_deref() != Some(hash.as_str()) {
+        let _ = location.set_hash(&hash);
+    }
+}
+
+/// Calls `on_change` when the hash changes, e.g. from the back button.
+/// Replaces an earlier listener.
+pub fn on_route_change(on_change: impl Fn(Route) + 'static) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+
+    let listener = Closure::<dyn Fn()>::new(move || on_change(read_route()));
+    let _ = window.add_event_listener_with_callback("hashchange", listener.as_ref().unchecked_ref());
+    if let Some(previous) = LISTENER.replace(Some(listener)) {
+        let _ = window.remove_event_listener_with_callback("hashchange", previous.as_ref().unchecked_ref());
+    }
+}
@@ -0,0 +1,53 @@
+//! Window actions for the custom title bar of frameless windows.
+//!
+//! Desktop only: the web build always runs inside the page and never drops
+//! its decorations.
+
+use slint::winit_030::{winit, WinitWindowAccessor};
+
+use crate::ResizeEdge;
+
+/// Hands a title bar drag to the window manager, which moves the window
+/// until the mouse button is released.
+pub fn start_move(window: &slint::Window) {
+    window.with_winit_window(|winit_window| {
+        let _ = winit_window.drag_window();
+    });
+}
+
+/// Starts an interactive resize from `edge`.
+pub fn start_resize(window: &slint::Window, edge: ResizeEdge) {
+    use winit::window::ResizeDirection;
+
+    let direction = match edge {
+        ResizeEdge::North => ResizeDirection::North,
+        ResizeEdge::South => ResizeDirection::South,
+        ResizeEdge::East => ResizeDirection::East,
+        ResizeEdge::West => ResizeDirection::West,
+        ResizeEdge::NorthEast => ResizeDirection::NorthEast,
+        ResizeEdge::NorthWest => ResizeDirection::NorthWest,
+        ResizeEdge::SouthEast => ResizeDirection::SouthEast,
+        ResizeEdge::SouthWest => ResizeDirection::SouthWest,
+    };
+
+    window.with_winit_window(|winit_window| {
+        let _ = winit_window.drag_resize_window(direction);
+    });
+}
+
+/// Maximizes or restores the window; returns whether it is now maximized.
+pub fn toggle_maximized(window: &slint::Window) -> bool {
+    let maximized = !window.is_maximized();
+    window.set_maximized(maximized);
+    maximized
+}
+
+pub fn minimize(window: &slint::Window) {
+    window.set_minimized(true);
+}
+
+/// Closes the window the same way the OS close button would, so
+/// close-requested handlers (geometry saving, tray hiding) still run.
+pub fn close(window: &slint::Window) {
+    let _ = window.try_dispatch_event(slint::platform::WindowEvent::CloseRequested);
+}